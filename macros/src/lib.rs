@@ -5,8 +5,8 @@ use proc_macro::TokenStream;
 use quote::quote;
 use syn::parse::Parser;
 use syn::{
-    Expr, ExprLit, FnArg, ItemFn, Lit, Meta, MetaNameValue, Pat, Token, parse_macro_input,
-    punctuated::Punctuated,
+    Expr, ExprLit, FnArg, ItemFn, Lit, Meta, MetaNameValue, Pat, PatType, Token, Type,
+    parse_macro_input, punctuated::Punctuated,
 };
 
 #[proc_macro_attribute]
@@ -45,6 +45,21 @@ use syn::{
 /// use `Option` types for arguments that are optional or implement a default for those
 /// that are not and handle those defaults accordingly in the tool body.
 ///
+/// Tools that need to perform I/O can be declared as `async fn`. The body is awaited
+/// when the model calls the tool.
+///
+/// ```rust,no_run
+/// use aisdk::macros::tool;
+/// use aisdk::core::tools::Tool;
+///
+/// #[tool]
+/// /// Fetches the username from a remote service
+/// async fn fetch_username(id: String) -> Tool {
+///     // Your async code here
+///     Ok(format!("user_{}", id))
+/// }
+/// ```
+///
 /// You can override name and description using the macro arguments `name` and `desc`.
 ///
 /// # Example with overrides
@@ -61,6 +76,39 @@ use syn::{
 ///         Ok(format!("user_{}", id))
 ///     }
 /// ```
+///
+/// Tools with side effects can require an approval before each call using the
+/// `needs_approval` argument.
+///
+/// ```rust,no_run
+/// use aisdk::macros::tool;
+/// use aisdk::core::tools::Tool;
+///
+/// #[tool(needs_approval = true)]
+/// /// Deletes a record
+/// fn delete_record(id: String) -> Tool {
+///     Ok(format!("deleted {}", id))
+/// }
+/// ```
+///
+/// An argument of type `ToolContext` receives the context of the call, with the tool
+/// call id, the conversation messages and the values attached to the request. It is
+/// not part of the input schema.
+///
+/// ```rust,no_run
+/// use aisdk::macros::tool;
+/// use aisdk::core::tools::{Tool, ToolContext};
+///
+/// #[derive(Clone)]
+/// struct UserId(String);
+///
+/// #[tool]
+/// /// Lists the orders of the signed in user
+/// fn list_orders(limit: u32, context: ToolContext) -> Tool {
+///     let user = context.get::<UserId>().ok_or("not signed in")?;
+///     Ok(format!("{} orders of {}", limit, user.0))
+/// }
+/// ```
 pub fn tool(_attr: TokenStream, item: TokenStream) -> TokenStream {
     let input_fn = parse_macro_input!(item as ItemFn);
     let fn_name = &input_fn.sig.ident;
@@ -72,9 +120,10 @@ pub fn tool(_attr: TokenStream, item: TokenStream) -> TokenStream {
     let args_parser = Punctuated::<MetaNameValue, Token![,]>::parse_terminated;
     let args = args_parser.parse(_attr);
 
-    let (name_arg, description_arg) = if let Ok(args) = args {
+    let (name_arg, description_arg, needs_approval) = if let Ok(args) = args {
         let mut name: Option<String> = None;
         let mut description: Option<String> = None;
+        let mut needs_approval = false;
 
         for arg in args {
             if arg.path.is_ident("desc")
@@ -87,12 +136,17 @@ pub fn tool(_attr: TokenStream, item: TokenStream) -> TokenStream {
                 && let Lit::Str(str_lit) = &lit.lit
             {
                 name = Some(str_lit.value());
+            } else if arg.path.is_ident("needs_approval")
+                && let Expr::Lit(lit) = &arg.value
+                && let Lit::Bool(bool_lit) = &lit.lit
+            {
+                needs_approval = bool_lit.value;
             }
         }
 
-        (name, description)
+        (name, description, needs_approval)
     } else {
-        (None, None)
+        (None, None, false)
     };
 
     let description = if let Some(desc) = description_arg {
@@ -140,6 +194,9 @@ pub fn tool(_attr: TokenStream, item: TokenStream) -> TokenStream {
                     let ident = &pat_ident.ident;
                     let ty = &*pat_type.ty;
                     let ident_str = ident.to_string();
+                    if is_context_arg(pat_type) {
+                        return Some(quote! { let #ident: #ty = __context; });
+                    }
                     Some(quote! {
                         let #ident: #ty = ::aisdk::__private::serde_json::from_value(
                             inp.as_object()
@@ -160,7 +217,9 @@ pub fn tool(_attr: TokenStream, item: TokenStream) -> TokenStream {
 
     // Generate the struct definition
     let struct_fields = inputs.iter().filter_map(|arg| {
-        if let FnArg::Typed(pat_type) = arg {
+        if let FnArg::Typed(pat_type) = arg
+            && !is_context_arg(pat_type)
+        {
             if let Pat::Ident(pat_ident) = &*pat_type.pat {
                 let ident = &pat_ident.ident;
                 let ty = &*pat_type.ty;
@@ -173,6 +232,36 @@ pub fn tool(_attr: TokenStream, item: TokenStream) -> TokenStream {
         }
     });
 
+    // Async tools run their body in an inner async fn so the output type is fixed
+    // to `Result<String, String>` and `?` works as in the sync case.
+    let execute = if input_fn.sig.asyncness.is_some() {
+        let arg_idents = inputs.iter().filter_map(|arg| {
+            if let FnArg::Typed(pat_type) = arg
+                && let Pat::Ident(pat_ident) = &*pat_type.pat
+            {
+                Some(&pat_ident.ident)
+            } else {
+                None
+            }
+        });
+
+        quote! {
+            ::aisdk::core::tools::ToolExecute::new_async_with_context(Box::new(|inp, __context| -> ::aisdk::core::tools::ToolFuture {
+                async fn __tool_body(#inputs) -> std::result::Result<String, String> #block
+
+                #(#binding_tokens)*
+                Box::pin(__tool_body(#(#arg_idents),*))
+            }))
+        }
+    } else {
+        quote! {
+            ::aisdk::core::tools::ToolExecute::new_with_context(Box::new(|inp, __context| -> std::result::Result<String, String> {
+                #(#binding_tokens)*
+                #block
+            }))
+        }
+    };
+
     let expanded = quote! {
         #vis fn #fn_name() #return_type  {
             // use schemars::{schema_for, JsonSchema, Schema};
@@ -195,10 +284,8 @@ pub fn tool(_attr: TokenStream, item: TokenStream) -> TokenStream {
                 .name(#name.to_string())
                 .description(#description.to_string())
                 .input_schema(input_schema)
-                .execute(::aisdk::core::tools::ToolExecute::new(Box::new(|inp| -> std::result::Result<String, String> {
-                    #(#binding_tokens)*
-                    #block
-                })));
+                .execute(#execute)
+                .needs_approval(#needs_approval);
 
             tool.build().expect("Failed to build tool")
         }
@@ -206,3 +293,15 @@ pub fn tool(_attr: TokenStream, item: TokenStream) -> TokenStream {
 
     TokenStream::from(expanded)
 }
+
+/// Returns `true` if the argument receives the `ToolContext` of the call.
+fn is_context_arg(pat_type: &PatType) -> bool {
+    matches!(
+        &*pat_type.ty,
+        Type::Path(type_path) if type_path
+            .path
+            .segments
+            .last()
+            .is_some_and(|segment| segment.ident == "ToolContext")
+    )
+}
@@ -70,6 +70,79 @@ mod tests {
         assert_eq!(tool.name, "the-name-for-this-tool");
     }
 
+    #[tool(needs_approval = true)]
+    /// Deletes a record.
+    pub fn my_example_tool_needing_approval(id: String) -> Tool {
+        Ok(format!("deleted {}", id))
+    }
+
+    #[test]
+    fn test_tool_macro_with_needs_approval() {
+        assert!(my_example_tool_needing_approval().needs_approval);
+        assert!(!my_example_tool().needs_approval);
+    }
+
+    #[derive(Clone)]
+    struct UserId(String);
+
+    #[tool]
+    /// Greets the signed in user.
+    pub fn my_example_tool_with_context(
+        greeting: String,
+        context: aisdk::core::tools::ToolContext,
+    ) -> Tool {
+        let user = context.get::<UserId>().ok_or("not signed in")?;
+        Ok(format!(
+            "{} {} ({})",
+            greeting, user.0, context.tool_call_id
+        ))
+    }
+
+    #[tool]
+    /// Greets the signed in user later.
+    pub async fn my_async_example_tool_with_context(
+        context: aisdk::core::tools::ToolContext,
+    ) -> Tool {
+        Ok(context
+            .get::<UserId>()
+            .map(|user| user.0)
+            .unwrap_or_default())
+    }
+
+    #[tokio::test]
+    async fn test_tool_macro_with_context() {
+        let tool = my_example_tool_with_context();
+        let properties = tool.input_schema.as_object().unwrap()["properties"]
+            .as_object()
+            .unwrap();
+        assert!(properties.contains_key("greeting"));
+        assert!(!properties.contains_key("context"));
+
+        let context = aisdk::core::tools::ToolContext {
+            tool_call_id: "call_1".to_string(),
+            ..Default::default()
+        };
+        context.extensions.insert(UserId("ada".to_string()));
+        let input = serde_json::json!({"greeting": "hello"});
+        assert_eq!(
+            tool.execute
+                .call_with_context(input.clone(), context.clone())
+                .await
+                .unwrap(),
+            "hello ada (call_1)"
+        );
+        assert!(tool.execute.call(input).is_err());
+
+        let tool = my_async_example_tool_with_context();
+        assert_eq!(
+            tool.execute
+                .call_with_context(serde_json::json!({}), context)
+                .await
+                .unwrap(),
+            "ada"
+        );
+    }
+
     #[tool(desc = "the-description-for-this-tool")]
     /// This is The Description of an example tool.
     pub fn my_example_tool_with_description(_name: String, a: u8, b: Option<u8>) -> Tool {
@@ -120,6 +193,40 @@ mod tests {
         assert_eq!(tool.description, "the-description-for-this-tool");
     }
 
+    #[tool]
+    /// Adds two numbers after yielding to the runtime.
+    pub async fn my_async_tool(a: u8, b: Option<u8>) -> Tool {
+        tokio::task::yield_now().await;
+        let b = b.ok_or("missing b")?;
+        Ok(format!("{}", a + b))
+    }
+
+    #[tokio::test]
+    async fn test_async_tool_macro() {
+        let tool = my_async_tool();
+        assert_eq!(tool.name, "my_async_tool");
+        assert_eq!(
+            tool.description,
+            " Adds two numbers after yielding to the runtime."
+        );
+
+        let input = serde_json::json!({ "a": 1, "b": 2 });
+        assert_eq!(
+            tool.execute.call_async(input).await.unwrap(),
+            "3".to_string()
+        );
+
+        let input = serde_json::json!({ "a": 1, "b": null });
+        assert!(tool.execute.call_async(input).await.is_err());
+
+        // The synchronous entry point refuses to block on an asynchronous tool
+        let input = serde_json::json!({"a": 1, "b": 2});
+        assert!(matches!(
+            tool.execute.call(input),
+            Err(aisdk::Error::ToolCallError(message)) if message.contains("call_async")
+        ));
+    }
+
     #[test]
     fn test_argument_json_schema() {}
 }
@@ -226,6 +226,9 @@ def get_model_capabilities(model_data: dict[str, Any]) -> list[str]:
     if "video" in input_modalities:
         capabilities.append("VideoInputSupport")
 
+    if "pdf" in input_modalities:
+        capabilities.append("FileInputSupport")
+
     # Output capabilities
     if "text" in output_modalities:
         capabilities.append("TextOutputSupport")
@@ -35,6 +35,9 @@ pub trait AudioInputSupport {}
 /// Marker trait for models that support image input.
 pub trait ImageInputSupport {}
 
+/// Marker trait for models that support file input, such as PDF documents.
+pub trait FileInputSupport {}
+
 /// Marker traits for models that support text output.
 pub trait TextOutputSupport {}
 
@@ -142,6 +145,7 @@ macro_rules! model_capabilities {
         impl TextInputSupport for $provider<DynamicModel> {}
         impl TextOutputSupport for $provider<DynamicModel> {}
         impl ImageInputSupport for $provider<DynamicModel> {}
+        impl FileInputSupport for $provider<DynamicModel> {}
         impl VideoInputSupport for $provider<DynamicModel> {}
         impl AudioInputSupport for $provider<DynamicModel> {}
         impl ImageOutputSupport for $provider<DynamicModel> {}
@@ -1,70 +1,296 @@
 //! This module provides the client for interacting with the AI providers.
 //! It is a thin wrapper around the `reqwest` crate.
 
+use crate::core::language_model::LanguageModelOptions;
 use crate::core::utils::join_url;
 use crate::error::{Error, Result};
+use derive_builder::Builder;
 use futures::Stream;
 use futures::StreamExt;
 use reqwest;
 use reqwest::IntoUrl;
 use reqwest_eventsource::{Event, RequestBuilderExt};
 use serde::de::DeserializeOwned;
+use std::collections::HashMap;
 use std::pin::Pin;
-use std::time::Duration;
-
-/// Configuration for retry behavior on API requests.
-#[derive(Debug, Clone)]
-struct RetryConfig {
-    /// Maximum number of retry attempts (default: 5)
-    max_retries: u32,
-    /// Initial wait time before first retry (default: 1 second)
-    initial_wait: Duration,
-    /// Maximum wait time between retries (default: 30 seconds)
-    max_wait: Duration,
-    /// Whether to add jitter to backoff (default: true)
-    use_jitter: bool,
+use std::sync::OnceLock;
+use std::time::{Duration, Instant};
+
+#[cfg(any(test, feature = "test-utils"))]
+pub mod cassette;
+// Only used by providers, which may all be disabled.
+#[allow(dead_code)]
+pub(crate) mod multipart;
+
+// Only used by the tests of providers, which may all be disabled.
+#[cfg(test)]
+#[allow(dead_code)]
+pub(crate) mod stub_server;
+
+/// The HTTP client shared by all providers that are not given their own client.
+static SHARED_HTTP_CLIENT: OnceLock<reqwest::Client> = OnceLock::new();
+
+/// Returns the HTTP client shared by all providers by default.
+///
+/// `reqwest::Client` is reference counted, so the returned clone shares the
+/// connection pool with every other provider using the default client.
+pub fn shared_http_client() -> reqwest::Client {
+    SHARED_HTTP_CLIENT.get_or_init(reqwest::Client::new).clone()
+}
+
+/// Configuration for building an HTTP client for providers.
+///
+/// # Example
+/// ```
+/// use aisdk::core::client::HttpClientConfig;
+/// use std::time::Duration;
+///
+/// let client = HttpClientConfig::builder()
+///     .timeout(Duration::from_secs(60))
+///     .proxy("http://proxy.internal:3128")
+///     .build()
+///     .unwrap()
+///     .into_client()
+///     .unwrap();
+/// ```
+#[derive(Debug, Clone, Default, Builder)]
+#[builder(setter(into, strip_option), default, build_fn(error = "Error"))]
+pub struct HttpClientConfig {
+    /// Total timeout of a request, including reading the response body.
+    pub timeout: Option<Duration>,
+
+    /// Timeout for establishing a connection.
+    pub connect_timeout: Option<Duration>,
+
+    /// URL of a proxy used for all requests, e.g. `http://proxy:3128`.
+    pub proxy: Option<String>,
+
+    /// Headers sent with every request.
+    #[builder(setter(custom))]
+    pub default_headers: HashMap<String, String>,
+
+    /// Additional trusted root certificates in PEM format.
+    #[builder(setter(custom))]
+    pub root_certificates: Vec<Vec<u8>>,
+}
+
+impl HttpClientConfigBuilder {
+    /// Adds a header sent with every request.
+    pub fn default_header(
+        &mut self,
+        name: impl Into<String>,
+        value: impl Into<String>,
+    ) -> &mut Self {
+        self.default_headers
+            .get_or_insert_with(HashMap::new)
+            .insert(name.into(), value.into());
+        self
+    }
+
+    /// Adds a trusted root certificate in PEM format.
+    pub fn root_certificate(&mut self, pem: impl Into<Vec<u8>>) -> &mut Self {
+        self.root_certificates
+            .get_or_insert_with(Vec::new)
+            .push(pem.into());
+        self
+    }
+}
+
+impl HttpClientConfig {
+    /// Creates a new builder for `HttpClientConfig`.
+    pub fn builder() -> HttpClientConfigBuilder {
+        HttpClientConfigBuilder::default()
+    }
+
+    /// Builds a `reqwest::Client` from the configuration.
+    ///
+    /// # Returns
+    ///
+    /// The client, or [`Error::InvalidInput`] if the proxy, a header or a
+    /// certificate is invalid.
+    pub fn into_client(self) -> Result<reqwest::Client> {
+        let invalid = |e: &dyn std::fmt::Display| Error::InvalidInput(e.to_string());
+        let mut builder = reqwest::Client::builder();
+
+        if let Some(timeout) = self.timeout {
+            builder = builder.timeout(timeout);
+        }
+        if let Some(connect_timeout) = self.connect_timeout {
+            builder = builder.connect_timeout(connect_timeout);
+        }
+        if let Some(proxy) = self.proxy {
+            builder = builder.proxy(reqwest::Proxy::all(proxy).map_err(|e| invalid(&e))?);
+        }
+        if !self.default_headers.is_empty() {
+            let mut headers = reqwest::header::HeaderMap::new();
+            for (name, value) in self.default_headers {
+                headers.insert(
+                    reqwest::header::HeaderName::try_from(name).map_err(|e| invalid(&e))?,
+                    reqwest::header::HeaderValue::try_from(value).map_err(|e| invalid(&e))?,
+                );
+            }
+            builder = builder.default_headers(headers);
+        }
+        for pem in self.root_certificates {
+            builder = builder.add_root_certificate(
+                reqwest::Certificate::from_pem(&pem).map_err(|e| invalid(&e))?,
+            );
+        }
+
+        builder.build().map_err(|e| invalid(&e))
+    }
+}
+
+/// Policy controlling how failed API requests are retried.
+///
+/// A policy can be set per provider through its builder and per request through
+/// `LanguageModelRequestBuilder::retry_policy`. The `max_retries` request option
+/// overrides the number of retries of the effective policy.
+///
+/// # Example
+/// ```
+/// use aisdk::core::client::RetryPolicy;
+/// use std::time::Duration;
+///
+/// let policy = RetryPolicy::builder()
+///     .max_retries(3u32)
+///     .deadline(Duration::from_secs(120))
+///     .build()
+///     .unwrap();
+///
+/// assert_eq!(policy.max_retries, 3);
+/// ```
+#[derive(Debug, Clone, PartialEq, Builder)]
+#[builder(setter(into), default, build_fn(error = "Error"))]
+pub struct RetryPolicy {
+    /// Maximum number of retry attempts (default: 5).
+    pub max_retries: u32,
+    /// Initial wait time before the first retry (default: 1 second).
+    pub initial_wait: Duration,
+    /// Maximum wait time between retries (default: 30 seconds).
+    pub max_wait: Duration,
+    /// Whether to add ±10% jitter to the backoff (default: true).
+    pub use_jitter: bool,
+    /// Response statuses that are retried (default: 408, 429, 502, 503, 504).
+    pub retryable_statuses: Vec<reqwest::StatusCode>,
+    /// Whether connection errors and timeouts are retried (default: true).
+    pub retry_connection_errors: bool,
+    /// Total time budget for all attempts. No retry is scheduled past it (default: none).
+    #[builder(setter(strip_option))]
+    pub deadline: Option<Duration>,
 }
 
-impl Default for RetryConfig {
+impl Default for RetryPolicy {
     fn default() -> Self {
         Self {
             max_retries: 5,
             initial_wait: Duration::from_secs(1),
             max_wait: Duration::from_secs(30),
             use_jitter: true,
+            retryable_statuses: vec![
+                reqwest::StatusCode::REQUEST_TIMEOUT,
+                reqwest::StatusCode::TOO_MANY_REQUESTS,
+                reqwest::StatusCode::BAD_GATEWAY,
+                reqwest::StatusCode::SERVICE_UNAVAILABLE,
+                reqwest::StatusCode::GATEWAY_TIMEOUT,
+            ],
+            retry_connection_errors: true,
+            deadline: None,
         }
     }
 }
 
-/// Checks if a status code is retryable.
-fn is_retryable_status(status: reqwest::StatusCode) -> bool {
-    matches!(
-        status,
-        reqwest::StatusCode::TOO_MANY_REQUESTS
-            | reqwest::StatusCode::BAD_GATEWAY
-            | reqwest::StatusCode::SERVICE_UNAVAILABLE
-            | reqwest::StatusCode::GATEWAY_TIMEOUT
-    )
+impl RetryPolicy {
+    /// Creates a new builder for `RetryPolicy`.
+    pub fn builder() -> RetryPolicyBuilder {
+        RetryPolicyBuilder::default()
+    }
+
+    /// Returns a policy that never retries.
+    pub fn none() -> Self {
+        Self {
+            max_retries: 0,
+            ..Default::default()
+        }
+    }
+
+    /// Returns the policy to use for a request.
+    ///
+    /// The request's `retry_policy` replaces this policy when set, and its
+    /// `max_retries` overrides the number of retries.
+    ///
+    /// # Parameters
+    ///
+    /// * `options` - The options of the request.
+    ///
+    /// # Returns
+    ///
+    /// The effective retry policy.
+    pub fn for_request(&self, options: &LanguageModelOptions) -> Self {
+        let mut policy = options.retry_policy.clone().unwrap_or_else(|| self.clone());
+        if let Some(max_retries) = options.max_retries {
+            policy.max_retries = max_retries;
+        }
+        policy
+    }
+
+    /// Checks if a response status is retryable.
+    fn is_retryable_status(&self, status: reqwest::StatusCode) -> bool {
+        self.retryable_statuses.contains(&status)
+    }
+
+    /// Checks if a transport error is retryable.
+    fn is_retryable_error(&self, error: &reqwest::Error) -> bool {
+        self.retry_connection_errors && (error.is_timeout() || error.is_connect())
+    }
+
+    /// Returns the wait before the next retry, or `None` if no retry is left.
+    ///
+    /// `retry_count` is the number of retries already made and `started` the
+    /// time of the first attempt.
+    fn next_wait(
+        &self,
+        retry_count: u32,
+        started: Instant,
+        retry_after: Option<Duration>,
+    ) -> Option<Duration> {
+        if retry_count >= self.max_retries {
+            return None;
+        }
+
+        let wait = calculate_backoff(retry_count, self, retry_after);
+        match self.deadline {
+            Some(deadline) if started.elapsed() + wait > deadline => None,
+            _ => Some(wait),
+        }
+    }
 }
 
 /// Parses the Retry-After header to get the wait duration.
+///
+/// Both delay seconds and HTTP dates are supported. Dates in the past yield a
+/// zero wait.
 fn parse_retry_after(headers: &reqwest::header::HeaderMap) -> Option<Duration> {
-    headers
+    let value = headers
         .get(reqwest::header::RETRY_AFTER)
-        .and_then(|v| v.to_str().ok())
-        .and_then(|s| {
-            // Try parsing as seconds (integer)
-            if let Ok(seconds) = s.parse::<u64>() {
-                return Some(Duration::from_secs(seconds));
-            }
-            None
-        })
+        .and_then(|v| v.to_str().ok())?
+        .trim();
+
+    if let Ok(seconds) = value.parse::<u64>() {
+        return Some(Duration::from_secs(seconds));
+    }
+
+    let date = httpdate::parse_http_date(value).ok()?;
+    Some(
+        date.duration_since(std::time::SystemTime::now())
+            .unwrap_or_default(),
+    )
 }
 
 /// Calculates the next wait duration with exponential backoff and optional jitter.
 fn calculate_backoff(
     retry_count: u32,
-    config: &RetryConfig,
+    config: &RetryPolicy,
     retry_after: Option<Duration>,
 ) -> Duration {
     // If server provides Retry-After, respect it
@@ -97,6 +323,42 @@ fn calculate_backoff(
     }
 }
 
+/// Builds the URL of a provider request, routed through the installed cassette if any.
+fn request_url(base_url: impl IntoUrl, path: &str) -> Result<reqwest::Url> {
+    let url = join_url(base_url, path)?;
+    #[cfg(any(test, feature = "test-utils"))]
+    let url = cassette::route(url);
+    Ok(url)
+}
+
+/// Sends a request with [`retry_response`] and deserializes the JSON response.
+async fn retry_request<F, T>(
+    client: reqwest::Client,
+    url: reqwest::Url,
+    method: reqwest::Method,
+    headers: reqwest::header::HeaderMap,
+    query_params: Vec<(&str, &str)>,
+    body_fn: F,
+    policy: &RetryPolicy,
+) -> Result<T>
+where
+    F: Fn() -> reqwest::Body,
+    T: DeserializeOwned + std::fmt::Debug,
+{
+    let resp = retry_response(client, url, method, headers, query_params, body_fn, policy).await?;
+
+    let status = resp.status();
+    let resp_text = resp.text().await.map_err(|e| Error::ApiError {
+        status_code: e.status(),
+        details: format!("Failed to read response: {e}"),
+    })?;
+
+    serde_json::from_str(&resp_text).map_err(|e| Error::ApiError {
+        status_code: Some(status),
+        details: format!("Failed to parse response: {e}"),
+    })
+}
+
 /// Shared retry logic for HTTP requests.
 ///
 /// This function handles:
@@ -105,79 +367,85 @@ fn calculate_backoff(
 /// - Retry-After header parsing
 /// - Retryable error detection (429, 502, 503, 504)
 /// - Request body reconstruction on each retry
-async fn retry_request<F, T>(
+///
+/// The successful response is returned without reading its body.
+async fn retry_response<F>(
+    client: reqwest::Client,
     url: reqwest::Url,
     method: reqwest::Method,
     headers: reqwest::header::HeaderMap,
     query_params: Vec<(&str, &str)>,
     body_fn: F,
-    config: RetryConfig,
-) -> Result<T>
+    policy: &RetryPolicy,
+) -> Result<reqwest::Response>
 where
     F: Fn() -> reqwest::Body,
-    T: DeserializeOwned + std::fmt::Debug,
 {
-    let client = reqwest::Client::new();
+    let started = Instant::now();
     let mut retry_count = 0;
 
     loop {
         // Reconstruct body for each attempt to avoid consumption issues
         let body = body_fn();
 
-        let resp = client
+        let resp = match client
             .request(method.clone(), url.clone())
             .headers(headers.clone())
             .query(&query_params)
             .body(body)
             .send()
             .await
-            .map_err(|e| {
-                // Check if error is retryable (timeout, connection error, etc.)
-                if e.is_timeout() || e.is_connect() {
+        {
+            Ok(resp) => resp,
+            Err(e) => {
+                // Retry connection errors and timeouts if the policy allows it
+                if policy.is_retryable_error(&e)
+                    && let Some(wait_time) = policy.next_wait(retry_count, started, None)
+                {
+                    retry_count += 1;
                     log::warn!(
-                        "Request failed with retryable error (attempt {}/{}): {}",
-                        retry_count + 1,
-                        config.max_retries + 1,
-                        e
+                        "Request failed with retryable error (attempt {}/{}): {}. Retrying after {:?}...",
+                        retry_count,
+                        policy.max_retries + 1,
+                        e,
+                        wait_time
                     );
-                } else {
-                    log::error!("Request failed: {e}");
+                    tokio::time::sleep(wait_time).await;
+                    continue;
                 }
 
-                Error::ApiError {
+                log::error!("Request failed: {e}");
+                return Err(Error::ApiError {
                     status_code: e.status(),
                     details: e.to_string(),
-                }
-            })?;
+                });
+            }
+        };
 
         let status = resp.status();
+        if status.is_success() {
+            log::debug!("Request succeeded on attempt {}", retry_count + 1);
+            return Ok(resp);
+        }
+
         let response_headers = resp.headers().clone();
         let resp_text = resp.text().await.map_err(|e| Error::ApiError {
             status_code: e.status(),
             details: format!("Failed to read response: {e}"),
         })?;
 
-        if status.is_success() {
-            log::debug!("Request succeeded on attempt {}", retry_count + 1);
-            return serde_json::from_str(&resp_text).map_err(|e| Error::ApiError {
-                status_code: Some(status),
-                details: format!("Failed to parse response: {e}"),
-            });
-        }
-
         // Check if error is retryable and we have retries left
-        if is_retryable_status(status) && retry_count < config.max_retries {
+        if policy.is_retryable_status(status)
+            && let Some(wait_time) =
+                policy.next_wait(retry_count, started, parse_retry_after(&response_headers))
+        {
             retry_count += 1;
 
-            // Parse Retry-After header if present
-            let retry_after = parse_retry_after(&response_headers);
-            let wait_time = calculate_backoff(retry_count - 1, &config, retry_after);
-
             log::warn!(
                 "Request failed with status {} (attempt {}/{}). Retrying after {:?}...",
                 status,
                 retry_count,
-                config.max_retries + 1,
+                policy.max_retries + 1,
                 wait_time
             );
 
@@ -186,9 +454,9 @@ where
         }
 
         // Non-retryable error or exhausted retries
-        if retry_count >= config.max_retries {
+        if policy.is_retryable_status(status) {
             log::error!(
-                "Request failed after {} retries with status {}: {}",
+                "Request failed after {} attempts with status {}: {}",
                 retry_count + 1,
                 status,
                 resp_text
@@ -214,38 +482,22 @@ pub(crate) trait LanguageModelClient {
     fn query_params(&self) -> Vec<(&str, &str)>;
     fn body(&self) -> reqwest::Body;
     fn headers(&self) -> reqwest::header::HeaderMap;
+    fn http_client(&self) -> reqwest::Client;
 
-    async fn send(&self, base_url: impl IntoUrl) -> Result<Self::Response> {
-        let url = join_url(base_url, &self.path())?;
-
-        // Serialize body once to avoid consumption issues on retries
-        let body_bytes = {
-            let body = self.body();
-            // Convert Body to bytes - this is the critical fix for retry body consumption
-            // We need to get the bytes from the body to be able to reconstruct it
-            match body.as_bytes() {
-                Some(bytes) => bytes.to_vec(),
-                None => {
-                    // If body doesn't have as_bytes (streaming body), we can't retry it
-                    // This should rarely happen for our use case (JSON bodies)
-                    log::warn!("Request body is not retryable (streaming body)");
-                    vec![]
-                }
-            }
-        };
-
-        let method = self.method();
-        let headers = self.headers();
-        let query_params = self.query_params();
-        let config = RetryConfig::default();
-
-        retry_request(
-            url,
-            method,
-            headers,
-            query_params,
-            move || reqwest::Body::from(body_bytes.clone()),
-            config,
+    async fn send(
+        &self,
+        base_url: impl IntoUrl,
+        retry_policy: &RetryPolicy,
+    ) -> Result<Self::Response> {
+        send_request(
+            self.http_client(),
+            base_url,
+            &self.path(),
+            self.method(),
+            self.headers(),
+            self.query_params(),
+            self.body(),
+            retry_policy,
         )
         .await
     }
@@ -261,28 +513,63 @@ pub(crate) trait LanguageModelClient {
     async fn send_and_stream(
         &self,
         base_url: impl IntoUrl,
+        retry_policy: &RetryPolicy,
     ) -> Result<Pin<Box<dyn Stream<Item = Result<Self::StreamEvent>> + Send>>>
     where
         Self::StreamEvent: Send + 'static,
         Self: Sync,
     {
-        let client = reqwest::Client::new();
-
-        let url = join_url(base_url, &self.path())?;
-
-        // Establish the event source stream directly
-        // Note: Status code errors (including 429) will be surfaced as stream events
-        // and should be handled by retry logic in the provider's stream_text() method
-        let events_stream = client
-            .request(self.method(), url.clone())
-            .headers(self.headers())
-            .query(&self.query_params())
-            .body(self.body())
-            .eventsource()
-            .map_err(|e| Error::ApiError {
-                status_code: None,
-                details: format!("SSE stream error: {e}"),
-            })?;
+        let client = self.http_client();
+
+        let url = request_url(base_url, &self.path())?;
+
+        let started = Instant::now();
+        let mut retry_count = 0;
+
+        // Establish the event source stream, retrying the connection setup.
+        // Errors after the connection is open are surfaced as stream events.
+        let events_stream = loop {
+            let mut event_source = client
+                .request(self.method(), url.clone())
+                .headers(self.headers())
+                .query(&self.query_params())
+                .body(self.body())
+                .eventsource()
+                .map_err(|e| Error::ApiError {
+                    status_code: None,
+                    details: format!("SSE stream error: {e}"),
+                })?;
+
+            let first = event_source.next().await;
+            let wait_time = match &first {
+                Some(Err(reqwest_eventsource::Error::InvalidStatusCode(status, resp)))
+                    if retry_policy.is_retryable_status(*status) =>
+                {
+                    retry_policy.next_wait(retry_count, started, parse_retry_after(resp.headers()))
+                }
+                Some(Err(reqwest_eventsource::Error::Transport(e)))
+                    if retry_policy.is_retryable_error(e) =>
+                {
+                    retry_policy.next_wait(retry_count, started, None)
+                }
+                _ => None,
+            };
+
+            match wait_time {
+                Some(wait_time) => {
+                    event_source.close();
+                    retry_count += 1;
+                    log::warn!(
+                        "Stream connection failed (attempt {}/{}). Retrying after {:?}...",
+                        retry_count,
+                        retry_policy.max_retries + 1,
+                        wait_time
+                    );
+                    tokio::time::sleep(wait_time).await;
+                }
+                None => break futures::stream::iter(first).chain(event_source),
+            }
+        };
 
         // Map events to deserialized StreamEvent ( ProviderStreamEvent )
         let mapped_stream = events_stream.map(|event_result| Self::parse_stream_sse(event_result));
@@ -306,9 +593,10 @@ pub(crate) trait LanguageModelClient {
         Ok(Box::pin(stream))
     }
 }
-/// Trait for embedding model clients to interact with embedding APIs.
+/// Trait for clients of non-streaming JSON APIs, such as the embedding, image generation,
+/// transcription, reranking and moderation APIs.
 #[allow(dead_code)]
-pub(crate) trait EmbeddingClient {
+pub(crate) trait JsonClient {
     type Response: DeserializeOwned + std::fmt::Debug + Clone;
 
     fn path(&self) -> String;
@@ -316,61 +604,122 @@ pub(crate) trait EmbeddingClient {
     fn query_params(&self) -> Vec<(&str, &str)>;
     fn body(&self) -> reqwest::Body;
     fn headers(&self) -> reqwest::header::HeaderMap;
+    fn http_client(&self) -> reqwest::Client;
 
-    async fn send(&self, base_url: impl IntoUrl) -> Result<Self::Response> {
-        let base_url = base_url
-            .into_url()
-            .map_err(|_| Error::InvalidInput("Invalid base URL".into()))?;
-
-        let url = join_url(base_url, &self.path())?;
-
-        // Serialize body once to avoid consumption issues on retries
-        let body_bytes = {
-            let body = self.body();
-            // Convert Body to bytes - this is the critical fix for retry body consumption
-            match body.as_bytes() {
-                Some(bytes) => bytes.to_vec(),
-                None => {
-                    // If body doesn't have as_bytes (streaming body), we can't retry it
-                    log::warn!("Request body is not retryable (streaming body)");
-                    vec![]
-                }
-            }
-        };
+    async fn send(
+        &self,
+        base_url: impl IntoUrl,
+        retry_policy: &RetryPolicy,
+    ) -> Result<Self::Response> {
+        send_request(
+            self.http_client(),
+            base_url,
+            &self.path(),
+            self.method(),
+            self.headers(),
+            self.query_params(),
+            self.body(),
+            retry_policy,
+        )
+        .await
+    }
+}
 
-        let method = self.method();
-        let headers = self.headers();
-        let query_params = self.query_params();
-        let config = RetryConfig::default();
+/// Trait for speech model clients to interact with text-to-speech APIs.
+///
+/// Unlike the other clients, the response is binary audio and is returned unread so
+/// that it can be buffered or streamed.
+#[allow(dead_code)]
+pub(crate) trait SpeechClient {
+    fn path(&self) -> String;
+    fn method(&self) -> reqwest::Method;
+    fn query_params(&self) -> Vec<(&str, &str)>;
+    fn body(&self) -> reqwest::Body;
+    fn headers(&self) -> reqwest::header::HeaderMap;
+    fn http_client(&self) -> reqwest::Client;
 
-        retry_request(
+    async fn send(
+        &self,
+        base_url: impl IntoUrl,
+        retry_policy: &RetryPolicy,
+    ) -> Result<reqwest::Response> {
+        let url = request_url(base_url, &self.path())?;
+        let body_bytes = buffered_body(self.body());
+
+        retry_response(
+            self.http_client(),
             url,
-            method,
-            headers,
-            query_params,
+            self.method(),
+            self.headers(),
+            self.query_params(),
             move || reqwest::Body::from(body_bytes.clone()),
-            config,
+            retry_policy,
         )
         .await
     }
 }
 
+/// Sends a non-streaming request with a buffered body and deserializes the JSON response.
+#[allow(clippy::too_many_arguments)]
+async fn send_request<T>(
+    http_client: reqwest::Client,
+    base_url: impl IntoUrl,
+    path: &str,
+    method: reqwest::Method,
+    headers: reqwest::header::HeaderMap,
+    query_params: Vec<(&str, &str)>,
+    body: reqwest::Body,
+    retry_policy: &RetryPolicy,
+) -> Result<T>
+where
+    T: DeserializeOwned + std::fmt::Debug,
+{
+    let url = request_url(base_url, path)?;
+    let body_bytes = buffered_body(body);
+
+    retry_request(
+        http_client,
+        url,
+        method,
+        headers,
+        query_params,
+        move || reqwest::Body::from(body_bytes.clone()),
+        retry_policy,
+    )
+    .await
+}
+
+/// Reads a request body into memory so it can be sent again on retries.
+fn buffered_body(body: reqwest::Body) -> Vec<u8> {
+    match body.as_bytes() {
+        Some(bytes) => bytes.to_vec(),
+        None => {
+            // If body doesn't have as_bytes (streaming body), we can't retry it
+            log::warn!("Request body is not retryable (streaming body)");
+            vec![]
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicUsize, Ordering};
 
-    /// Helper to create a custom RetryConfig for testing
+    /// Helper to create a custom RetryPolicy for testing
     fn test_config(
         max_retries: u32,
         initial_wait_ms: u64,
         max_wait_ms: u64,
         use_jitter: bool,
-    ) -> RetryConfig {
-        RetryConfig {
+    ) -> RetryPolicy {
+        RetryPolicy {
             max_retries,
             initial_wait: Duration::from_millis(initial_wait_ms),
             max_wait: Duration::from_millis(max_wait_ms),
             use_jitter,
+            ..Default::default()
         }
     }
 
@@ -642,11 +991,12 @@ mod tests {
 
     #[test]
     fn test_calculate_backoff_very_large_initial_wait() {
-        let config = RetryConfig {
+        let config = RetryPolicy {
             max_retries: 5,
             initial_wait: Duration::from_secs(1_000_000),
             max_wait: Duration::from_secs(2_000_000),
             use_jitter: false,
+            ..Default::default()
         };
 
         let result = calculate_backoff(0, &config, None);
@@ -655,11 +1005,12 @@ mod tests {
 
     #[test]
     fn test_calculate_backoff_overflow_protection() {
-        let config = RetryConfig {
+        let config = RetryPolicy {
             max_retries: 100,
             initial_wait: Duration::from_millis(u64::MAX / 2),
             max_wait: Duration::from_secs(60),
             use_jitter: false,
+            ..Default::default()
         };
 
         // This should saturate multiplication and get capped at max_wait
@@ -739,6 +1090,11 @@ mod tests {
     // Tests for is_retryable_status
     // ========================================================================
 
+    /// Checks a status against the default retry policy
+    fn is_retryable_status(status: reqwest::StatusCode) -> bool {
+        RetryPolicy::default().is_retryable_status(status)
+    }
+
     #[test]
     fn test_is_retryable_status_429() {
         assert!(is_retryable_status(reqwest::StatusCode::TOO_MANY_REQUESTS));
@@ -856,15 +1212,28 @@ mod tests {
 
     #[test]
     fn test_parse_retry_after_http_date_format() {
-        // HTTP date format is not currently supported, should return None
+        // A date in the past means the request can be retried immediately
         let mut headers = reqwest::header::HeaderMap::new();
         headers.insert(
             reqwest::header::RETRY_AFTER,
-            reqwest::header::HeaderValue::from_static("Wed, 21 Oct 2025 07:28:00 GMT"),
+            reqwest::header::HeaderValue::from_static("Wed, 21 Oct 2015 07:28:00 GMT"),
         );
 
         let result = parse_retry_after(&headers);
-        assert_eq!(result, None); // Not implemented yet
+        assert_eq!(result, Some(Duration::ZERO));
+    }
+
+    #[test]
+    fn test_parse_retry_after_future_http_date() {
+        let date = std::time::SystemTime::now() + Duration::from_secs(120);
+        let mut headers = reqwest::header::HeaderMap::new();
+        headers.insert(
+            reqwest::header::RETRY_AFTER,
+            reqwest::header::HeaderValue::from_str(&httpdate::fmt_http_date(date)).unwrap(),
+        );
+
+        let result = parse_retry_after(&headers).unwrap();
+        assert!(result > Duration::from_secs(115) && result <= Duration::from_secs(120));
     }
 
     #[test]
@@ -890,4 +1259,164 @@ mod tests {
         let result = parse_retry_after(&headers);
         assert_eq!(result, None); // Should fail to parse as u64
     }
+
+    // ========================================================================
+    // Tests for HttpClientConfig
+    // ========================================================================
+
+    #[test]
+    fn test_http_client_config_builds_client() {
+        let config = HttpClientConfig::builder()
+            .timeout(Duration::from_secs(60))
+            .connect_timeout(Duration::from_secs(5))
+            .proxy("http://proxy.internal:3128")
+            .default_header("x-tenant", "acme")
+            .build()
+            .unwrap();
+
+        assert_eq!(config.timeout, Some(Duration::from_secs(60)));
+        assert_eq!(config.default_headers.get("x-tenant").unwrap(), "acme");
+        assert!(config.into_client().is_ok());
+    }
+
+    #[test]
+    fn test_http_client_config_rejects_invalid_header() {
+        let config = HttpClientConfig::builder()
+            .default_header("invalid header", "value")
+            .build()
+            .unwrap();
+
+        assert!(matches!(config.into_client(), Err(Error::InvalidInput(_))));
+    }
+
+    #[test]
+    fn test_http_client_config_rejects_invalid_certificate() {
+        let config = HttpClientConfig::builder()
+            .root_certificate(b"not a certificate".to_vec())
+            .build()
+            .unwrap();
+
+        assert!(matches!(config.into_client(), Err(Error::InvalidInput(_))));
+    }
+
+    // ========================================================================
+    // Tests for RetryPolicy
+    // ========================================================================
+
+    #[test]
+    fn test_retry_policy_for_request_overrides() {
+        let provider_policy = RetryPolicy::default();
+
+        let options = LanguageModelOptions {
+            max_retries: Some(1),
+            ..Default::default()
+        };
+        assert_eq!(provider_policy.for_request(&options).max_retries, 1);
+
+        let options = LanguageModelOptions {
+            retry_policy: Some(RetryPolicy::none()),
+            ..Default::default()
+        };
+        assert_eq!(provider_policy.for_request(&options), RetryPolicy::none());
+
+        let options = LanguageModelOptions::default();
+        assert_eq!(provider_policy.for_request(&options), provider_policy);
+    }
+
+    #[test]
+    fn test_retry_policy_next_wait_respects_limits() {
+        let policy = RetryPolicy {
+            deadline: Some(Duration::from_secs(5)),
+            ..test_config(2, 1000, 30000, false)
+        };
+        let started = Instant::now();
+
+        assert_eq!(
+            policy.next_wait(0, started, None),
+            Some(Duration::from_secs(1))
+        );
+        assert_eq!(policy.next_wait(2, started, None), None);
+        assert_eq!(
+            policy.next_wait(1, started, Some(Duration::from_secs(10))),
+            None
+        );
+    }
+
+    /// Serves the given raw HTTP responses, one per connection, and counts the requests.
+    async fn serve(responses: Vec<&'static str>) -> (reqwest::Url, Arc<AtomicUsize>) {
+        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url =
+            reqwest::Url::parse(&format!("http://{}/", listener.local_addr().unwrap())).unwrap();
+        let requests = Arc::new(AtomicUsize::new(0));
+
+        let counter = requests.clone();
+        tokio::spawn(async move {
+            for response in responses {
+                let (mut socket, _) = listener.accept().await.unwrap();
+                let mut buf = [0u8; 4096];
+                let _ = socket.read(&mut buf).await;
+                counter.fetch_add(1, Ordering::SeqCst);
+                socket.write_all(response.as_bytes()).await.unwrap();
+                socket.shutdown().await.unwrap();
+            }
+        });
+
+        (url, requests)
+    }
+
+    const UNAVAILABLE: &str = "HTTP/1.1 503 Service Unavailable\r\nretry-after: 0\r\ncontent-length: 4\r\nconnection: close\r\n\r\nbusy";
+    const OK: &str = "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\ncontent-length: 11\r\nconnection: close\r\n\r\n{\"ok\":true}";
+
+    async fn send(url: reqwest::Url, policy: &RetryPolicy) -> Result<serde_json::Value> {
+        retry_request(
+            reqwest::Client::new(),
+            url,
+            reqwest::Method::POST,
+            reqwest::header::HeaderMap::new(),
+            vec![],
+            || reqwest::Body::from("{}"),
+            policy,
+        )
+        .await
+    }
+
+    #[tokio::test]
+    async fn test_retry_request_retries_retryable_status() {
+        let (url, requests) = serve(vec![UNAVAILABLE, UNAVAILABLE, OK]).await;
+
+        let result = send(url, &test_config(5, 10, 100, false)).await.unwrap();
+
+        assert_eq!(result, serde_json::json!({ "ok": true }));
+        assert_eq!(requests.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test]
+    async fn test_retry_request_stops_after_max_retries() {
+        let (url, requests) = serve(vec![UNAVAILABLE, UNAVAILABLE, OK]).await;
+
+        let result = send(url, &test_config(1, 10, 100, false)).await;
+
+        assert!(matches!(
+            result,
+            Err(Error::ApiError {
+                status_code: Some(reqwest::StatusCode::SERVICE_UNAVAILABLE),
+                ..
+            })
+        ));
+        assert_eq!(requests.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn test_retry_request_skips_non_retryable_status() {
+        let (url, requests) = serve(vec![UNAVAILABLE, OK]).await;
+        let policy = RetryPolicy {
+            retryable_statuses: vec![reqwest::StatusCode::TOO_MANY_REQUESTS],
+            ..test_config(5, 10, 100, false)
+        };
+
+        assert!(send(url, &policy).await.is_err());
+        assert_eq!(requests.load(Ordering::SeqCst), 1);
+    }
 }
@@ -0,0 +1,773 @@
+//! Record/replay cassettes for provider HTTP traffic.
+//!
+//! When a cassette is installed, every provider request is routed through a local
+//! stand-in server. In [`CassetteMode::Record`] the stand-in forwards requests to the
+//! real endpoint and writes each request/response pair, including SSE event streams,
+//! to a fixture file. In [`CassetteMode::Replay`] it serves the recorded responses
+//! without any network access, so provider tests run offline and deterministically.
+//!
+//! Requests are matched by method, URL and body. Body fields holding generated ids,
+//! timestamps and signatures (see [`VOLATILE_FIELDS`]) are left out of matching, so a
+//! conversation replays even when these values differ from the recording. API keys and
+//! other credentials are redacted before anything is written to disk and do not take
+//! part in matching.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use aisdk::core::client::cassette::{Cassette, CassetteMode};
+//!
+//! Cassette::new(CassetteMode::Replay, "tests/fixtures/cassettes")
+//!     .install()
+//!     .unwrap();
+//! ```
+//!
+//! The provider test suites install a cassette from the `AISDK_CASSETTE_MODE`
+//! (`record` or `replay`) and `AISDK_CASSETTE_DIR` environment variables through
+//! [`install_from_env`].
+
+use crate::core::utils::fnv1a;
+use crate::error::{Error, Result};
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD as BASE64;
+use parking_lot::Mutex;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::BTreeMap;
+use std::net::SocketAddr;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpStream;
+
+/// Environment variable selecting the cassette mode (`record` or `replay`).
+pub const CASSETTE_MODE_ENV: &str = "AISDK_CASSETTE_MODE";
+
+/// Environment variable overriding the fixture directory.
+pub const CASSETTE_DIR_ENV: &str = "AISDK_CASSETTE_DIR";
+
+/// Fixture directory used when [`CASSETTE_DIR_ENV`] is not set.
+pub const DEFAULT_CASSETTE_DIR: &str = "tests/fixtures/cassettes";
+
+/// Placeholder written in place of redacted secrets.
+const REDACTED: &str = "[REDACTED]";
+
+/// Request headers carrying credentials.
+const SECRET_HEADERS: &[&str] = &[
+    "authorization",
+    "proxy-authorization",
+    "x-api-key",
+    "api-key",
+    "x-goog-api-key",
+    "cookie",
+];
+
+/// Query parameters carrying credentials.
+const SECRET_QUERY_PARAMS: &[&str] = &["key", "api_key", "apikey", "access_token", "token"];
+
+/// Request body fields left out of matching, as their values change between runs.
+pub const VOLATILE_FIELDS: &[&str] = &[
+    "id",
+    "call_id",
+    "tool_call_id",
+    "tool_use_id",
+    "previous_response_id",
+    "created",
+    "created_at",
+    "timestamp",
+    "signature",
+    "thought_signature",
+    "encrypted_content",
+];
+
+/// Request headers that are not forwarded to the real endpoint.
+const HOP_HEADERS: &[&str] = &["host", "content-length", "connection", "accept-encoding"];
+
+/// The cassette installed for this process, if any.
+static INSTALLED: Mutex<Option<Installed>> = Mutex::new(None);
+
+#[derive(Debug, Clone)]
+struct Installed {
+    cassette: Cassette,
+    addr: SocketAddr,
+}
+
+/// Whether a cassette records live traffic or replays recorded traffic.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CassetteMode {
+    /// Forward requests to the real endpoints and write the interactions to disk.
+    Record,
+    /// Serve recorded interactions without network access.
+    Replay,
+}
+
+impl FromStr for CassetteMode {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.to_ascii_lowercase().as_str() {
+            "record" => Ok(Self::Record),
+            "replay" => Ok(Self::Replay),
+            _ => Err(Error::InvalidInput(format!(
+                "Invalid cassette mode '{s}', expected 'record' or 'replay'"
+            ))),
+        }
+    }
+}
+
+/// A set of recorded interactions stored as JSON files in a directory.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Cassette {
+    mode: CassetteMode,
+    dir: PathBuf,
+}
+
+impl Cassette {
+    /// Creates a cassette backed by the given fixture directory.
+    ///
+    /// # Parameters
+    ///
+    /// * `mode` - Whether to record or replay interactions.
+    /// * `dir` - The directory holding one JSON file per interaction.
+    pub fn new(mode: CassetteMode, dir: impl Into<PathBuf>) -> Self {
+        Self {
+            mode,
+            dir: dir.into(),
+        }
+    }
+
+    /// Creates a cassette from [`CASSETTE_MODE_ENV`] and [`CASSETTE_DIR_ENV`].
+    ///
+    /// # Returns
+    ///
+    /// `None` if no mode is set, or [`Error::InvalidInput`] if the mode is invalid.
+    pub fn from_env() -> Result<Option<Self>> {
+        let Ok(mode) = std::env::var(CASSETTE_MODE_ENV) else {
+            return Ok(None);
+        };
+        let dir = std::env::var(CASSETTE_DIR_ENV).unwrap_or_else(|_| DEFAULT_CASSETTE_DIR.into());
+        Ok(Some(Self::new(mode.parse()?, dir)))
+    }
+
+    /// Returns the mode of the cassette.
+    pub fn mode(&self) -> CassetteMode {
+        self.mode
+    }
+
+    /// Returns the fixture directory of the cassette.
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// Starts the stand-in server and routes all provider requests of this process
+    /// through it.
+    ///
+    /// Installing the same cassette again is a no-op, so every test may call this.
+    ///
+    /// # Returns
+    ///
+    /// The address of the stand-in server, or [`Error::InvalidInput`] if a different
+    /// cassette is already installed.
+    pub fn install(self) -> Result<SocketAddr> {
+        let mut installed = INSTALLED.lock();
+        if let Some(installed) = installed.as_ref() {
+            if installed.cassette == self {
+                return Ok(installed.addr);
+            }
+            return Err(Error::InvalidInput(
+                "A different cassette is already installed".to_string(),
+            ));
+        }
+
+        let addr = self.clone().start()?;
+        *installed = Some(Installed {
+            cassette: self,
+            addr,
+        });
+        Ok(addr)
+    }
+
+    /// Starts the stand-in server on a dedicated thread, so it outlives the runtime
+    /// of the test that started it.
+    fn start(self) -> Result<SocketAddr> {
+        let listener = std::net::TcpListener::bind("127.0.0.1:0")
+            .and_then(|l| l.set_nonblocking(true).map(|_| l))
+            .map_err(|e| Error::Other(format!("Failed to start cassette server: {e}")))?;
+        let addr = listener
+            .local_addr()
+            .map_err(|e| Error::Other(format!("Failed to start cassette server: {e}")))?;
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .map_err(|e| Error::Other(format!("Failed to start cassette server: {e}")))?;
+
+        std::thread::spawn(move || {
+            runtime.block_on(async move {
+                let Ok(listener) = tokio::net::TcpListener::from_std(listener) else {
+                    return;
+                };
+                let client = reqwest::Client::new();
+                while let Ok((socket, _)) = listener.accept().await {
+                    let cassette = self.clone();
+                    let client = client.clone();
+                    tokio::spawn(async move {
+                        if let Err(e) = cassette.handle(socket, client).await {
+                            log::error!("Cassette server failed to handle a request: {e}");
+                        }
+                    });
+                }
+            });
+        });
+
+        Ok(addr)
+    }
+
+    /// Serves a single request on the connection.
+    async fn handle(&self, mut socket: TcpStream, client: reqwest::Client) -> Result<()> {
+        let request = read_request(&mut socket).await?;
+        let response = match self.mode {
+            CassetteMode::Record => self.record(request, client).await,
+            CassetteMode::Replay => self.replay(request),
+        }
+        .unwrap_or_else(|e| RecordedResponse::error(502, &e.to_string()));
+        write_response(&mut socket, &response).await
+    }
+
+    /// Forwards the request to the real endpoint and writes the interaction to disk.
+    async fn record(
+        &self,
+        request: IncomingRequest,
+        client: reqwest::Client,
+    ) -> Result<RecordedResponse> {
+        let method = reqwest::Method::from_bytes(request.method.as_bytes())
+            .map_err(|e| Error::InvalidInput(format!("Invalid method: {e}")))?;
+        let mut upstream = client.request(method, request.upstream.clone());
+        for (name, value) in &request.headers {
+            if !HOP_HEADERS.contains(&name.as_str()) {
+                upstream = upstream.header(name, value);
+            }
+        }
+
+        let response = upstream
+            .body(request.body.clone())
+            .send()
+            .await
+            .map_err(|e| Error::ApiError {
+                status_code: e.status(),
+                details: e.to_string(),
+            })?;
+        let status = response.status().as_u16();
+        let mut headers = BTreeMap::new();
+        if let Some(content_type) = response.headers().get(reqwest::header::CONTENT_TYPE)
+            && let Ok(content_type) = content_type.to_str()
+        {
+            headers.insert("content-type".to_string(), content_type.to_string());
+        }
+        let body = response.bytes().await.map_err(|e| Error::ApiError {
+            status_code: e.status(),
+            details: format!("Failed to read response: {e}"),
+        })?;
+
+        let response = RecordedResponse::new(status, headers, &body);
+        let interaction = Interaction {
+            request: request.redacted(),
+            response: response.clone(),
+        }
+        .scrubbed(&request.secrets());
+
+        std::fs::create_dir_all(&self.dir)
+            .and_then(|_| {
+                let json = serde_json::to_string_pretty(&interaction)?;
+                std::fs::write(self.dir.join(interaction.request.file_name()), json)
+            })
+            .map_err(|e| Error::Other(format!("Failed to write cassette: {e}")))?;
+
+        Ok(response)
+    }
+
+    /// Looks up the recorded response for the request.
+    fn replay(&self, request: IncomingRequest) -> Result<RecordedResponse> {
+        let recorded = request.redacted();
+        let path = self.dir.join(recorded.file_name());
+        let Ok(json) = std::fs::read_to_string(&path) else {
+            return Ok(RecordedResponse::error(
+                404,
+                &format!(
+                    "No cassette recorded for {} {}, record it with {CASSETTE_MODE_ENV}=record",
+                    recorded.method, recorded.url
+                ),
+            ));
+        };
+        let interaction: Interaction = serde_json::from_str(&json)
+            .map_err(|e| Error::Other(format!("Invalid cassette {}: {e}", path.display())))?;
+        Ok(interaction.response)
+    }
+}
+
+/// Installs the cassette configured through the environment, if any.
+///
+/// # Returns
+///
+/// The mode of the installed cassette, or `None` if [`CASSETTE_MODE_ENV`] is not set.
+pub fn install_from_env() -> Result<Option<CassetteMode>> {
+    match Cassette::from_env()? {
+        Some(cassette) => {
+            let mode = cassette.mode();
+            cassette.install()?;
+            Ok(Some(mode))
+        }
+        None => Ok(None),
+    }
+}
+
+/// Rewrites a provider URL to go through the installed cassette, if any.
+pub(crate) fn route(url: reqwest::Url) -> reqwest::Url {
+    match INSTALLED.lock().as_ref() {
+        Some(installed) => route_via(installed.addr, &url),
+        None => url,
+    }
+}
+
+/// Encodes the real endpoint into the path of a stand-in server URL.
+fn route_via(addr: SocketAddr, url: &reqwest::Url) -> reqwest::Url {
+    let mut routed = url.clone();
+    let _ = routed.set_scheme("http");
+    let _ = routed.set_host(Some(&addr.ip().to_string()));
+    let _ = routed.set_port(Some(addr.port()));
+    routed.set_path(&format!(
+        "/{}/{}{}{}",
+        url.scheme(),
+        url.host_str().unwrap_or_default(),
+        url.port().map(|p| format!(":{p}")).unwrap_or_default(),
+        url.path()
+    ));
+    routed
+}
+
+/// A request received by the stand-in server.
+#[derive(Debug)]
+struct IncomingRequest {
+    method: String,
+    upstream: reqwest::Url,
+    headers: Vec<(String, String)>,
+    body: Vec<u8>,
+}
+
+impl IncomingRequest {
+    /// Returns the credential values sent with the request.
+    fn secrets(&self) -> Vec<String> {
+        let headers = self
+            .headers
+            .iter()
+            .filter(|(name, _)| SECRET_HEADERS.contains(&name.as_str()))
+            .map(|(_, value)| value.clone());
+        let query = self
+            .upstream
+            .query_pairs()
+            .filter(|(name, _)| SECRET_QUERY_PARAMS.contains(&name.as_ref()))
+            .map(|(_, value)| value.into_owned());
+        headers
+            .chain(query)
+            .flat_map(|secret| {
+                // Also catch the bare token of `Bearer <token>` style values.
+                let token = secret.split_whitespace().last().map(str::to_string);
+                [Some(secret), token]
+            })
+            .flatten()
+            .filter(|secret| secret.len() >= 8)
+            .collect()
+    }
+
+    /// Returns the request with its credentials replaced by a placeholder.
+    fn redacted(&self) -> RecordedRequest {
+        let mut url = self.upstream.clone();
+        let pairs: Vec<(String, String)> = self
+            .upstream
+            .query_pairs()
+            .map(|(name, value)| {
+                let value = if SECRET_QUERY_PARAMS.contains(&name.as_ref()) {
+                    REDACTED.to_string()
+                } else {
+                    value.into_owned()
+                };
+                (name.into_owned(), value)
+            })
+            .collect();
+        if pairs.is_empty() {
+            url.set_query(None);
+        } else {
+            url.query_pairs_mut().clear().extend_pairs(pairs);
+        }
+
+        let headers = self
+            .headers
+            .iter()
+            .filter(|(name, _)| !HOP_HEADERS.contains(&name.as_str()))
+            .map(|(name, value)| {
+                let value = if SECRET_HEADERS.contains(&name.as_str()) {
+                    REDACTED.to_string()
+                } else {
+                    value.clone()
+                };
+                (name.clone(), value)
+            })
+            .collect();
+
+        let body = match serde_json::from_slice::<Value>(&self.body) {
+            Ok(json) => json,
+            Err(_) => Value::String(String::from_utf8_lossy(&self.body).into_owned()),
+        };
+
+        RecordedRequest {
+            method: self.method.clone(),
+            url: url.to_string(),
+            headers,
+            body,
+        }
+    }
+}
+
+/// A recorded request/response pair.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct Interaction {
+    request: RecordedRequest,
+    response: RecordedResponse,
+}
+
+impl Interaction {
+    /// Replaces any leaked secret value in the interaction by a placeholder.
+    fn scrubbed(self, secrets: &[String]) -> Self {
+        if secrets.is_empty() {
+            return self;
+        }
+        let mut json = serde_json::to_string(&self).unwrap_or_default();
+        for secret in secrets {
+            json = json.replace(secret.as_str(), REDACTED);
+        }
+        serde_json::from_str(&json).unwrap_or(self)
+    }
+}
+
+/// A request as stored in a cassette, with credentials redacted.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct RecordedRequest {
+    method: String,
+    url: String,
+    headers: BTreeMap<String, String>,
+    body: Value,
+}
+
+impl RecordedRequest {
+    /// Returns the fixture file name, derived from the parts used for matching.
+    fn file_name(&self) -> String {
+        let host = reqwest::Url::parse(&self.url)
+            .ok()
+            .and_then(|url| url.host_str().map(str::to_string))
+            .unwrap_or_default();
+        let host: String = host
+            .chars()
+            .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+            .collect();
+        let key = format!(
+            "{} {}\n{}",
+            self.method,
+            self.url,
+            without_volatile_fields(&self.body)
+        );
+        format!("{host}-{:016x}.json", fnv1a(key.as_bytes()))
+    }
+}
+
+/// Returns the JSON value with the [`VOLATILE_FIELDS`] of all objects removed.
+fn without_volatile_fields(value: &Value) -> Value {
+    match value {
+        Value::Object(map) => Value::Object(
+            map.iter()
+                .filter(|(name, _)| !VOLATILE_FIELDS.contains(&name.as_str()))
+                .map(|(name, value)| (name.clone(), without_volatile_fields(value)))
+                .collect(),
+        ),
+        Value::Array(values) => Value::Array(values.iter().map(without_volatile_fields).collect()),
+        other => other.clone(),
+    }
+}
+
+/// A response as stored in a cassette.
+///
+/// Text bodies, including SSE event streams, are stored verbatim. Binary bodies are
+/// stored base64 encoded.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct RecordedResponse {
+    status: u16,
+    headers: BTreeMap<String, String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    body: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    body_base64: Option<String>,
+}
+
+impl RecordedResponse {
+    fn new(status: u16, headers: BTreeMap<String, String>, body: &[u8]) -> Self {
+        let (body, body_base64) = match std::str::from_utf8(body) {
+            Ok(text) => (Some(text.to_string()), None),
+            Err(_) => (None, Some(BASE64.encode(body))),
+        };
+        Self {
+            status,
+            headers,
+            body,
+            body_base64,
+        }
+    }
+
+    /// Creates a JSON error response produced by the stand-in itself.
+    fn error(status: u16, message: &str) -> Self {
+        let body = serde_json::json!({ "error": { "message": message } }).to_string();
+        let headers =
+            BTreeMap::from([("content-type".to_string(), "application/json".to_string())]);
+        Self::new(status, headers, body.as_bytes())
+    }
+
+    fn body_bytes(&self) -> Vec<u8> {
+        match (&self.body, &self.body_base64) {
+            (Some(body), _) => body.as_bytes().to_vec(),
+            (None, Some(encoded)) => BASE64.decode(encoded).unwrap_or_default(),
+            (None, None) => Vec::new(),
+        }
+    }
+}
+
+/// A raw HTTP/1.1 request read from a socket.
+#[derive(Debug)]
+pub(crate) struct HttpRequest {
+    pub method: String,
+    pub target: String,
+    /// Header names are lower case.
+    pub headers: Vec<(String, String)>,
+    pub body: Vec<u8>,
+}
+
+/// Reads an HTTP/1.1 request with a `content-length` delimited body.
+pub(crate) async fn read_http_request(socket: &mut TcpStream) -> Result<HttpRequest> {
+    let io_error = |e: std::io::Error| Error::Other(format!("Cassette server I/O error: {e}"));
+
+    let mut buf = Vec::new();
+    let header_end = loop {
+        if let Some(pos) = buf.windows(4).position(|w| w == b"\r\n\r\n") {
+            break pos + 4;
+        }
+        let mut chunk = [0u8; 4096];
+        let read = socket.read(&mut chunk).await.map_err(io_error)?;
+        if read == 0 {
+            return Err(Error::InvalidInput("Incomplete HTTP request".to_string()));
+        }
+        buf.extend_from_slice(&chunk[..read]);
+    };
+
+    let head = String::from_utf8_lossy(&buf[..header_end]).into_owned();
+    let mut lines = head.split("\r\n");
+    let mut request_line = lines.next().unwrap_or_default().split_whitespace();
+    let method = request_line.next().unwrap_or_default().to_string();
+    let target = request_line.next().unwrap_or_default().to_string();
+
+    let headers: Vec<(String, String)> = lines
+        .filter_map(|line| line.split_once(':'))
+        .map(|(name, value)| (name.trim().to_ascii_lowercase(), value.trim().to_string()))
+        .collect();
+    let content_length = headers
+        .iter()
+        .find(|(name, _)| name == "content-length")
+        .and_then(|(_, value)| value.parse::<usize>().ok())
+        .unwrap_or(0);
+
+    let mut body = buf[header_end..].to_vec();
+    while body.len() < content_length {
+        let mut chunk = [0u8; 4096];
+        let read = socket.read(&mut chunk).await.map_err(io_error)?;
+        if read == 0 {
+            break;
+        }
+        body.extend_from_slice(&chunk[..read]);
+    }
+
+    Ok(HttpRequest {
+        method,
+        target,
+        headers,
+        body,
+    })
+}
+
+/// Reads an HTTP/1.1 request whose target encodes the real endpoint.
+async fn read_request(socket: &mut TcpStream) -> Result<IncomingRequest> {
+    let HttpRequest {
+        method,
+        target,
+        headers,
+        body,
+    } = read_http_request(socket).await?;
+
+    // The target has the form `/{scheme}/{host}/{path}?{query}`.
+    let (scheme, rest) = target
+        .trim_start_matches('/')
+        .split_once('/')
+        .ok_or_else(|| Error::InvalidInput(format!("Invalid cassette target '{target}'")))?;
+    let upstream = reqwest::Url::parse(&format!("{scheme}://{rest}"))
+        .map_err(|e| Error::InvalidInput(format!("Invalid cassette target '{target}': {e}")))?;
+
+    Ok(IncomingRequest {
+        method,
+        upstream,
+        headers,
+        body,
+    })
+}
+
+/// Writes a response and closes the connection.
+async fn write_response(socket: &mut TcpStream, response: &RecordedResponse) -> Result<()> {
+    write_http_response(
+        socket,
+        response.status,
+        &response.headers,
+        &response.body_bytes(),
+    )
+    .await
+}
+
+/// Writes an HTTP/1.1 response with a `content-length` delimited body and closes the
+/// connection.
+pub(crate) async fn write_http_response(
+    socket: &mut TcpStream,
+    status: u16,
+    headers: &BTreeMap<String, String>,
+    body: &[u8],
+) -> Result<()> {
+    let reason = reqwest::StatusCode::from_u16(status)
+        .ok()
+        .and_then(|s| s.canonical_reason())
+        .unwrap_or("Unknown");
+
+    let mut head = format!("HTTP/1.1 {status} {reason}\r\n");
+    for (name, value) in headers {
+        head.push_str(&format!("{name}: {value}\r\n"));
+    }
+    head.push_str(&format!(
+        "content-length: {}\r\nconnection: close\r\n\r\n",
+        body.len()
+    ));
+
+    let io_error = |e: std::io::Error| Error::Other(format!("Cassette server I/O error: {e}"));
+    socket.write_all(head.as_bytes()).await.map_err(io_error)?;
+    socket.write_all(body).await.map_err(io_error)?;
+    socket.shutdown().await.map_err(io_error)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::client::stub_server::StubServer;
+
+    const API_KEY: &str = "sk-test-1234567890";
+
+    /// Serves an SSE response that echoes the API key, like a careless endpoint.
+    async fn upstream() -> (StubServer, reqwest::Url) {
+        let body = format!("data: {{\"key\":\"{API_KEY}\"}}\n\ndata: [DONE]\n\n");
+        let server = StubServer::start(200, "text/event-stream", body).await;
+        let url = reqwest::Url::parse(&server.url)
+            .unwrap()
+            .join("v1/responses")
+            .unwrap();
+        (server, url)
+    }
+
+    async fn post(addr: SocketAddr, url: &reqwest::Url, body: &str) -> (u16, String) {
+        let response = reqwest::Client::new()
+            .post(route_via(addr, url))
+            .bearer_auth(API_KEY)
+            .query(&[("key", API_KEY)])
+            .body(body.to_string())
+            .send()
+            .await
+            .unwrap();
+        (response.status().as_u16(), response.text().await.unwrap())
+    }
+
+    #[test]
+    fn test_route_via_encodes_upstream_in_path() {
+        let addr: SocketAddr = "127.0.0.1:4000".parse().unwrap();
+        let url = reqwest::Url::parse("https://api.openai.com/v1/responses?alt=sse").unwrap();
+        assert_eq!(
+            route_via(addr, &url).as_str(),
+            "http://127.0.0.1:4000/https/api.openai.com/v1/responses?alt=sse"
+        );
+    }
+
+    #[test]
+    fn test_cassette_mode_from_str() {
+        assert_eq!("Record".parse::<CassetteMode>(), Ok(CassetteMode::Record));
+        assert_eq!("replay".parse::<CassetteMode>(), Ok(CassetteMode::Replay));
+        assert!(matches!(
+            "live".parse::<CassetteMode>(),
+            Err(Error::InvalidInput(_))
+        ));
+    }
+
+    #[test]
+    fn test_volatile_fields_do_not_take_part_in_matching() {
+        let request = |call_id: &str| RecordedRequest {
+            method: "POST".to_string(),
+            url: "https://api.openai.com/v1/responses".to_string(),
+            headers: BTreeMap::new(),
+            body: serde_json::json!({
+                "model": "gpt-5-nano",
+                "input": [
+                    { "type": "function_call", "call_id": call_id, "name": "echo" },
+                    { "type": "function_call_output", "call_id": call_id, "output": "42" }
+                ]
+            }),
+        };
+
+        assert_eq!(
+            request("call_abc").file_name(),
+            request("call_xyz").file_name()
+        );
+
+        let mut other = request("call_abc");
+        other.body["input"][1]["output"] = "43".into();
+        assert_ne!(request("call_abc").file_name(), other.file_name());
+    }
+
+    #[tokio::test]
+    async fn test_record_then_replay_with_redacted_secrets() {
+        let dir = tempfile::tempdir().unwrap();
+        let (server, url) = upstream().await;
+        let body = r#"{"model":"gpt-5-nano","stream":true}"#;
+
+        let recorder = Cassette::new(CassetteMode::Record, dir.path())
+            .start()
+            .unwrap();
+        let (status, recorded) = post(recorder, &url, body).await;
+        assert_eq!(status, 200);
+        assert!(recorded.contains(API_KEY));
+
+        let files: Vec<_> = std::fs::read_dir(dir.path()).unwrap().collect();
+        assert_eq!(files.len(), 1);
+        let fixture = std::fs::read_to_string(files[0].as_ref().unwrap().path()).unwrap();
+        assert!(!fixture.contains(API_KEY));
+        assert!(fixture.contains(REDACTED));
+        assert!(fixture.contains("text/event-stream"));
+
+        // The upstream is gone, so the response must come from the cassette.
+        drop(server);
+        let player = Cassette::new(CassetteMode::Replay, dir.path())
+            .start()
+            .unwrap();
+        let (status, replayed) = post(player, &url, body).await;
+        assert_eq!(status, 200);
+        assert_eq!(replayed, recorded.replace(API_KEY, REDACTED));
+
+        let (status, missing) = post(player, &url, r#"{"model":"other"}"#).await;
+        assert_eq!(status, 404);
+        assert!(missing.contains("No cassette recorded"));
+    }
+}
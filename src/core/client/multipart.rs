@@ -0,0 +1,178 @@
+//! Encoding of `multipart/form-data` request bodies.
+//!
+//! The body is built in memory so it can be sent again on retries, and the boundary
+//! is derived from the content so that identical forms encode identically, which keeps
+//! recorded cassettes replayable.
+
+use crate::core::utils::fnv1a;
+
+/// A part of a multipart form.
+#[derive(Debug, Clone)]
+struct Part {
+    name: String,
+    filename: Option<String>,
+    media_type: Option<String>,
+    data: Vec<u8>,
+}
+
+/// A `multipart/form-data` form.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct MultipartForm {
+    parts: Vec<Part>,
+}
+
+impl MultipartForm {
+    /// Creates an empty form.
+    pub(crate) fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a text field.
+    pub(crate) fn text(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.parts.push(Part {
+            name: name.into(),
+            filename: None,
+            media_type: None,
+            data: value.into().into_bytes(),
+        });
+        self
+    }
+
+    /// Adds a text field if the value is set.
+    pub(crate) fn text_opt(
+        self,
+        name: impl Into<String>,
+        value: Option<impl Into<String>>,
+    ) -> Self {
+        match value {
+            Some(value) => self.text(name, value),
+            None => self,
+        }
+    }
+
+    /// Adds a file field.
+    pub(crate) fn file(
+        mut self,
+        name: impl Into<String>,
+        filename: impl Into<String>,
+        media_type: impl Into<String>,
+        data: impl Into<Vec<u8>>,
+    ) -> Self {
+        self.parts.push(Part {
+            name: name.into(),
+            filename: Some(filename.into()),
+            media_type: Some(media_type.into()),
+            data: data.into(),
+        });
+        self
+    }
+
+    /// Encodes the form, returning the `content-type` header value and the body.
+    pub(crate) fn finish(self) -> (String, Vec<u8>) {
+        // The fields are length-prefixed, so different forms cannot produce the same key.
+        let mut key = Vec::new();
+        for part in &self.parts {
+            let fields = [
+                Some(part.name.as_bytes()),
+                part.filename.as_deref().map(str::as_bytes),
+                part.media_type.as_deref().map(str::as_bytes),
+                Some(part.data.as_slice()),
+            ];
+            for field in fields {
+                match field {
+                    Some(field) => {
+                        key.extend_from_slice(&(field.len() as u64 + 1).to_le_bytes());
+                        key.extend_from_slice(field);
+                    }
+                    None => key.extend_from_slice(&0u64.to_le_bytes()),
+                }
+            }
+        }
+        let boundary = format!("aisdk-boundary-{:016x}", fnv1a(&key));
+
+        let mut body = Vec::new();
+        for part in self.parts {
+            body.extend_from_slice(format!("--{boundary}\r\n").as_bytes());
+            let mut disposition = format!(
+                "Content-Disposition: form-data; name=\"{}\"",
+                escape_quoted(&part.name)
+            );
+            if let Some(filename) = &part.filename {
+                disposition.push_str(&format!("; filename=\"{}\"", escape_quoted(filename)));
+            }
+            body.extend_from_slice(disposition.as_bytes());
+            body.extend_from_slice(b"\r\n");
+            if let Some(media_type) = &part.media_type {
+                let media_type: String = media_type
+                    .chars()
+                    .filter(|c| !matches!(c, '\r' | '\n'))
+                    .collect();
+                body.extend_from_slice(format!("Content-Type: {media_type}\r\n").as_bytes());
+            }
+            body.extend_from_slice(b"\r\n");
+            body.extend_from_slice(&part.data);
+            body.extend_from_slice(b"\r\n");
+        }
+        body.extend_from_slice(format!("--{boundary}--\r\n").as_bytes());
+
+        (format!("multipart/form-data; boundary={boundary}"), body)
+    }
+}
+
+/// Percent-encodes the characters that would end a quoted header parameter or inject
+/// further headers, as browsers do for form field and file names.
+fn escape_quoted(value: &str) -> String {
+    value
+        .replace('"', "%22")
+        .replace('\r', "%0D")
+        .replace('\n', "%0A")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_multipart_form_encoding() {
+        let form = || {
+            MultipartForm::new()
+                .text("model", "whisper-1")
+                .text_opt("language", None::<String>)
+                .file("file", "call.wav", "audio/wav", b"RIFF".to_vec())
+        };
+
+        let (content_type, body) = form().finish();
+        let boundary = content_type
+            .strip_prefix("multipart/form-data; boundary=")
+            .unwrap();
+
+        assert_eq!(
+            String::from_utf8(body).unwrap(),
+            format!(
+                "--{boundary}\r\nContent-Disposition: form-data; name=\"model\"\r\n\r\nwhisper-1\r\n\
+                 --{boundary}\r\nContent-Disposition: form-data; name=\"file\"; filename=\"call.wav\"\r\n\
+                 Content-Type: audio/wav\r\n\r\nRIFF\r\n--{boundary}--\r\n"
+            )
+        );
+
+        // Names cannot end the quoted parameter or inject headers.
+        let (_, body) = MultipartForm::new()
+            .file(
+                "fi\"le",
+                "a\"b\r\nX-Injected: 1.wav",
+                "audio/wav\r\nX-Injected: 2",
+                b"RIFF".to_vec(),
+            )
+            .finish();
+        let body = String::from_utf8(body).unwrap();
+        assert!(body.contains(
+            "Content-Disposition: form-data; name=\"fi%22le\"; filename=\"a%22b%0D%0AX-Injected: 1.wav\"\r\n\
+             Content-Type: audio/wavX-Injected: 2\r\n"
+        ));
+        assert!(!body.contains("\r\nX-Injected"));
+
+        // Identical forms are encoded identically, with any toolchain.
+        assert_eq!(form().finish().0, content_type);
+        assert_eq!(boundary, "aisdk-boundary-00aae81f540ba115");
+    }
+}
@@ -0,0 +1,84 @@
+//! A minimal HTTP server for testing clients against canned responses.
+//!
+//! Shares the HTTP/1.1 handling of the cassette stand-in server.
+
+use crate::core::client::cassette::{read_http_request, write_http_response};
+use std::collections::{BTreeMap, HashMap};
+use tokio::sync::mpsc;
+use tokio::task::JoinHandle;
+
+/// A request received by the [`StubServer`].
+#[derive(Debug, Clone)]
+pub(crate) struct StubRequest {
+    pub method: String,
+    pub path: String,
+    /// Header names are lower case.
+    pub headers: HashMap<String, String>,
+    pub body: Vec<u8>,
+}
+
+impl StubRequest {
+    /// Parses the request body as JSON.
+    pub fn json(&self) -> serde_json::Value {
+        serde_json::from_slice(&self.body).expect("request body is not JSON")
+    }
+}
+
+/// Serves the same response to every connection and records the requests.
+///
+/// The server stops when it is dropped.
+pub(crate) struct StubServer {
+    /// The base URL of the server, ending with a slash.
+    pub url: String,
+    requests: mpsc::UnboundedReceiver<StubRequest>,
+    task: JoinHandle<()>,
+}
+
+impl StubServer {
+    /// Starts a server answering every request with `status`, `content_type` and `body`.
+    pub async fn start(status: u16, content_type: &str, body: impl Into<Vec<u8>>) -> Self {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}/", listener.local_addr().unwrap());
+        let (tx, requests) = mpsc::unbounded_channel();
+
+        let body = body.into();
+        let headers = BTreeMap::from([("content-type".to_string(), content_type.to_string())]);
+
+        let task = tokio::spawn(async move {
+            while let Ok((mut socket, _)) = listener.accept().await {
+                let Ok(request) = read_http_request(&mut socket).await else {
+                    continue;
+                };
+                let _ = tx.send(StubRequest {
+                    method: request.method,
+                    path: request.target,
+                    headers: request.headers.into_iter().collect(),
+                    body: request.body,
+                });
+                let _ = write_http_response(&mut socket, status, &headers, &body).await;
+            }
+        });
+
+        Self {
+            url,
+            requests,
+            task,
+        }
+    }
+
+    /// Starts a server answering every request with a JSON body.
+    pub async fn json(body: serde_json::Value) -> Self {
+        Self::start(200, "application/json", body.to_string()).await
+    }
+
+    /// Returns the next recorded request.
+    pub async fn request(&mut self) -> StubRequest {
+        self.requests.recv().await.expect("stub server stopped")
+    }
+}
+
+impl Drop for StubServer {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
@@ -0,0 +1,150 @@
+//! Image model
+//!
+//! Defines the [`ImageModel`] trait implemented by providers that generate images
+//! from a text prompt, together with its options and response types.
+
+/// Image generation request builder and related types.
+#[cfg(feature = "image-model-request")]
+pub mod request;
+
+use crate::core::language_model::Usage;
+use crate::core::messages::DataContent;
+use crate::error::{Error, Result};
+use async_trait::async_trait;
+
+use derive_builder::Builder;
+#[cfg(feature = "image-model-request")]
+pub use request::ImageModelRequest;
+
+/// The options for image generation requests.
+#[derive(Debug, Clone, Default, Builder)]
+#[builder(setter(into), default, build_fn(error = "Error"))]
+pub struct ImageModelOptions {
+    /// The text description of the images to generate.
+    pub prompt: String,
+    /// The size of the images, e.g. `1024x1024`. Supported sizes depend on the model.
+    pub size: Option<String>,
+    /// The number of images to generate.
+    pub n: Option<usize>,
+    /// The seed used for generation, for providers that support reproducible images.
+    pub seed: Option<u64>,
+    /// The quality of the images, e.g. `standard`, `hd` or `high`.
+    pub quality: Option<String>,
+    /// The style of the images, e.g. `vivid` or `natural`.
+    pub style: Option<String>,
+}
+
+impl ImageModelOptions {
+    /// Returns the image options builder.
+    pub fn builder() -> ImageModelOptionsBuilder {
+        ImageModelOptionsBuilder::default()
+    }
+}
+
+/// The core trait abstracting the capabilities of an image generation model.
+#[async_trait]
+pub trait ImageModel: Clone + Send + Sync + std::fmt::Debug + 'static {
+    /// Generates images from the prompt in the options.
+    async fn generate_image(&self, options: ImageModelOptions) -> Result<ImageModelResponse>;
+}
+
+/// The response type for image generation requests.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ImageModelResponse {
+    /// The generated images.
+    pub images: Vec<GeneratedImage>,
+    /// Token usage of the request, for providers that report it.
+    pub usage: Option<Usage>,
+}
+
+/// A single generated image.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GeneratedImage {
+    /// The image content, either inline data or a URL to download it from.
+    pub data: DataContent,
+    /// The IANA media type of the image, if known.
+    pub media_type: Option<String>,
+    /// The prompt the provider used after rewriting the original one, if any.
+    pub revised_prompt: Option<String>,
+}
+
+impl GeneratedImage {
+    /// Creates a generated image, detecting the media type of inline data.
+    pub fn new(data: impl Into<DataContent>) -> Self {
+        let data = data.into();
+        let media_type = data
+            .to_bytes()
+            .and_then(|bytes| sniff_media_type(&bytes))
+            .map(str::to_string);
+
+        Self {
+            data,
+            media_type,
+            revised_prompt: None,
+        }
+    }
+
+    /// Sets the revised prompt of the image.
+    pub fn with_revised_prompt(mut self, revised_prompt: Option<String>) -> Self {
+        self.revised_prompt = revised_prompt;
+        self
+    }
+
+    /// Returns the raw image bytes, or `None` if the image is only available as a URL.
+    pub fn bytes(&self) -> Option<Vec<u8>> {
+        self.data.to_bytes()
+    }
+
+    /// Returns the URL of the image, or `None` if the image was returned inline.
+    pub fn url(&self) -> Option<&str> {
+        match &self.data {
+            DataContent::Url(url) => Some(url),
+            _ => None,
+        }
+    }
+}
+
+/// Detects the media type of common image formats from their magic bytes.
+fn sniff_media_type(bytes: &[u8]) -> Option<&'static str> {
+    match bytes {
+        [0x89, b'P', b'N', b'G', ..] => Some("image/png"),
+        [0xFF, 0xD8, 0xFF, ..] => Some("image/jpeg"),
+        [b'G', b'I', b'F', b'8', ..] => Some("image/gif"),
+        [
+            b'R',
+            b'I',
+            b'F',
+            b'F',
+            _,
+            _,
+            _,
+            _,
+            b'W',
+            b'E',
+            b'B',
+            b'P',
+            ..,
+        ] => Some("image/webp"),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_generated_image_detects_media_type() {
+        let png = GeneratedImage::new(DataContent::base64("iVBORw0KGgo="));
+        assert_eq!(png.media_type.as_deref(), Some("image/png"));
+        assert_eq!(png.bytes().unwrap()[..4], [0x89, b'P', b'N', b'G']);
+
+        let jpeg = GeneratedImage::new(vec![0xFF, 0xD8, 0xFF, 0xE0]);
+        assert_eq!(jpeg.media_type.as_deref(), Some("image/jpeg"));
+
+        let url = GeneratedImage::new(DataContent::url("https://example.com/a.png"));
+        assert_eq!(url.media_type, None);
+        assert_eq!(url.url(), Some("https://example.com/a.png"));
+        assert_eq!(url.bytes(), None);
+    }
+}
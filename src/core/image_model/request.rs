@@ -0,0 +1,252 @@
+use std::ops::{Deref, DerefMut};
+
+use crate::core::image_model::{ImageModel, ImageModelOptions, ImageModelResponse};
+use crate::error::Result;
+
+/// Options for image generation requests to be used by `generate_image`.
+#[derive(Debug, Clone)]
+pub struct ImageModelRequest<M: ImageModel> {
+    /// The image model to use for generating images.
+    pub model: M,
+    /// Prompt and configuration options for the image model request.
+    pub(crate) options: ImageModelOptions,
+}
+
+impl<M: ImageModel> ImageModelRequest<M> {
+    /// Creates a new builder for constructing an `ImageModelRequest`.
+    ///
+    /// This method initiates the type-state builder pattern, starting with the
+    /// [`ModelStage`] where you must specify the image model.
+    pub fn builder() -> ImageModelRequestBuilder<M> {
+        ImageModelRequestBuilder::default()
+    }
+
+    /// Generates images for the prompt.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing the generated images as inline data or URLs together with
+    /// their metadata, or an error if the request fails.
+    pub async fn generate_image(&self) -> Result<ImageModelResponse> {
+        self.model.generate_image(self.options.clone()).await
+    }
+}
+
+impl<M: ImageModel> Deref for ImageModelRequest<M> {
+    type Target = ImageModelOptions;
+
+    fn deref(&self) -> &Self::Target {
+        &self.options
+    }
+}
+
+impl<M: ImageModel> DerefMut for ImageModelRequest<M> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.options
+    }
+}
+
+/// Type-state markers for the `ImageModelRequestBuilder`.
+///
+/// These zero-sized types ensure the builder is used in the correct order,
+/// preventing invalid request configurations at compile time.
+///
+/// The initial builder state where the image model must be set.
+///
+/// Transitions to [`PromptStage`] after calling [`model`](ImageModelRequestBuilder::model).
+pub struct ModelStage {}
+
+/// The state where the prompt must be set.
+///
+/// Transitions to [`OptionsStage`] after calling [`prompt`](ImageModelRequestBuilder::prompt).
+pub struct PromptStage {}
+
+/// The final state where the image options can be configured before building.
+///
+/// Transitions to the completed `ImageModelRequest` after calling [`build`](ImageModelRequestBuilder::build).
+pub struct OptionsStage {}
+
+/// A type-state builder for constructing `ImageModelRequest` instances.
+///
+/// This builder uses phantom types to enforce a specific construction order,
+/// ensuring that required fields (the model and the prompt) are set before optional ones.
+///
+/// # Type Parameters
+///
+/// * `M` - The image model type.
+/// * `State` - The current builder state, determining available methods.
+pub struct ImageModelRequestBuilder<M: ImageModel, State = ModelStage> {
+    model: Option<M>,
+    options: ImageModelOptions,
+    state: std::marker::PhantomData<State>,
+}
+
+impl<M: ImageModel, State> Deref for ImageModelRequestBuilder<M, State> {
+    type Target = ImageModelOptions;
+
+    /// Dereferences to the underlying `ImageModelOptions`.
+    ///
+    /// This allows direct access to the options fields during building.
+    fn deref(&self) -> &Self::Target {
+        &self.options
+    }
+}
+
+impl<M: ImageModel, State> DerefMut for ImageModelRequestBuilder<M, State> {
+    /// Mutably dereferences to the underlying `ImageModelOptions`.
+    ///
+    /// This allows direct mutation of the options fields during building.
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.options
+    }
+}
+
+impl<M: ImageModel> ImageModelRequestBuilder<M> {
+    fn default() -> Self {
+        ImageModelRequestBuilder {
+            model: None,
+            options: ImageModelOptions::default(),
+            state: std::marker::PhantomData,
+        }
+    }
+}
+
+impl<M: ImageModel, State> ImageModelRequestBuilder<M, State> {
+    fn into_state<Next>(self) -> ImageModelRequestBuilder<M, Next> {
+        ImageModelRequestBuilder {
+            model: self.model,
+            options: self.options,
+            state: std::marker::PhantomData,
+        }
+    }
+}
+
+/// Methods available in the [`ModelStage`] state.
+impl<M: ImageModel> ImageModelRequestBuilder<M, ModelStage> {
+    /// Sets the image model for the request.
+    ///
+    /// This is the first required step in building a request.
+    ///
+    /// # Parameters
+    ///
+    /// * `model` - The image model instance to use.
+    ///
+    /// # Returns
+    ///
+    /// The builder in the [`PromptStage`] state.
+    pub fn model(mut self, model: M) -> ImageModelRequestBuilder<M, PromptStage> {
+        self.model = Some(model);
+        self.into_state()
+    }
+}
+
+/// Methods available in the [`PromptStage`] state.
+impl<M: ImageModel> ImageModelRequestBuilder<M, PromptStage> {
+    /// Sets the text description of the images to generate.
+    ///
+    /// # Parameters
+    ///
+    /// * `prompt` - The prompt describing the images.
+    ///
+    /// # Returns
+    ///
+    /// The builder in the [`OptionsStage`] state.
+    pub fn prompt(
+        mut self,
+        prompt: impl Into<String>,
+    ) -> ImageModelRequestBuilder<M, OptionsStage> {
+        self.options.prompt = prompt.into();
+        self.into_state()
+    }
+}
+
+/// Methods available in the [`OptionsStage`] state.
+impl<M: ImageModel> ImageModelRequestBuilder<M, OptionsStage> {
+    /// Sets the size of the images.
+    ///
+    /// # Parameters
+    ///
+    /// * `size` - The image size, e.g. `1024x1024`.
+    ///
+    /// # Returns
+    ///
+    /// The builder with the size set.
+    pub fn size(mut self, size: impl Into<String>) -> Self {
+        self.options.size = Some(size.into());
+        self
+    }
+
+    /// Sets the number of images to generate.
+    ///
+    /// # Parameters
+    ///
+    /// * `n` - The number of images.
+    ///
+    /// # Returns
+    ///
+    /// The builder with the number of images set.
+    pub fn n(mut self, n: usize) -> Self {
+        self.options.n = Some(n);
+        self
+    }
+
+    /// Sets the seed used for generation.
+    ///
+    /// # Parameters
+    ///
+    /// * `seed` - The seed value.
+    ///
+    /// # Returns
+    ///
+    /// The builder with the seed set.
+    pub fn seed(mut self, seed: u64) -> Self {
+        self.options.seed = Some(seed);
+        self
+    }
+
+    /// Sets the quality of the images.
+    ///
+    /// # Parameters
+    ///
+    /// * `quality` - The image quality, e.g. `standard`, `hd` or `high`.
+    ///
+    /// # Returns
+    ///
+    /// The builder with the quality set.
+    pub fn quality(mut self, quality: impl Into<String>) -> Self {
+        self.options.quality = Some(quality.into());
+        self
+    }
+
+    /// Sets the style of the images.
+    ///
+    /// # Parameters
+    ///
+    /// * `style` - The image style, e.g. `vivid` or `natural`.
+    ///
+    /// # Returns
+    ///
+    /// The builder with the style set.
+    pub fn style(mut self, style: impl Into<String>) -> Self {
+        self.options.style = Some(style.into());
+        self
+    }
+
+    /// Builds the `ImageModelRequest`.
+    ///
+    /// This method consumes the builder and returns the configured request.
+    ///
+    /// # Returns
+    ///
+    /// The constructed `ImageModelRequest`.
+    pub fn build(self) -> ImageModelRequest<M> {
+        let model = self
+            .model
+            .unwrap_or_else(|| unreachable!("Model must be set"));
+
+        ImageModelRequest {
+            model,
+            options: self.options,
+        }
+    }
+}
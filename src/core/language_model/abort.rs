@@ -0,0 +1,95 @@
+//! Cancellation of running generations.
+
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use tokio::sync::Notify;
+
+/// A handle to abort a running generation.
+///
+/// Clones share the same state, so a generation can be aborted from another task.
+/// Aborting stops the current model call, skips the remaining tool calls and sets
+/// the stop reason to [`StopReason::Aborted`](super::StopReason::Aborted).
+///
+/// A streaming generation is also aborted when its stream is dropped.
+///
+/// # Example
+///
+/// ```rust
+/// use aisdk::core::language_model::AbortHandle;
+///
+/// let handle = AbortHandle::new();
+/// let clone = handle.clone();
+///
+/// clone.abort();
+/// assert!(handle.is_aborted());
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct AbortHandle {
+    inner: Arc<AbortState>,
+}
+
+#[derive(Debug, Default)]
+struct AbortState {
+    aborted: AtomicBool,
+    notify: Notify,
+}
+
+impl AbortHandle {
+    /// Creates a new handle that has not been aborted.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Aborts the generations using this handle.
+    pub fn abort(&self) {
+        self.inner.aborted.store(true, Ordering::SeqCst);
+        self.inner.notify.notify_waiters();
+    }
+
+    /// Returns `true` once [`abort`](Self::abort) has been called.
+    pub fn is_aborted(&self) -> bool {
+        self.inner.aborted.load(Ordering::SeqCst)
+    }
+
+    /// Waits until the handle is aborted.
+    pub async fn aborted(&self) {
+        loop {
+            let notified = self.inner.notify.notified();
+            tokio::pin!(notified);
+            // Register for the notification before checking, so an abort in
+            // between is not missed.
+            notified.as_mut().enable();
+            if self.is_aborted() {
+                return;
+            }
+            notified.await;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[tokio::test]
+    async fn test_aborted_resolves_after_abort() {
+        let handle = AbortHandle::new();
+        let waiter = tokio::spawn({
+            let handle = handle.clone();
+            async move { handle.aborted().await }
+        });
+
+        tokio::time::sleep(Duration::from_millis(10)).await;
+        assert!(!waiter.is_finished());
+
+        handle.abort();
+        tokio::time::timeout(Duration::from_secs(1), waiter)
+            .await
+            .unwrap()
+            .unwrap();
+
+        // Resolves immediately once aborted.
+        handle.aborted().await;
+    }
+}
@@ -0,0 +1,151 @@
+//! Defines `AnyLanguageModel`, a type-erased language model for runtime provider selection.
+//!
+//! `LanguageModelRequest` is generic over its model, which ties the provider to a
+//! type known at compile time. `AnyLanguageModel` wraps any [`LanguageModel`]
+//! behind a trait object so the provider can be chosen from configuration instead.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//!# #[cfg(all(feature = "openai", feature = "anthropic"))]
+//!# {
+//! use aisdk::core::{AnyLanguageModel, LanguageModelRequest};
+//! use aisdk::providers::{Anthropic, OpenAI};
+//!
+//! async fn run(use_anthropic: bool) -> aisdk::Result<()> {
+//!     let model = if use_anthropic {
+//!         AnyLanguageModel::new(Anthropic::model_name("claude-sonnet-4-5"))
+//!     } else {
+//!         AnyLanguageModel::new(OpenAI::model_name("gpt-5"))
+//!     };
+//!
+//!     let result = LanguageModelRequest::builder()
+//!         .model(model)
+//!         .prompt("Hello!")
+//!         .build()
+//!         .generate_text()
+//!         .await?;
+//!
+//!     println!("{:?}", result.text());
+//!     Ok(())
+//! }
+//!# }
+//! ```
+
+use crate::core::capabilities::*;
+use crate::core::language_model::{
+    LanguageModel, LanguageModelOptions, LanguageModelResponse, ProviderStream,
+};
+use crate::error::Result;
+use async_trait::async_trait;
+
+/// Object-safe counterpart of [`LanguageModel`], implemented for every language model.
+#[async_trait]
+trait DynLanguageModel: Send + Sync + std::fmt::Debug {
+    fn name(&self) -> String;
+
+    async fn generate_text(
+        &mut self,
+        options: LanguageModelOptions,
+    ) -> Result<LanguageModelResponse>;
+
+    async fn stream_text(&mut self, options: LanguageModelOptions) -> Result<ProviderStream>;
+
+    fn clone_box(&self) -> Box<dyn DynLanguageModel>;
+}
+
+#[async_trait]
+impl<M: LanguageModel> DynLanguageModel for M {
+    fn name(&self) -> String {
+        LanguageModel::name(self)
+    }
+
+    async fn generate_text(
+        &mut self,
+        options: LanguageModelOptions,
+    ) -> Result<LanguageModelResponse> {
+        LanguageModel::generate_text(self, options).await
+    }
+
+    async fn stream_text(&mut self, options: LanguageModelOptions) -> Result<ProviderStream> {
+        LanguageModel::stream_text(self, options).await
+    }
+
+    fn clone_box(&self) -> Box<dyn DynLanguageModel> {
+        Box::new(self.clone())
+    }
+}
+
+/// A language model whose provider is selected at runtime.
+///
+/// Like [`DynamicModel`], `AnyLanguageModel` bypasses compile-time capability
+/// checking: every capability is assumed to be supported and unsupported
+/// features are reported by the provider API.
+pub struct AnyLanguageModel {
+    inner: Box<dyn DynLanguageModel>,
+}
+
+impl AnyLanguageModel {
+    /// Wraps a language model, erasing its provider and model types.
+    ///
+    /// # Parameters
+    ///
+    /// * `model` - Any configured language model, e.g. `OpenAI::model_name("gpt-5")`.
+    ///
+    /// # Returns
+    ///
+    /// The type-erased model.
+    pub fn new(model: impl LanguageModel) -> Self {
+        Self {
+            inner: Box::new(model),
+        }
+    }
+}
+
+impl Clone for AnyLanguageModel {
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone_box(),
+        }
+    }
+}
+
+impl std::fmt::Debug for AnyLanguageModel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("AnyLanguageModel")
+            .field(&self.inner)
+            .finish()
+    }
+}
+
+#[async_trait]
+impl LanguageModel for AnyLanguageModel {
+    fn name(&self) -> String {
+        self.inner.name()
+    }
+
+    async fn generate_text(
+        &mut self,
+        options: LanguageModelOptions,
+    ) -> Result<LanguageModelResponse> {
+        self.inner.generate_text(options).await
+    }
+
+    async fn stream_text(&mut self, options: LanguageModelOptions) -> Result<ProviderStream> {
+        self.inner.stream_text(options).await
+    }
+}
+
+// Capabilities are validated by the provider API at runtime, as for `DynamicModel`.
+impl ToolCallSupport for AnyLanguageModel {}
+impl StructuredOutputSupport for AnyLanguageModel {}
+impl ReasoningSupport for AnyLanguageModel {}
+impl TextInputSupport for AnyLanguageModel {}
+impl TextOutputSupport for AnyLanguageModel {}
+impl ImageInputSupport for AnyLanguageModel {}
+impl FileInputSupport for AnyLanguageModel {}
+impl VideoInputSupport for AnyLanguageModel {}
+impl AudioInputSupport for AnyLanguageModel {}
+impl ImageOutputSupport for AnyLanguageModel {}
+impl VideoOutputSupport for AnyLanguageModel {}
+impl AudioOutputSupport for AnyLanguageModel {}
@@ -0,0 +1,785 @@
+//! Typed structured output impl for the `LanguageModelRequest` trait.
+
+use crate::core::capabilities::StructuredOutputSupport;
+use crate::core::language_model::{
+    LanguageModel, LanguageModelOptions, request::LanguageModelRequest,
+};
+use crate::core::messages::{Message, TaggedMessage};
+use crate::error::{Error, Result};
+use schemars::{JsonSchema, Schema, schema_for};
+use serde::de::DeserializeOwned;
+use serde_json::{Map, Value, json};
+use std::ops::Deref;
+
+impl<M: LanguageModel + StructuredOutputSupport> LanguageModelRequest<M> {
+    /// Generates a structured object of type `T` using the language model.
+    ///
+    /// The schema of `T` is sent to the model and the generated text is parsed and
+    /// validated against it. When [`max_repair_attempts`](crate::core::language_model::request::LanguageModelRequestBuilder::max_repair_attempts)
+    /// is set, an invalid answer is sent back to the model together with the
+    /// validation error, asking it to correct the object.
+    ///
+    /// Types without an object root, such as unit-only enums used for classification,
+    /// are wrapped in an object for the model and unwrapped again in the response.
+    ///
+    /// For streaming partial objects, use [`stream_object`](Self::stream_object) instead.
+    ///
+    /// # Type Parameters
+    ///
+    /// * `T` - The type of the generated object.
+    ///
+    /// # Returns
+    ///
+    /// A [`GenerateObjectResponse`] containing the object and the conversation.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidObject`] if the final answer does not match the schema,
+    /// or any error of the underlying generation.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    ///# #[cfg(feature = "openai")]
+    ///# {
+    ///    use aisdk::{core::LanguageModelRequest, providers::OpenAI};
+    ///    use schemars::JsonSchema;
+    ///    use serde::Deserialize;
+    ///
+    ///    #[derive(Debug, Deserialize, JsonSchema)]
+    ///    struct Invoice {
+    ///        number: String,
+    ///        total: f64,
+    ///    }
+    ///
+    ///    async fn extract(text: &str) -> aisdk::Result<Invoice> {
+    ///        let result = LanguageModelRequest::builder()
+    ///            .model(OpenAI::gpt_5())
+    ///            .prompt(format!("Extract the invoice: {text}"))
+    ///            .max_repair_attempts(2u32)
+    ///            .build()
+    ///            .generate_object::<Invoice>()
+    ///            .await?;
+    ///
+    ///        println!("usage: {:?}", result.usage());
+    ///        Ok(result.object)
+    ///    }
+    ///# }
+    /// ```
+    pub async fn generate_object<T: JsonSchema + DeserializeOwned>(
+        &mut self,
+    ) -> Result<GenerateObjectResponse<T>> {
+        self.generate_output(OutputSchema::object(schema_for!(T)))
+            .await
+    }
+
+    /// Generates an array of elements of type `T` using the language model.
+    ///
+    /// Behaves like [`generate_object`](Self::generate_object), with the array wrapped
+    /// in an object root for the model and unwrapped again in the response.
+    ///
+    /// For streaming each element as it completes, use
+    /// [`stream_array`](Self::stream_array) instead.
+    ///
+    /// # Type Parameters
+    ///
+    /// * `T` - The type of the array elements.
+    ///
+    /// # Returns
+    ///
+    /// A [`GenerateObjectResponse`] containing the elements and the conversation.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidObject`] if the final answer does not match the schema,
+    /// or any error of the underlying generation.
+    pub async fn generate_array<T: JsonSchema + DeserializeOwned>(
+        &mut self,
+    ) -> Result<GenerateObjectResponse<Vec<T>>> {
+        self.generate_output(OutputSchema::array(schema_for!(T)))
+            .await
+    }
+
+    /// Classifies the input by asking the model to pick one of the given choices.
+    ///
+    /// To get a typed answer, call [`generate_object`](Self::generate_object) with a
+    /// unit-only enum instead.
+    ///
+    /// # Parameters
+    ///
+    /// * `choices` - The allowed answers, e.g. a set of labels.
+    ///
+    /// # Returns
+    ///
+    /// A [`GenerateObjectResponse`] containing the chosen answer and the conversation.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidObject`] if the model answers with anything else than
+    /// one of the choices, or any error of the underlying generation.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    ///# #[cfg(feature = "openai")]
+    ///# {
+    ///    use aisdk::{core::LanguageModelRequest, providers::OpenAI};
+    ///
+    ///    async fn label(document: &str) -> aisdk::Result<String> {
+    ///        let result = LanguageModelRequest::builder()
+    ///            .model(OpenAI::gpt_5())
+    ///            .prompt(format!("Classify this document: {document}"))
+    ///            .build()
+    ///            .generate_choice(["invoice", "contract", "other"])
+    ///            .await?;
+    ///
+    ///        Ok(result.object)
+    ///    }
+    ///# }
+    /// ```
+    pub async fn generate_choice<I, S>(
+        &mut self,
+        choices: I,
+    ) -> Result<GenerateObjectResponse<String>>
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        let choices: Vec<String> = choices.into_iter().map(Into::into).collect();
+        if choices.is_empty() {
+            return Err(Error::InvalidInput(
+                "generate_choice requires at least one choice".to_string(),
+            ));
+        }
+        self.generate_output(OutputSchema::choice(choices)).await
+    }
+
+    /// Generates text constrained to `output` and parses it, repairing invalid answers.
+    async fn generate_output<T: DeserializeOwned>(
+        &mut self,
+        output: OutputSchema,
+    ) -> Result<GenerateObjectResponse<T>> {
+        let mut request = LanguageModelRequest {
+            model: self.model.clone(),
+            prompt: self.prompt.clone(),
+            options: LanguageModelOptions {
+                schema: Some(output.schema().clone()),
+                ..self.options.clone()
+            },
+        };
+
+        let mut attempts = 0;
+        loop {
+            let response = request.generate_text().await?;
+            let text = response.text().unwrap_or_default();
+
+            match output.parse::<T>(&text) {
+                Ok(object) => {
+                    return Ok(GenerateObjectResponse {
+                        object,
+                        options: response.options,
+                    });
+                }
+                Err(Error::InvalidObject { details, .. })
+                    if attempts < self.options.max_repair_attempts.unwrap_or(0) =>
+                {
+                    attempts += 1;
+                    log::warn!(
+                        "Generated object is invalid (repair attempt {attempts}): {details}"
+                    );
+
+                    // Continue the conversation with the validation error.
+                    let mut options = response.options;
+                    options.messages.push(TaggedMessage::new(
+                        options.current_step_id,
+                        Message::User(repair_prompt(&details).into()),
+                    ));
+                    request.prompt = None;
+                    request.options = options;
+                }
+                Err(e) => return Err(e),
+            }
+        }
+    }
+}
+
+/// Builds the message asking the model to correct an invalid object.
+fn repair_prompt(details: &str) -> String {
+    format!(
+        "The previous answer does not match the required JSON schema: {details}. \
+         Respond again with only the corrected JSON object."
+    )
+}
+
+// ============================================================================
+// Section: response types
+// ============================================================================
+
+/// Response from a `generate_object` call.
+#[derive(Debug, Clone)]
+pub struct GenerateObjectResponse<T> {
+    /// The generated object.
+    pub object: T,
+    /// The options that generated this response, including every repair attempt.
+    pub options: LanguageModelOptions,
+}
+
+impl<T> GenerateObjectResponse<T> {
+    /// Consumes the response and returns the generated object.
+    pub fn into_object(self) -> T {
+        self.object
+    }
+}
+
+impl<T> Deref for GenerateObjectResponse<T> {
+    type Target = LanguageModelOptions;
+
+    fn deref(&self) -> &Self::Target {
+        &self.options
+    }
+}
+
+// ============================================================================
+// Section: parsing and validation
+// ============================================================================
+
+/// The schema sent to the model for a structured output.
+///
+/// Many providers (e.g. OpenAI) only accept an object at the root of a response
+/// schema, so other outputs are wrapped in an object with a single property.
+#[derive(Debug, Clone)]
+pub(crate) struct OutputSchema {
+    schema: Schema,
+    // The property holding the output, if the schema is wrapped.
+    key: Option<&'static str>,
+}
+
+impl OutputSchema {
+    /// The property holding the elements of an array output.
+    const ELEMENTS: &'static str = "elements";
+    /// The property holding any other wrapped output.
+    const RESULT: &'static str = "result";
+
+    /// An output of the given schema, wrapped if its root is not an object.
+    pub(crate) fn object(schema: Schema) -> Self {
+        if schema.get("type").and_then(Value::as_str) == Some("object") {
+            return Self { schema, key: None };
+        }
+        let title = schema
+            .get("title")
+            .and_then(Value::as_str)
+            .unwrap_or("Result")
+            .to_string();
+        Self::wrap(Self::RESULT, schema.to_value(), title)
+    }
+
+    /// An array output with elements of the given schema.
+    pub(crate) fn array(items: Schema) -> Self {
+        let title = items
+            .get("title")
+            .and_then(Value::as_str)
+            .map_or_else(|| "Elements".to_string(), |title| format!("{title}List"));
+        let mut items = items.to_value();
+        let defs = take_definitions(&mut items);
+        if let Some(items) = items.as_object_mut() {
+            items.remove("$schema");
+        }
+        let mut array = json!({ "type": "array", "items": items });
+        array.as_object_mut().expect("array schema").extend(defs);
+        Self::wrap(Self::ELEMENTS, array, title)
+    }
+
+    /// A string output constrained to the given choices.
+    pub(crate) fn choice(choices: Vec<String>) -> Self {
+        Self::wrap(
+            Self::RESULT,
+            json!({ "type": "string", "enum": choices }),
+            "Choice".to_string(),
+        )
+    }
+
+    /// Wraps `inner` as the only property `key` of an object schema.
+    fn wrap(key: &'static str, mut inner: Value, title: String) -> Self {
+        // Definitions move to the root, where `$ref`s of the inner schema point to.
+        let defs = take_definitions(&mut inner);
+        if let Some(inner) = inner.as_object_mut() {
+            inner.remove("$schema");
+            inner.remove("title");
+        }
+        let mut schema = json!({
+            "$schema": "https://json-schema.org/draft/2020-12/schema",
+            "title": title,
+            "type": "object",
+            "properties": { key: inner },
+            "required": [key],
+            "additionalProperties": false,
+        });
+        schema.as_object_mut().expect("object schema").extend(defs);
+        Self {
+            schema: Schema::try_from(schema).expect("object schema"),
+            key: Some(key),
+        }
+    }
+
+    /// The schema to send to the model.
+    pub(crate) fn schema(&self) -> &Schema {
+        &self.schema
+    }
+
+    /// Extracts the output from a (partial) value of the schema.
+    pub(crate) fn unwrap<'a>(&self, value: &'a Value) -> Option<&'a Value> {
+        match self.key {
+            Some(key) => value.get(key),
+            None => Some(value),
+        }
+    }
+
+    /// Parses generated text into the output of type `T`, validating it against the schema.
+    pub(crate) fn parse<T: DeserializeOwned>(&self, text: &str) -> Result<T> {
+        let value: Value = parse_object(text, &self.schema)?;
+        let output = self.unwrap(&value).cloned().unwrap_or_default();
+        serde_json::from_value(output).map_err(|e| Error::InvalidObject {
+            details: e.to_string(),
+            text: text.to_string(),
+        })
+    }
+
+    /// Parses the element at `index` of an array output, validating it against the
+    /// element schema.
+    pub(crate) fn parse_element<T: DeserializeOwned>(
+        &self,
+        value: &Value,
+        index: usize,
+    ) -> Result<T> {
+        let invalid = |details: String| Error::InvalidObject {
+            details,
+            text: value.to_string(),
+        };
+        let root = self.schema.as_value();
+        let items = root
+            .pointer(&format!("/properties/{}/items", Self::ELEMENTS))
+            .ok_or_else(|| invalid("the output is not an array".to_string()))?;
+        validate(value, items, root, &format!("/{}/{index}", Self::ELEMENTS))
+            .map_err(|e| invalid(e.to_string()))?;
+        serde_json::from_value(value.clone()).map_err(|e| invalid(e.to_string()))
+    }
+}
+
+/// Removes the definitions of a schema, returning them as root keywords.
+fn take_definitions(schema: &mut Value) -> Map<String, Value> {
+    let mut defs = Map::new();
+    if let Some(schema) = schema.as_object_mut() {
+        for keyword in ["$defs", "definitions"] {
+            if let Some(value) = schema.remove(keyword) {
+                defs.insert(keyword.to_string(), value);
+            }
+        }
+    }
+    defs
+}
+
+/// Parses generated text into a value of type `T`, validating it against `schema`.
+///
+/// Markdown code fences around the JSON document are ignored.
+pub(crate) fn parse_object<T: DeserializeOwned>(text: &str, schema: &Schema) -> Result<T> {
+    let invalid = |details: String| Error::InvalidObject {
+        details,
+        text: text.to_string(),
+    };
+
+    let value: Value = serde_json::from_str(strip_code_fence(text))
+        .map_err(|e| invalid(format!("invalid JSON: {e}")))?;
+    validate(&value, schema.as_value(), schema.as_value(), "")
+        .map_err(|e| invalid(e.to_string()))?;
+    serde_json::from_value(value).map_err(|e| invalid(e.to_string()))
+}
+
+/// Removes a surrounding markdown code fence, e.g. ```` ```json ... ``` ````.
+pub(crate) fn strip_code_fence(text: &str) -> &str {
+    let trimmed = text.trim();
+    let Some(rest) = trimmed.strip_prefix("```") else {
+        return trimmed;
+    };
+    let body = rest.split_once('\n').map_or("", |(_, body)| body);
+    body.trim_end().strip_suffix("```").unwrap_or(body).trim()
+}
+
+/// A value not matching the schema, located by its JSON pointer.
+#[derive(Debug)]
+struct ValidationError {
+    path: String,
+    message: String,
+}
+
+impl std::fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let path = if self.path.is_empty() {
+            "/"
+        } else {
+            &self.path
+        };
+        write!(f, "{path}: {}", self.message)
+    }
+}
+
+/// Validates a value against the subset of JSON schema produced by `schemars`.
+///
+/// Supports `$ref` into the root definitions, `type`, `enum`, `const`, object
+/// properties, arrays, the `anyOf`/`oneOf`/`allOf` combinators and numeric, string
+/// and array bounds. Other keywords such as `format` are not checked.
+fn validate(
+    value: &Value,
+    schema: &Value,
+    root: &Value,
+    path: &str,
+) -> std::result::Result<(), ValidationError> {
+    let error = |message: String| ValidationError {
+        path: path.to_string(),
+        message,
+    };
+
+    let schema = match schema {
+        Value::Bool(true) => return Ok(()),
+        Value::Bool(false) => return Err(error("no value is allowed here".to_string())),
+        Value::Object(schema) => schema,
+        _ => return Ok(()),
+    };
+
+    if let Some(reference) = schema.get("$ref").and_then(Value::as_str) {
+        let target = reference
+            .strip_prefix('#')
+            .and_then(|pointer| root.pointer(pointer))
+            .ok_or_else(|| error(format!("unresolved reference {reference}")))?;
+        validate(value, target, root, path)?;
+    }
+
+    if let Some(types) = schema.get("type") {
+        let allowed: Vec<&str> = match types {
+            Value::String(t) => vec![t.as_str()],
+            Value::Array(ts) => ts.iter().filter_map(Value::as_str).collect(),
+            _ => vec![],
+        };
+        if !allowed.is_empty() && !allowed.iter().any(|t| has_type(value, t)) {
+            return Err(error(format!(
+                "expected {}, found {}",
+                allowed.join(" or "),
+                type_name(value)
+            )));
+        }
+    }
+
+    if let Some(Value::Array(options)) = schema.get("enum")
+        && !options.contains(value)
+    {
+        return Err(error(format!(
+            "expected one of {}",
+            Value::Array(options.clone())
+        )));
+    }
+
+    if let Some(expected) = schema.get("const")
+        && expected != value
+    {
+        return Err(error(format!("expected {expected}")));
+    }
+
+    if let Some(Value::Array(all)) = schema.get("allOf") {
+        for sub in all {
+            validate(value, sub, root, path)?;
+        }
+    }
+
+    for keyword in ["anyOf", "oneOf"] {
+        if let Some(Value::Array(options)) = schema.get(keyword)
+            && !options
+                .iter()
+                .any(|sub| validate(value, sub, root, path).is_ok())
+        {
+            return Err(error(
+                "does not match any of the allowed schemas".to_string(),
+            ));
+        }
+    }
+
+    match value {
+        Value::Object(map) => {
+            if let Some(Value::Array(required)) = schema.get("required") {
+                for key in required.iter().filter_map(Value::as_str) {
+                    if !map.contains_key(key) {
+                        return Err(error(format!("missing required property '{key}'")));
+                    }
+                }
+            }
+            let properties = schema.get("properties").and_then(Value::as_object);
+            for (key, item) in map {
+                let item_path = format!("{path}/{key}");
+                match properties.and_then(|p| p.get(key)) {
+                    Some(sub) => validate(item, sub, root, &item_path)?,
+                    None => {
+                        if let Some(additional) = schema.get("additionalProperties") {
+                            validate(item, additional, root, &item_path)
+                                .map_err(|_| error(format!("unexpected property '{key}'")))?;
+                        }
+                    }
+                }
+            }
+        }
+        Value::Array(items) => {
+            if let Some(min) = schema.get("minItems").and_then(Value::as_u64)
+                && (items.len() as u64) < min
+            {
+                return Err(error(format!("expected at least {min} items")));
+            }
+            if let Some(max) = schema.get("maxItems").and_then(Value::as_u64)
+                && (items.len() as u64) > max
+            {
+                return Err(error(format!("expected at most {max} items")));
+            }
+            if let Some(sub) = schema.get("items") {
+                for (i, item) in items.iter().enumerate() {
+                    validate(item, sub, root, &format!("{path}/{i}"))?;
+                }
+            }
+        }
+        Value::String(s) => {
+            let len = s.chars().count() as u64;
+            if let Some(min) = schema.get("minLength").and_then(Value::as_u64)
+                && len < min
+            {
+                return Err(error(format!("expected at least {min} characters")));
+            }
+            if let Some(max) = schema.get("maxLength").and_then(Value::as_u64)
+                && len > max
+            {
+                return Err(error(format!("expected at most {max} characters")));
+            }
+        }
+        Value::Number(n) => {
+            let n = n.as_f64().unwrap_or_default();
+            if let Some(min) = schema.get("minimum").and_then(Value::as_f64)
+                && n < min
+            {
+                return Err(error(format!("expected a value >= {min}")));
+            }
+            if let Some(max) = schema.get("maximum").and_then(Value::as_f64)
+                && n > max
+            {
+                return Err(error(format!("expected a value <= {max}")));
+            }
+        }
+        Value::Bool(_) | Value::Null => {}
+    }
+
+    Ok(())
+}
+
+fn has_type(value: &Value, expected: &str) -> bool {
+    match expected {
+        "integer" => value.as_i64().is_some() || value.as_u64().is_some(),
+        "number" => value.is_number(),
+        other => type_name(value) == other,
+    }
+}
+
+fn type_name(value: &Value) -> &'static str {
+    match value {
+        Value::Null => "null",
+        Value::Bool(_) => "boolean",
+        Value::Number(_) => "number",
+        Value::String(_) => "string",
+        Value::Array(_) => "array",
+        Value::Object(_) => "object",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::language_model::{LanguageModelResponse, MockLanguageModel};
+    use serde::Deserialize;
+
+    #[derive(Debug, PartialEq, Deserialize, JsonSchema)]
+    enum Priority {
+        Low,
+        High,
+    }
+
+    #[derive(Debug, PartialEq, Deserialize, JsonSchema)]
+    struct Ticket {
+        title: String,
+        #[schemars(range(min = 1, max = 5))]
+        severity: u8,
+        priority: Priority,
+        tags: Vec<String>,
+        assignee: Option<String>,
+    }
+
+    fn request(model: &MockLanguageModel) -> LanguageModelRequest<MockLanguageModel> {
+        LanguageModelRequest::builder()
+            .model(model.clone())
+            .prompt("Create a ticket")
+            .build()
+    }
+
+    #[test]
+    fn test_parse_object_validates_against_schema() {
+        let schema = schema_for!(Ticket);
+        let ticket: Ticket = parse_object(
+            "```json\n{\"title\":\"Login\",\"severity\":2,\"priority\":\"High\",\"tags\":[]}\n```",
+            &schema,
+        )
+        .unwrap();
+        assert_eq!(ticket.priority, Priority::High);
+
+        let cases = [
+            (
+                r#"{"severity":2,"priority":"High","tags":[]}"#,
+                "/: missing required property 'title'",
+            ),
+            (
+                r#"{"title":"x","severity":9,"priority":"High","tags":[]}"#,
+                "/severity: expected a value <= 5",
+            ),
+            (
+                r#"{"title":"x","severity":2,"priority":"Urgent","tags":[]}"#,
+                "/priority: expected one of",
+            ),
+            (
+                r#"{"title":"x","severity":2,"priority":"Low","tags":[1]}"#,
+                "/tags/0: expected string, found number",
+            ),
+            ("not json", "invalid JSON"),
+        ];
+        for (text, expected) in cases {
+            match parse_object::<Ticket>(text, &schema) {
+                Err(Error::InvalidObject { details, .. }) => {
+                    assert!(details.starts_with(expected), "{details}")
+                }
+                other => panic!("unexpected result for {text}: {other:?}"),
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_generate_object_sends_schema() {
+        let model = MockLanguageModel::new()
+            .with_text(r#"{"title":"Login","severity":1,"priority":"Low","tags":["auth"]}"#);
+
+        let result = request(&model).generate_object::<Ticket>().await.unwrap();
+
+        assert_eq!(result.object.tags, vec!["auth".to_string()]);
+        assert!(model.last_call().unwrap().schema.is_some());
+    }
+
+    #[tokio::test]
+    async fn test_generate_object_repairs_invalid_output() {
+        let model = MockLanguageModel::new()
+            .with_text(r#"{"title":"Login","severity":7,"priority":"Low","tags":[]}"#)
+            .with_response(LanguageModelResponse::new(
+                r#"{"title":"Login","severity":5,"priority":"Low","tags":[]}"#,
+            ));
+
+        let mut request = LanguageModelRequest::builder()
+            .model(model.clone())
+            .prompt("Create a ticket")
+            .max_repair_attempts(1u32)
+            .build();
+        let result = request.generate_object::<Ticket>().await.unwrap();
+
+        assert_eq!(result.object.severity, 5);
+        assert_eq!(result.steps().len(), 3);
+        let repair = model.last_call().unwrap().messages();
+        assert!(matches!(
+            repair.last(),
+            Some(Message::User(m)) if m.content.to_string().contains("/severity: expected a value <= 5")
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_generate_object_fails_without_repair_attempts() {
+        let model = MockLanguageModel::new().with_text(r#"{"title":"Login"}"#);
+
+        let result = request(&model).generate_object::<Ticket>().await;
+
+        assert!(matches!(result, Err(Error::InvalidObject { .. })));
+        assert_eq!(model.calls().len(), 1);
+    }
+
+    #[test]
+    fn test_output_schema_wraps_non_object_roots() {
+        let object = OutputSchema::object(schema_for!(Ticket));
+        assert_eq!(object.schema(), &schema_for!(Ticket));
+
+        let array = OutputSchema::array(schema_for!(Ticket));
+        let root = array.schema().as_value();
+        assert_eq!(root["type"], "object");
+        assert_eq!(root["required"], json!(["elements"]));
+        assert_eq!(root["properties"]["elements"]["type"], "array");
+        // Definitions referenced by the elements move to the root.
+        assert!(root.pointer("/$defs/Priority").is_some());
+
+        let tickets: Vec<Ticket> = array
+            .parse(r#"{"elements":[{"title":"Login","severity":1,"priority":"Low","tags":[]}]}"#)
+            .unwrap();
+        assert_eq!(tickets[0].title, "Login");
+        assert!(matches!(
+            array.parse::<Vec<Ticket>>(r#"{"elements":[{"title":"Login"}]}"#),
+            Err(Error::InvalidObject { details, .. }) if details.starts_with("/elements/0:")
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_generate_array_unwraps_elements() {
+        let model = MockLanguageModel::new().with_text(
+            r#"{"elements":[
+                {"title":"Login","severity":1,"priority":"Low","tags":[]},
+                {"title":"Logout","severity":2,"priority":"High","tags":[]}
+            ]}"#,
+        );
+
+        let result = request(&model).generate_array::<Ticket>().await.unwrap();
+
+        let titles: Vec<&str> = result.object.iter().map(|t| t.title.as_str()).collect();
+        assert_eq!(titles, vec!["Login", "Logout"]);
+        let schema = model.last_call().unwrap().schema.unwrap();
+        assert_eq!(schema.get("type"), Some(&json!("object")));
+    }
+
+    #[tokio::test]
+    async fn test_generate_object_wraps_enum() {
+        let model = MockLanguageModel::new().with_text(r#"{"result":"High"}"#);
+
+        let result = request(&model).generate_object::<Priority>().await.unwrap();
+
+        assert_eq!(result.object, Priority::High);
+    }
+
+    #[tokio::test]
+    async fn test_generate_choice_constrains_answer() {
+        let model = MockLanguageModel::new()
+            .with_text(r#"{"result":"spam"}"#)
+            .with_text(r#"{"result":"ham"}"#);
+
+        let result = request(&model).generate_choice(["ham", "eggs"]).await;
+        assert!(matches!(
+            result,
+            Err(Error::InvalidObject { details, .. }) if details.starts_with("/result: expected one of")
+        ));
+
+        let result = request(&model)
+            .generate_choice(["ham", "eggs"])
+            .await
+            .unwrap();
+        assert_eq!(result.object, "ham");
+        let schema = model.last_call().unwrap().schema.unwrap();
+        assert_eq!(
+            schema.as_value().pointer("/properties/result/enum"),
+            Some(&json!(["ham", "eggs"]))
+        );
+
+        assert!(matches!(
+            request(&model).generate_choice(Vec::<String>::new()).await,
+            Err(Error::InvalidInput(_))
+        ));
+    }
+}
@@ -72,99 +72,173 @@ impl<M: LanguageModel> LanguageModelRequest<M> {
             schema: self.options.schema.to_owned(),
             stop_sequences: self.options.stop_sequences.to_owned(),
             tools: self.options.tools.to_owned(),
+            tool_choice: self.options.tool_choice.clone(),
+            retry_policy: self.options.retry_policy.clone(),
+            abort_handle: self.options.abort_handle.clone(),
+            tool_approvals: self.options.tool_approvals.clone(),
+            tool_context: self.options.tool_context.clone(),
             stop_when: self.options.stop_when.clone(),
             on_step_start: self.options.on_step_start.clone(),
             on_step_finish: self.options.on_step_finish.clone(),
+            input_guardrail: self.options.input_guardrail.clone(),
             stop_reason: None,
             ..self.options
         };
 
-        loop {
-            // Update the current step
-            options.current_step_id += 1;
+        let abort = self.options.abort_handle.clone();
+        let generation = async {
+            // Check the input before anything reaches the model or the tools
+            if let Some(hook) = options.input_guardrail.clone() {
+                let verdict = hook(&options).await.inspect_err(|e| {
+                    options.stop_reason = Some(StopReason::Error(e.clone()));
+                })?;
+                if let Some(reason) = verdict {
+                    options.stop_reason = Some(StopReason::Guardrail(reason));
+                    return Ok(());
+                }
+            }
 
-            // Prepare the next step
-            if let Some(hook) = options.on_step_start.clone() {
-                hook(&mut options);
+            // Run the tool calls a resumed conversation stopped at
+            let unresolved = options.unresolved_tool_calls();
+            let outcome = options.handle_tool_calls(&unresolved).await;
+            if let Some(stop_reason) = outcome.stop_reason() {
+                options.stop_reason = Some(stop_reason);
+                return Ok(());
             }
 
-            let response: LanguageModelResponse = self
-                .model
-                .generate_text(options.clone())
-                .await
-                .inspect_err(|e| {
-                options.stop_reason = Some(StopReason::Error(e.clone()));
-            })?;
-
-            for output in response.contents.iter() {
-                match output {
-                    LanguageModelResponseContentType::Text(text) => {
-                        let assistant_msg = Message::Assistant(AssistantMessage {
-                            content: text.clone().into(),
-                            usage: response.usage.clone(),
-                        });
-                        options
-                            .messages
-                            .push(TaggedMessage::new(options.current_step_id, assistant_msg));
-                    }
-                    LanguageModelResponseContentType::Reasoning {
-                        content,
-                        extensions,
-                    } => {
-                        let assistant_msg = Message::Assistant(AssistantMessage {
-                            content: LanguageModelResponseContentType::Reasoning {
-                                content: content.clone(),
-                                extensions: extensions.clone(),
-                            },
-                            usage: response.usage.clone(),
-                        });
-                        options
-                            .messages
-                            .push(TaggedMessage::new(options.current_step_id, assistant_msg));
-                    }
-                    LanguageModelResponseContentType::ToolCall(tool_info) => {
-                        // add tool message
-                        let usage = response.usage.clone();
-                        let _ = &options.messages.push(TaggedMessage::new(
-                            options.current_step_id.to_owned(),
-                            Message::Assistant(AssistantMessage::new(
-                                LanguageModelResponseContentType::ToolCall(tool_info.clone()),
+            loop {
+                // Update the current step
+                options.current_step_id += 1;
+
+                // Prepare the next step
+                if let Some(hook) = options.on_step_start.clone() {
+                    hook(&mut options);
+                }
+
+                let response: LanguageModelResponse = self
+                    .model
+                    .generate_text(options.clone())
+                    .await
+                    .inspect_err(|e| {
+                        options.stop_reason = Some(StopReason::Error(e.clone()));
+                    })?;
+
+                // The usage covers the whole response, so only the last message carries it.
+                let last = response.contents.iter().rposition(|output| {
+                    matches!(
+                        output,
+                        LanguageModelResponseContentType::Text(_)
+                            | LanguageModelResponseContentType::Reasoning { .. }
+                            | LanguageModelResponseContentType::ToolCall(_)
+                    )
+                });
+                let mut tool_calls = Vec::new();
+                for (index, output) in response.contents.iter().enumerate() {
+                    let usage = if Some(index) == last {
+                        response.usage.clone()
+                    } else {
+                        None
+                    };
+                    match output {
+                        LanguageModelResponseContentType::Text(text) => {
+                            let assistant_msg = Message::Assistant(AssistantMessage {
+                                content: text.clone().into(),
                                 usage,
-                            )),
-                        ));
-                        options.handle_tool_call(tool_info).await;
+                            });
+                            options
+                                .messages
+                                .push(TaggedMessage::new(options.current_step_id, assistant_msg));
+                        }
+                        LanguageModelResponseContentType::Reasoning {
+                            content,
+                            extensions,
+                        } => {
+                            let assistant_msg = Message::Assistant(AssistantMessage {
+                                content: LanguageModelResponseContentType::Reasoning {
+                                    content: content.clone(),
+                                    extensions: extensions.clone(),
+                                },
+                                usage,
+                            });
+                            options
+                                .messages
+                                .push(TaggedMessage::new(options.current_step_id, assistant_msg));
+                        }
+                        LanguageModelResponseContentType::ToolCall(tool_info) => {
+                            // add tool message
+                            let _ = &options.messages.push(TaggedMessage::new(
+                                options.current_step_id.to_owned(),
+                                Message::Assistant(AssistantMessage::new(
+                                    LanguageModelResponseContentType::ToolCall(tool_info.clone()),
+                                    usage,
+                                )),
+                            ));
+                            tool_calls.push(tool_info.clone());
+                        }
+                        _ => (),
                     }
-                    _ => (),
                 }
-            }
 
-            // Finish the step
-            if let Some(ref hook) = options.on_step_finish {
-                hook(&options);
-            };
+                // Run the tool calls of this step concurrently
+                let outcome = options.handle_tool_calls(&tool_calls).await;
+                if !tool_calls.is_empty() {
+                    options.relax_tool_choice();
+                }
 
-            if response.contents.is_empty() {
-                options.stop_reason = Some(StopReason::Error(Error::Other(
-                    "Language model returned empty response".to_string(),
-                )));
-                break;
-            }
+                // Finish the step
+                if let Some(ref hook) = options.on_step_finish {
+                    hook(&options);
+                };
 
-            // Stop If
-            if let Some(hook) = &options.stop_when.clone()
-                && hook(&options)
-            {
-                options.stop_reason = Some(StopReason::Hook);
-                break;
-            }
+                if let Some(stop_reason) = outcome.stop_reason() {
+                    options.stop_reason = Some(stop_reason);
+                    break;
+                }
 
-            match response.contents.last() {
-                Some(LanguageModelResponseContentType::ToolCall(_)) => (),
-                _ => {
-                    options.stop_reason = Some(StopReason::Finish);
+                if response.contents.is_empty() {
+                    options.stop_reason = Some(StopReason::Error(Error::Other(
+                        "Language model returned empty response".to_string(),
+                    )));
                     break;
                 }
-            };
+
+                // Stop If
+                if let Some(hook) = &options.stop_when.clone()
+                    && hook(&options)
+                {
+                    options.stop_reason = Some(StopReason::Hook);
+                    break;
+                }
+
+                match response.contents.last() {
+                    Some(LanguageModelResponseContentType::ToolCall(_)) => (),
+                    _ => {
+                        options.stop_reason = Some(StopReason::Finish);
+                        break;
+                    }
+                };
+            }
+            Ok::<_, Error>(())
+        };
+
+        // Dropping the generation cancels the model call and skips the remaining
+        // tool calls.
+        let aborted = match abort {
+            Some(abort) => tokio::select! {
+                biased;
+                _ = abort.aborted() => true,
+                result = generation => {
+                    result?;
+                    false
+                }
+            },
+            None => {
+                generation.await?;
+                false
+            }
+        };
+        if aborted {
+            options.stop_reason = Some(StopReason::Aborted);
         }
 
         Ok(GenerateTextResponse { options })
@@ -226,11 +300,15 @@ impl Deref for GenerateTextResponse {
 mod tests {
     use super::*;
     use crate::core::{
-        AssistantMessage,
-        language_model::{LanguageModelResponseContentType, Usage},
+        AssistantMessage, MockLanguageModel,
+        language_model::{
+            AbortHandle, LanguageModelResponseContentType, ToolChoice, Usage,
+            mock::fixtures::{approval_tool, echo_call, echo_tool, pending_tool},
+        },
         messages::TaggedMessage,
-        tools::{ToolCallInfo, ToolResultInfo},
+        tools::{Tool, ToolApproval, ToolCallInfo, ToolExecute, ToolOutput, ToolResultInfo},
     };
+    use serde_json::json;
 
     #[test]
     fn test_generate_text_response_step() {
@@ -612,4 +690,262 @@ mod tests {
             assert_eq!(result.tool.name, format!("tool{i}"));
         }
     }
+
+    #[tokio::test]
+    async fn test_generate_text_counts_parallel_tool_call_usage_once() {
+        let usage = Usage {
+            input_tokens: Some(10),
+            output_tokens: Some(5),
+            ..Default::default()
+        };
+        let mut second_call = echo_call();
+        second_call.id("call_2");
+        let model = MockLanguageModel::new()
+            .with_response(LanguageModelResponse {
+                contents: vec![
+                    LanguageModelResponseContentType::ToolCall(echo_call()),
+                    LanguageModelResponseContentType::ToolCall(second_call),
+                ],
+                usage: Some(usage.clone()),
+            })
+            .with_text("done");
+
+        let response = LanguageModelRequest::builder()
+            .model(model)
+            .prompt("Call echo twice")
+            .with_tool(echo_tool())
+            .build()
+            .generate_text()
+            .await
+            .unwrap();
+
+        let step = response.step(1).unwrap();
+        assert_eq!(step.tool_calls().map(|calls| calls.len()), Some(2));
+        assert_eq!(step.usage(), usage);
+    }
+
+    #[tokio::test]
+    async fn test_generate_text_aborts_running_tool_call() {
+        let model = MockLanguageModel::new()
+            .with_tool_call(echo_call())
+            .with_text("never sent");
+        let abort = AbortHandle::new();
+
+        let request = tokio::spawn({
+            let model = model.clone();
+            let abort = abort.clone();
+            async move {
+                LanguageModelRequest::builder()
+                    .model(model)
+                    .prompt("Call echo")
+                    .with_tool(pending_tool())
+                    .abort_handle(abort)
+                    .build()
+                    .generate_text()
+                    .await
+            }
+        });
+        tokio::time::sleep(std::time::Duration::from_millis(20)).await;
+        abort.abort();
+
+        let result = request.await.unwrap().unwrap();
+        assert_eq!(result.stop_reason(), Some(StopReason::Aborted));
+        assert_eq!(model.calls().len(), 1);
+    }
+
+    async fn generate_until_approval(model: &MockLanguageModel) -> GenerateTextResponse {
+        LanguageModelRequest::builder()
+            .model(model.clone())
+            .prompt("Call echo")
+            .with_tool(approval_tool())
+            .build()
+            .generate_text()
+            .await
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_generate_text_pauses_for_tool_approval() {
+        let model = MockLanguageModel::new()
+            .with_tool_call(echo_call())
+            .with_text("done");
+
+        let paused = generate_until_approval(&model).await;
+        assert_eq!(paused.stop_reason(), Some(StopReason::ToolApprovalRequired));
+        assert_eq!(paused.pending_tool_approvals(), vec![echo_call()]);
+        assert!(paused.tool_results().is_none());
+        assert_eq!(model.calls().len(), 1);
+
+        let resumed = LanguageModelRequest::builder()
+            .model(model.clone())
+            .conversation(paused.conversation_state())
+            .with_tool(approval_tool())
+            .tool_approval("call_1", ToolApproval::Approve)
+            .build()
+            .generate_text()
+            .await
+            .unwrap();
+
+        assert_eq!(resumed.stop_reason(), Some(StopReason::Finish));
+        assert_eq!(resumed.text(), Some("done".to_string()));
+        assert!(resumed.pending_tool_approvals().is_empty());
+        let results = resumed.tool_results().unwrap();
+        assert_eq!(results.len(), 1);
+        assert_eq!(
+            results[0].output.clone().unwrap(),
+            ToolOutput::Text(r#"{"value":42}"#.to_string())
+        );
+        // the model receives the result of the approved call
+        assert!(model.last_call().unwrap().tool_results().is_some());
+    }
+
+    #[tokio::test]
+    async fn test_generate_text_applies_edited_and_denied_tool_calls() {
+        let model = MockLanguageModel::new()
+            .with_tool_call(echo_call())
+            .with_text("edited")
+            .with_tool_call(echo_call())
+            .with_text("denied");
+
+        let paused = generate_until_approval(&model).await;
+        let edited = LanguageModelRequest::builder()
+            .model(model.clone())
+            .conversation(paused.conversation_state())
+            .with_tool(approval_tool())
+            .tool_approval("call_1", ToolApproval::Edit(json!({"value": 7})))
+            .build()
+            .generate_text()
+            .await
+            .unwrap();
+        assert_eq!(
+            edited.tool_results().unwrap()[0].output.clone().unwrap(),
+            ToolOutput::Text(r#"{"value":7}"#.to_string())
+        );
+        assert_eq!(edited.tool_calls().unwrap()[0].input, json!({"value": 7}));
+
+        let paused = generate_until_approval(&model).await;
+        let denied = LanguageModelRequest::builder()
+            .model(model.clone())
+            .conversation(paused.conversation_state())
+            .with_tool(approval_tool())
+            .tool_approval("call_1", ToolApproval::Deny("not allowed".to_string()))
+            .build()
+            .generate_text()
+            .await
+            .unwrap();
+        assert_eq!(
+            denied.tool_results().unwrap()[0].output.clone().unwrap(),
+            ToolOutput::Text("Tool call denied: not allowed".to_string())
+        );
+        assert_eq!(denied.text(), Some("denied".to_string()));
+    }
+
+    #[tokio::test]
+    async fn test_generate_text_stops_for_deferred_tool_calls() {
+        let model = MockLanguageModel::new()
+            .with_tool_call(echo_call())
+            .with_text("done");
+        // without an execute function the tool is executed by the caller
+        let client_tool = || {
+            Tool::builder()
+                .name("echo")
+                .description("Runs in the browser")
+                .input_schema(schemars::json_schema!({"type": "object"}))
+                .build()
+                .unwrap()
+        };
+
+        let paused = LanguageModelRequest::builder()
+            .model(model.clone())
+            .prompt("Call echo")
+            .with_tool(client_tool())
+            .build()
+            .generate_text()
+            .await
+            .unwrap();
+        assert_eq!(paused.stop_reason(), Some(StopReason::DeferredToolCalls));
+        assert_eq!(paused.pending_tool_calls(), vec![echo_call()]);
+        assert!(paused.tool_results().is_none());
+
+        let mut state = paused.conversation_state();
+        let mut result = ToolResultInfo::new("echo");
+        result.id("call_1");
+        result.output(json!("from the client"));
+        state.push(Message::Tool(result));
+
+        let resumed = LanguageModelRequest::builder()
+            .model(model.clone())
+            .conversation(state)
+            .with_tool(client_tool())
+            .build()
+            .generate_text()
+            .await
+            .unwrap();
+        assert_eq!(resumed.stop_reason(), Some(StopReason::Finish));
+        assert_eq!(resumed.text(), Some("done".to_string()));
+        assert!(resumed.pending_tool_calls().is_empty());
+        assert_eq!(model.calls().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_generate_text_passes_tool_context() {
+        #[derive(Clone)]
+        struct UserId(&'static str);
+
+        let model = MockLanguageModel::new()
+            .with_tool_call(echo_call())
+            .with_text("done");
+        let tool = Tool {
+            execute: ToolExecute::new_with_context(Box::new(|_, context| {
+                let user = context.get::<UserId>().ok_or("missing user")?;
+                Ok(format!(
+                    "{} {} {}",
+                    user.0,
+                    context.tool_call_id,
+                    context.messages.len()
+                ))
+            })),
+            ..echo_tool()
+        };
+
+        let result = LanguageModelRequest::builder()
+            .model(model)
+            .prompt("Call echo")
+            .with_tool(tool)
+            .with_tool_context(UserId("ada"))
+            .build()
+            .generate_text()
+            .await
+            .unwrap();
+
+        // the user prompt and the tool call
+        assert_eq!(
+            result.tool_results().unwrap()[0].output.clone().unwrap(),
+            ToolOutput::Text("ada call_1 2".to_string())
+        );
+    }
+
+    #[tokio::test]
+    async fn test_generate_text_forces_tool_choice_on_first_step_only() {
+        let model = MockLanguageModel::new()
+            .with_tool_call(echo_call())
+            .with_text("done");
+
+        let result = LanguageModelRequest::builder()
+            .model(model.clone())
+            .prompt("Extract the value")
+            .with_tool(echo_tool())
+            .tool_choice(ToolChoice::tool("echo"))
+            .build()
+            .generate_text()
+            .await
+            .unwrap();
+
+        assert_eq!(result.stop_reason(), Some(StopReason::Finish));
+        assert_eq!(result.text(), Some("done".to_string()));
+        let calls = model.calls();
+        assert_eq!(calls.len(), 2);
+        assert_eq!(calls[0].tool_choice, Some(ToolChoice::tool("echo")));
+        assert_eq!(calls[1].tool_choice, Some(ToolChoice::Auto));
+    }
 }
@@ -0,0 +1,438 @@
+//! Defines `MockLanguageModel`, a scripted language model for offline tests.
+//!
+//! The mock replays queued responses and stream chunk sequences in order and records
+//! the [`LanguageModelOptions`] of every call, so agent logic built on top of `aisdk`
+//! can be tested deterministically without network access or API keys.
+//!
+//! # Example
+//!
+//! ```rust
+//!# #[cfg(feature = "language-model-request")]
+//!# {
+//! use aisdk::core::{LanguageModelRequest, MockLanguageModel};
+//!
+//! # tokio::runtime::Runtime::new().unwrap().block_on(async {
+//! let model = MockLanguageModel::new().with_text("Hello from the mock!");
+//!
+//! let result = LanguageModelRequest::builder()
+//!     .model(model.clone())
+//!     .prompt("Hello!")
+//!     .build()
+//!     .generate_text()
+//!     .await
+//!     .unwrap();
+//!
+//! assert_eq!(result.text(), Some("Hello from the mock!".to_string()));
+//! assert_eq!(model.calls().len(), 1);
+//! # });
+//!# }
+//! ```
+
+use crate::core::capabilities::*;
+use crate::core::language_model::{
+    LanguageModel, LanguageModelOptions, LanguageModelResponse, LanguageModelResponseContentType,
+    LanguageModelStreamChunk, LanguageModelStreamChunkType, ProviderStream,
+};
+use crate::core::messages::AssistantMessage;
+use crate::core::tools::ToolCallInfo;
+use crate::error::{Error, Result};
+use async_trait::async_trait;
+use parking_lot::Mutex;
+use std::collections::VecDeque;
+use std::sync::Arc;
+
+/// The events of a scripted stream, or the error returned when opening it.
+type StreamScript = Result<Vec<Result<Vec<LanguageModelStreamChunk>>>>;
+
+#[derive(Debug, Default)]
+struct MockState {
+    /// Scripted results of `generate_text`, consumed in order.
+    responses: VecDeque<Result<LanguageModelResponse>>,
+    /// Scripted results of `stream_text`, consumed in order.
+    streams: VecDeque<StreamScript>,
+    /// Options received by every call, in call order.
+    calls: Vec<LanguageModelOptions>,
+}
+
+/// A language model returning scripted responses, for tests without network access.
+///
+/// `generate_text` and `stream_text` consume separate queues. Each call pops the next
+/// scripted entry and returns [`Error::Other`] once its queue is exhausted. Clones share
+/// the same script and call log, so keep a clone of the model to inspect the calls
+/// after handing it to a request.
+///
+/// Like [`DynamicModel`], the mock implements every capability.
+#[derive(Debug, Clone)]
+pub struct MockLanguageModel {
+    name: String,
+    state: Arc<Mutex<MockState>>,
+}
+
+impl Default for MockLanguageModel {
+    fn default() -> Self {
+        Self {
+            name: "mock".to_string(),
+            state: Arc::default(),
+        }
+    }
+}
+
+impl MockLanguageModel {
+    /// Creates a mock named `mock` without any scripted response.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the name returned by [`LanguageModel::name`].
+    pub fn with_name(mut self, name: impl Into<String>) -> Self {
+        self.name = name.into();
+        self
+    }
+
+    /// Queues a response for `generate_text`.
+    ///
+    /// # Parameters
+    ///
+    /// * `response` - The response returned by the next unscripted call.
+    ///
+    /// # Returns
+    ///
+    /// The mock, for chaining further responses.
+    pub fn with_response(self, response: LanguageModelResponse) -> Self {
+        self.state.lock().responses.push_back(Ok(response));
+        self
+    }
+
+    /// Queues a text response for `generate_text`.
+    pub fn with_text(self, text: impl Into<String>) -> Self {
+        self.with_response(LanguageModelResponse::new(text))
+    }
+
+    /// Queues a response for `generate_text` containing a single tool call.
+    ///
+    /// # Parameters
+    ///
+    /// * `tool_call` - The tool call requested by the model.
+    pub fn with_tool_call(self, tool_call: ToolCallInfo) -> Self {
+        self.with_response(LanguageModelResponse {
+            contents: vec![LanguageModelResponseContentType::ToolCall(tool_call)],
+            usage: None,
+        })
+    }
+
+    /// Queues an error for `generate_text`.
+    pub fn with_error(self, error: Error) -> Self {
+        self.state.lock().responses.push_back(Err(error));
+        self
+    }
+
+    /// Queues a stream for `stream_text` emitting one chunk per event.
+    ///
+    /// # Parameters
+    ///
+    /// * `chunks` - The chunks of the stream, usually deltas followed by a
+    ///   [`LanguageModelStreamChunk::Done`] for each message of the step.
+    ///
+    /// # Returns
+    ///
+    /// The mock, for chaining further streams.
+    pub fn with_stream(self, chunks: Vec<LanguageModelStreamChunk>) -> Self {
+        self.with_stream_events(chunks.into_iter().map(|c| Ok(vec![c])).collect())
+    }
+
+    /// Queues a stream for `stream_text` with raw events, which may contain errors.
+    pub fn with_stream_events(self, events: Vec<Result<Vec<LanguageModelStreamChunk>>>) -> Self {
+        self.state.lock().streams.push_back(Ok(events));
+        self
+    }
+
+    /// Queues a stream for `stream_text` emitting the given text deltas.
+    ///
+    /// The stream ends with a [`LanguageModelStreamChunk::Done`] holding the full text.
+    pub fn with_text_stream<S: Into<String>>(self, deltas: impl IntoIterator<Item = S>) -> Self {
+        let deltas: Vec<String> = deltas.into_iter().map(Into::into).collect();
+        let text = deltas.concat();
+        let mut chunks: Vec<LanguageModelStreamChunk> = deltas
+            .into_iter()
+            .map(|d| LanguageModelStreamChunk::Delta(LanguageModelStreamChunkType::Text(d)))
+            .collect();
+        chunks.push(LanguageModelStreamChunk::Done(AssistantMessage::new(
+            LanguageModelResponseContentType::Text(text),
+            None,
+        )));
+        self.with_stream(chunks)
+    }
+
+    /// Queues an error returned by `stream_text` before any chunk is emitted.
+    pub fn with_stream_error(self, error: Error) -> Self {
+        self.state.lock().streams.push_back(Err(error));
+        self
+    }
+
+    /// Returns the options received by every call, in call order.
+    pub fn calls(&self) -> Vec<LanguageModelOptions> {
+        self.state.lock().calls.clone()
+    }
+
+    /// Returns the options received by the most recent call.
+    pub fn last_call(&self) -> Option<LanguageModelOptions> {
+        self.state.lock().calls.last().cloned()
+    }
+
+    /// Returns `true` if every scripted response and stream has been consumed.
+    pub fn is_exhausted(&self) -> bool {
+        let state = self.state.lock();
+        state.responses.is_empty() && state.streams.is_empty()
+    }
+}
+
+#[async_trait]
+impl LanguageModel for MockLanguageModel {
+    fn name(&self) -> String {
+        self.name.clone()
+    }
+
+    async fn generate_text(
+        &mut self,
+        options: LanguageModelOptions,
+    ) -> Result<LanguageModelResponse> {
+        let mut state = self.state.lock();
+        state.calls.push(options);
+        state.responses.pop_front().unwrap_or_else(|| {
+            Err(Error::Other(
+                "MockLanguageModel has no scripted response left".to_string(),
+            ))
+        })
+    }
+
+    async fn stream_text(&mut self, options: LanguageModelOptions) -> Result<ProviderStream> {
+        let script = {
+            let mut state = self.state.lock();
+            state.calls.push(options);
+            state.streams.pop_front()
+        };
+        let events = script.unwrap_or_else(|| {
+            Err(Error::Other(
+                "MockLanguageModel has no scripted stream left".to_string(),
+            ))
+        })?;
+        Ok(Box::pin(futures::stream::iter(events)))
+    }
+}
+
+// The mock stands in for any provider, so every capability is supported.
+impl ToolCallSupport for MockLanguageModel {}
+impl StructuredOutputSupport for MockLanguageModel {}
+impl ReasoningSupport for MockLanguageModel {}
+impl TextInputSupport for MockLanguageModel {}
+impl TextOutputSupport for MockLanguageModel {}
+impl ImageInputSupport for MockLanguageModel {}
+impl FileInputSupport for MockLanguageModel {}
+impl VideoInputSupport for MockLanguageModel {}
+impl AudioInputSupport for MockLanguageModel {}
+impl ImageOutputSupport for MockLanguageModel {}
+impl VideoOutputSupport for MockLanguageModel {}
+impl AudioOutputSupport for MockLanguageModel {}
+
+/// Tools and tool calls shared by the generation loop tests.
+#[cfg(all(test, feature = "language-model-request"))]
+pub(crate) mod fixtures {
+    use crate::core::tools::{Tool, ToolCallInfo, ToolExecute};
+    use serde_json::json;
+
+    /// A tool returning its input.
+    pub(crate) fn echo_tool() -> Tool {
+        Tool::builder()
+            .name("echo")
+            .description("Echoes the input")
+            .input_schema(schemars::json_schema!({"type": "object"}))
+            .execute(ToolExecute::new(Box::new(|input| Ok(input.to_string()))))
+            .build()
+            .unwrap()
+    }
+
+    /// A call of [`echo_tool`] with id `call_1`.
+    pub(crate) fn echo_call() -> ToolCallInfo {
+        let mut call = ToolCallInfo::new("echo");
+        call.id("call_1");
+        call.input(json!({"value": 42}));
+        call
+    }
+
+    /// An `echo` tool that never finishes.
+    pub(crate) fn pending_tool() -> Tool {
+        Tool::builder()
+            .name("echo")
+            .description("Never finishes")
+            .input_schema(schemars::json_schema!({"type": "object"}))
+            .execute(ToolExecute::new_async(Box::new(|_| {
+                Box::pin(futures::future::pending())
+            })))
+            .build()
+            .unwrap()
+    }
+
+    /// An [`echo_tool`] that needs approval.
+    pub(crate) fn approval_tool() -> Tool {
+        Tool {
+            needs_approval: true,
+            ..echo_tool()
+        }
+    }
+}
+
+#[cfg(all(test, feature = "language-model-request"))]
+mod tests {
+    use super::fixtures::{echo_call, echo_tool};
+    use super::*;
+    use crate::core::LanguageModelRequest;
+    use crate::core::language_model::StopReason;
+    use futures::StreamExt;
+
+    #[test]
+    fn test_supports_every_attachment() {
+        use crate::core::AnyLanguageModel;
+        use crate::core::messages::{DataContent, Message, UserContentPart};
+
+        fn attached_parts(options: &LanguageModelOptions) -> usize {
+            match options.messages().last() {
+                Some(Message::User(user)) => user
+                    .clone()
+                    .into_parts()
+                    .into_iter()
+                    .filter(|part| !matches!(part, UserContentPart::Text(_)))
+                    .count(),
+                _ => 0,
+            }
+        }
+
+        let mock = LanguageModelRequest::builder()
+            .model(MockLanguageModel::new())
+            .prompt("Summarize")
+            .image(DataContent::url("https://example.com/a.png"), "image/png")
+            .file(DataContent::bytes(b"%PDF".to_vec()), "application/pdf")
+            .build();
+        assert_eq!(attached_parts(&mock.options), 2);
+
+        let any = LanguageModelRequest::builder()
+            .model(AnyLanguageModel::new(MockLanguageModel::new()))
+            .prompt("Summarize")
+            .file(DataContent::bytes(b"%PDF".to_vec()), "application/pdf")
+            .build();
+        assert_eq!(attached_parts(&any.options), 1);
+    }
+
+    #[tokio::test]
+    async fn test_generate_text_replays_tool_call_then_text() {
+        let model = MockLanguageModel::new()
+            .with_tool_call(echo_call())
+            .with_response(LanguageModelResponse {
+                contents: vec![
+                    LanguageModelResponseContentType::Reasoning {
+                        content: "thinking".to_string(),
+                        extensions: Default::default(),
+                    },
+                    LanguageModelResponseContentType::Text("done".to_string()),
+                ],
+                usage: None,
+            });
+
+        let result = LanguageModelRequest::builder()
+            .model(model.clone())
+            .prompt("Call echo")
+            .with_tool(echo_tool())
+            .build()
+            .generate_text()
+            .await
+            .unwrap();
+
+        assert_eq!(result.text(), Some("done".to_string()));
+        assert_eq!(result.tool_results().unwrap().len(), 1);
+        assert!(model.is_exhausted());
+
+        let calls = model.calls();
+        assert_eq!(calls.len(), 2);
+        assert_eq!(
+            calls[0].tools.as_ref().unwrap().tools.lock().unwrap().len(),
+            1
+        );
+        // The second step sees the tool result of the first one.
+        assert_eq!(
+            model.last_call().unwrap().messages.len(),
+            calls[0].messages.len() + 2
+        );
+    }
+
+    #[tokio::test]
+    async fn test_generate_text_returns_scripted_error() {
+        let model = MockLanguageModel::new().with_error(Error::ApiError {
+            status_code: None,
+            details: "boom".to_string(),
+        });
+
+        let result = LanguageModelRequest::builder()
+            .model(model)
+            .prompt("Hello")
+            .build()
+            .generate_text()
+            .await;
+
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_stream_text_replays_chunks() {
+        let model = MockLanguageModel::new().with_text_stream(["Hel", "lo"]);
+
+        let response = LanguageModelRequest::builder()
+            .model(model.clone())
+            .prompt("Hello")
+            .build()
+            .stream_text()
+            .await
+            .unwrap();
+
+        let chunks: Vec<_> = response.stream.collect().await;
+        let text: String = chunks
+            .iter()
+            .filter_map(|c| match c {
+                LanguageModelStreamChunkType::Text(t) => Some(t.as_str()),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(text, "Hello");
+        assert_eq!(model.calls().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_stream_text_reports_mid_stream_error() {
+        let model = MockLanguageModel::new().with_stream_events(vec![
+            Ok(vec![LanguageModelStreamChunk::Delta(
+                LanguageModelStreamChunkType::Text("partial".to_string()),
+            )]),
+            Err(Error::Other("connection lost".to_string())),
+        ]);
+
+        let mut response = LanguageModelRequest::builder()
+            .model(model)
+            .prompt("Hello")
+            .build()
+            .stream_text()
+            .await
+            .unwrap();
+
+        let chunks: Vec<_> = response.stream.by_ref().collect().await;
+        assert!(matches!(
+            chunks.as_slice(),
+            [
+                ..,
+                LanguageModelStreamChunkType::Failed(_),
+                LanguageModelStreamChunkType::Finish { .. }
+            ]
+        ));
+        assert!(matches!(
+            response.stop_reason().await,
+            Some(StopReason::Error(_))
+        ));
+    }
+}
@@ -5,15 +5,25 @@
 //! underlying implementation details of different AI providers, offering a
 //! unified interface for various operations like text generation or streaming.
 
+pub mod abort;
+pub mod any_model;
+#[cfg(feature = "language-model-request")]
+pub mod generate_object;
 #[cfg(feature = "language-model-request")]
 pub mod generate_text;
+#[cfg(any(test, feature = "test-utils"))]
+pub mod mock;
 #[cfg(feature = "language-model-request")]
 pub mod request;
 #[cfg(feature = "language-model-request")]
+pub mod stream_object;
+#[cfg(feature = "language-model-request")]
 pub mod stream_text;
 
-use crate::core::messages::{AssistantMessage, TaggedMessage, TaggedMessageHelpers};
-use crate::core::tools::ToolList;
+use crate::core::messages::{
+    AssistantMessage, ConversationState, TaggedMessage, TaggedMessageHelpers,
+};
+use crate::core::tools::{ToolApproval, ToolContext, ToolList, ToolOutput};
 use crate::core::{
     Message,
     tools::{ToolCallInfo, ToolResultInfo},
@@ -22,8 +32,9 @@ use crate::core::{Messages, utils};
 use crate::error::{Error, Result};
 use async_trait::async_trait;
 use derive_builder::Builder;
-use futures::Stream;
+use futures::{Stream, StreamExt};
 use schemars::Schema;
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::fmt::Debug;
 use std::ops::Add;
@@ -32,6 +43,11 @@ use std::sync::Arc;
 use std::task::{Context, Poll};
 use tokio::sync::mpsc::{self, UnboundedReceiver, UnboundedSender};
 
+pub use abort::AbortHandle;
+pub use any_model::AnyLanguageModel;
+#[cfg(any(test, feature = "test-utils"))]
+pub use mock::MockLanguageModel;
+
 // ============================================================================
 // Section: constants
 // ============================================================================
@@ -98,6 +114,15 @@ pub type OnStepStartHook = Arc<dyn Fn(&mut LanguageModelOptions) + Send + Sync>;
 /// Type alias for a hook function called after each generation step.
 pub type OnStepFinishHook = Arc<dyn Fn(&LanguageModelOptions) + Send + Sync>;
 
+/// The future returned by an [`InputGuardrailHook`].
+///
+/// Resolves to the reason for rejecting the input, or `None` to continue.
+pub type InputGuardrailFuture = Pin<Box<dyn Future<Output = Result<Option<String>>> + Send>>;
+
+/// Type alias for an asynchronous hook checking the input before the first generation step.
+pub type InputGuardrailHook =
+    Arc<dyn Fn(&LanguageModelOptions) -> InputGuardrailFuture + Send + Sync>;
+
 // ============================================================================
 // Section: structs and impls
 // ============================================================================
@@ -174,6 +199,30 @@ impl Step {
 // Section: options
 // ============================================================================
 
+/// The tool calls of a step after [`LanguageModelOptions::handle_tool_calls`].
+#[derive(Debug, Default)]
+pub(crate) struct ToolCallOutcome {
+    /// Results of the executed and denied calls, in call order.
+    pub(crate) results: Vec<ToolResultInfo>,
+    /// Calls waiting for a [`ToolApproval`].
+    pub(crate) pending: Vec<ToolCallInfo>,
+    /// Calls of tools executed by the caller.
+    pub(crate) deferred: Vec<ToolCallInfo>,
+}
+
+impl ToolCallOutcome {
+    /// Returns why generation has to stop before the next step, if it does.
+    pub(crate) fn stop_reason(&self) -> Option<StopReason> {
+        if !self.pending.is_empty() {
+            Some(StopReason::ToolApprovalRequired)
+        } else if !self.deferred.is_empty() {
+            Some(StopReason::DeferredToolCalls)
+        } else {
+            None
+        }
+    }
+}
+
 /// Configuration options for language model requests.
 ///
 /// This struct contains all the parameters that can be used to customize
@@ -201,8 +250,17 @@ pub struct LanguageModelOptions {
     pub top_k: Option<u32>,
 
     /// Maximum number of retries for failed requests.
+    ///
+    /// Overrides the number of retries of the effective retry policy.
     pub max_retries: Option<u32>,
 
+    /// Retry policy for failed requests, replacing the provider's policy.
+    pub retry_policy: Option<crate::core::client::RetryPolicy>,
+
+    /// Number of times `generate_object` sends an invalid object back to the model
+    /// together with the validation error. No repair is attempted when not set.
+    pub max_repair_attempts: Option<u32>,
+
     /// Maximum number of output tokens to generate.
     pub max_output_tokens: Option<u32>,
 
@@ -227,12 +285,36 @@ pub struct LanguageModelOptions {
     /// Hook called after each generation step.
     pub on_step_finish: Option<OnStepFinishHook>,
 
+    /// Hook checking the input before the first generation step, see
+    /// [`moderation_guardrail`](crate::core::moderation_model::moderation_guardrail).
+    pub input_guardrail: Option<InputGuardrailHook>,
+
     /// Level of reasoning effort for the model.
     pub reasoning_effort: Option<ReasoningEffort>,
 
+    /// Controls whether and which tools the model must call.
+    pub tool_choice: Option<ToolChoice>,
+
+    /// Whether the model may return multiple tool calls in a single step, for
+    /// providers that support the setting.
+    pub parallel_tool_calls: Option<bool>,
+
+    /// Maximum number of tool calls from a single step that are executed
+    /// concurrently. Unlimited when not set.
+    pub max_tool_concurrency: Option<usize>,
+
+    /// Handle to abort the generation. A stream is also aborted when it is dropped.
+    pub abort_handle: Option<AbortHandle>,
+
     /// List of tools to use.
     pub(crate) tools: Option<ToolList>,
 
+    /// Decisions on tool calls that need approval, by tool call id.
+    pub(crate) tool_approvals: HashMap<String, ToolApproval>,
+
+    /// Request-scoped values passed to the tools in their [`ToolContext`].
+    pub(crate) tool_context: crate::extensions::Extensions,
+
     /// Current step ID for tracking multi-step interactions.
     pub(crate) current_step_id: usize,
 
@@ -254,15 +336,24 @@ impl Debug for LanguageModelOptions {
             .field("top_p", &self.top_p)
             .field("top_k", &self.top_k)
             .field("max_retries", &self.max_retries)
+            .field("retry_policy", &self.retry_policy)
+            .field("max_repair_attempts", &self.max_repair_attempts)
             .field("max_output_tokens", &self.max_output_tokens)
             .field("stop_sequences", &self.stop_sequences)
             .field("presence_penalty", &self.presence_penalty)
             .field("frequency_penalty", &self.frequency_penalty)
             .field("tools", &self.tools)
+            .field("tool_choice", &self.tool_choice)
+            .field("parallel_tool_calls", &self.parallel_tool_calls)
+            .field("max_tool_concurrency", &self.max_tool_concurrency)
+            .field("abort_handle", &self.abort_handle)
+            .field("tool_approvals", &self.tool_approvals)
+            .field("tool_context", &self.tool_context)
             .field("current_step_id", &self.current_step_id)
             .field("stop_when", &self.stop_when.is_some())
             .field("on_step_start", &self.on_step_start.is_some())
             .field("on_step_finish", &self.on_step_finish.is_some())
+            .field("input_guardrail", &self.input_guardrail.is_some())
             .finish()
     }
 }
@@ -278,38 +369,180 @@ impl LanguageModelOptions {
         self.messages.iter().map(|m| m.message.clone()).collect()
     }
 
-    /// Executes a tool call and adds the result to the message history.
-    pub(crate) async fn handle_tool_call(&mut self, input: &ToolCallInfo) -> &mut Self {
-        if let Some(tools) = &self.tools {
-            let tool_result_task = tools.execute(input.clone()).await;
-            let tool_result = tool_result_task
-                .await
-                .map_err(|err| Error::ToolCallError(format!("Error executing tool: {err}")))
-                .and_then(|result| result);
+    /// Returns a serializable snapshot of the conversation, including step ids.
+    pub fn conversation_state(&self) -> ConversationState {
+        ConversationState::from_tagged(self.messages.clone())
+    }
 
-            let mut tool_output_infos = Vec::new();
+    /// Executes the tool calls of a step concurrently and adds the results to the
+    /// message history in call order.
+    ///
+    /// At most `max_tool_concurrency` calls run at the same time. Calls of tools that
+    /// need approval run according to their [`ToolApproval`]; calls without a decision
+    /// are returned as pending and get no result. Calls of deferred tools are returned
+    /// for the caller to execute.
+    pub(crate) async fn handle_tool_calls(&mut self, inputs: &[ToolCallInfo]) -> ToolCallOutcome {
+        let mut outcome = ToolCallOutcome::default();
+        let Some(tools) = self.tools.clone() else {
+            return outcome;
+        };
 
-            let mut tool_output_info = ToolResultInfo::new(&input.tool.name);
-            let output = match tool_result {
-                Ok(result) => serde_json::Value::String(result),
-                Err(err) => serde_json::Value::String(format!("Error: {err}")),
-            };
-            tool_output_info.output(output);
-            tool_output_info.id(&input.tool.id);
-            tool_output_infos.push(tool_output_info.clone());
+        // Pair every call to run with the reason it was denied, if any
+        let mut calls = Vec::new();
+        for input in inputs {
+            if tools.is_deferred(&input.tool.name) {
+                outcome.deferred.push(input.clone());
+                continue;
+            }
+            if !tools.needs_approval(&input.tool.name) {
+                calls.push((input.clone(), None));
+                continue;
+            }
+            match self.tool_approvals.get(&input.tool.id).cloned() {
+                None => outcome.pending.push(input.clone()),
+                Some(ToolApproval::Approve) => calls.push((input.clone(), None)),
+                Some(ToolApproval::Edit(edited)) => {
+                    self.edit_tool_call_input(&input.tool.id, &edited);
+                    let mut input = input.clone();
+                    input.input(edited);
+                    calls.push((input, None));
+                }
+                Some(ToolApproval::Deny(reason)) => calls.push((input.clone(), Some(reason))),
+            }
+        }
+        if calls.is_empty() {
+            return outcome;
+        }
+
+        let limit = self
+            .max_tool_concurrency
+            .unwrap_or(calls.len())
+            .clamp(1, calls.len());
+        let messages = self.messages();
+
+        outcome.results = futures::stream::iter(calls)
+            .map(|(input, denied)| {
+                let tools = tools.clone();
+                let context = ToolContext {
+                    tool_call_id: input.tool.id.clone(),
+                    messages: messages.clone(),
+                    extensions: self.tool_context.clone(),
+                };
+                async move {
+                    let output = match denied {
+                        Some(reason) => Ok(ToolOutput::Text(format!("Tool call denied: {reason}"))),
+                        None => tools
+                            .execute(input.clone(), context)
+                            .await
+                            .await
+                            .map_err(|err| {
+                                Error::ToolCallError(format!("Error executing tool: {err}"))
+                            })
+                            .and_then(|result| result),
+                    };
+
+                    let mut tool_output_info = ToolResultInfo::new(&input.tool.name);
+                    tool_output_info.output = output;
+                    tool_output_info.id(&input.tool.id);
+                    tool_output_info
+                }
+            })
+            .buffered(limit)
+            .collect()
+            .await;
 
-            // update messages
+        // update messages
+        for tool_output_info in &outcome.results {
             self.messages.push(TaggedMessage::new(
                 self.current_step_id,
-                Message::Tool(tool_output_info),
+                Message::Tool(tool_output_info.clone()),
             ));
+        }
 
-            self
-        } else {
-            self
+        outcome
+    }
+
+    /// Replaces the input of the tool call with the given id in the message history.
+    fn edit_tool_call_input(&mut self, id: &str, input: &serde_json::Value) {
+        for tagged in self.messages.iter_mut() {
+            if let Message::Assistant(AssistantMessage {
+                content: LanguageModelResponseContentType::ToolCall(ref mut call),
+                ..
+            }) = tagged.message
+                && call.tool.id == id
+            {
+                call.input(input.clone());
+            }
         }
     }
 
+    /// Resets a forced tool choice to [`ToolChoice::Auto`] once the model called tools,
+    /// so that `Required` and `Tool` only apply until the first step with tool calls.
+    pub(crate) fn relax_tool_choice(&mut self) {
+        if matches!(
+            self.tool_choice,
+            Some(ToolChoice::Required | ToolChoice::Tool(_))
+        ) {
+            self.tool_choice = Some(ToolChoice::Auto);
+        }
+    }
+
+    /// Returns the tool calls of the current step that have no result yet.
+    pub(crate) fn unresolved_tool_calls(&self) -> Vec<ToolCallInfo> {
+        let step: Vec<&Message> = self
+            .messages
+            .iter()
+            .filter(|t| t.step_id == self.current_step_id)
+            .map(|t| &t.message)
+            .collect();
+        let resolved: Vec<&str> = step
+            .iter()
+            .filter_map(|m| match m {
+                Message::Tool(result) => Some(result.tool.id.as_str()),
+                _ => None,
+            })
+            .collect();
+        step.iter()
+            .filter_map(|m| match m {
+                Message::Assistant(AssistantMessage {
+                    content: LanguageModelResponseContentType::ToolCall(call),
+                    ..
+                }) if !resolved.contains(&call.tool.id.as_str()) => Some(call.clone()),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Returns the calls of deferred tools that are waiting for a result from the caller.
+    ///
+    /// Generation stops with [`StopReason::DeferredToolCalls`] while there are any.
+    /// Add a [`Message::Tool`] result for each call to the conversation state and pass
+    /// it to a new request to continue.
+    pub fn pending_tool_calls(&self) -> Vec<ToolCallInfo> {
+        let Some(tools) = &self.tools else {
+            return Vec::new();
+        };
+        self.unresolved_tool_calls()
+            .into_iter()
+            .filter(|call| tools.is_deferred(&call.tool.name))
+            .collect()
+    }
+
+    /// Returns the tool calls waiting for a [`ToolApproval`].
+    ///
+    /// Generation stops with [`StopReason::ToolApprovalRequired`] while there are any.
+    /// Resume it by passing the conversation state and a decision for each call to a
+    /// new request.
+    pub fn pending_tool_approvals(&self) -> Vec<ToolCallInfo> {
+        let Some(tools) = &self.tools else {
+            return Vec::new();
+        };
+        self.unresolved_tool_calls()
+            .into_iter()
+            .filter(|call| tools.needs_approval(&call.tool.name))
+            .collect()
+    }
+
     /// Returns the step with the given index, if it exists.
     pub fn step(&self, index: usize) -> Option<Step> {
         let messages: Messages = self
@@ -412,7 +645,8 @@ impl LanguageModelOptions {
 // ============================================================================
 
 /// The different types of content that can be generated by a language model.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
 pub enum LanguageModelResponseContentType {
     /// Plain text response.
     Text(String),
@@ -423,6 +657,10 @@ pub enum LanguageModelResponseContentType {
         /// The reasoning/thinking content
         content: String,
         /// Provider-specific extensions
+        #[serde(
+            default,
+            skip_serializing_if = "crate::extensions::Extensions::is_empty"
+        )]
         extensions: crate::extensions::Extensions,
     },
     /// Feature not supported by the provider.
@@ -449,7 +687,7 @@ impl LanguageModelResponseContentType {
 }
 
 /// Token usage statistics for a language model operation.
-#[derive(Default, Debug, Clone, PartialEq)]
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Usage {
     /// Number of input tokens processed.
     pub input_tokens: Option<usize>,
@@ -504,8 +742,50 @@ pub enum LanguageModelStreamChunkType {
     Text(String),
     /// Reasoning summary text chunk (content delta only)
     Reasoning(String),
-    /// Tool call argument chunk
-    ToolCall(String),
+    /// The model started generating the input of a tool call.
+    ToolInputStart {
+        /// The id of the tool call.
+        id: String,
+        /// The name of the called tool.
+        name: String,
+    },
+    /// A chunk of the JSON input of a tool call.
+    ///
+    /// Use a [`PartialJsonParser`](crate::core::partial_json::PartialJsonParser) per
+    /// tool call id to get partial inputs.
+    ToolInputDelta {
+        /// The id of the tool call.
+        id: String,
+        /// The next piece of the JSON input.
+        delta: String,
+    },
+    /// The complete input of a tool call, sent before the tool is executed.
+    ToolInputAvailable(ToolCallInfo),
+    /// A tool call needs a [`ToolApproval`] before it is executed.
+    ToolApprovalRequest(ToolCallInfo),
+    /// The result of an executed tool call.
+    ToolOutputAvailable(ToolResultInfo),
+    /// A new step of the generation started.
+    StepStart {
+        /// The id of the step.
+        step_id: usize,
+    },
+    /// A step of the generation finished, after its tool calls were executed.
+    StepFinish {
+        /// The id of the step.
+        step_id: usize,
+        /// The token usage of the step.
+        usage: Usage,
+        /// Why the step finished.
+        finish_reason: FinishReason,
+    },
+    /// The generation finished. This is the last chunk of the stream.
+    Finish {
+        /// The token usage of all steps.
+        total_usage: Usage,
+        /// Why the generation stopped.
+        stop_reason: StopReason,
+    },
     /// Successful completion of generation.
     End(AssistantMessage),
     /// Generation failed with an error message.
@@ -550,6 +830,41 @@ impl LanguageModelStream {
         let (tx, rx) = mpsc::unbounded_channel();
         (tx, LanguageModelStream { receiver: rx })
     }
+
+    /// Turns the text deltas of a structured output stream into JSON snapshots.
+    ///
+    /// Use it with a `schema` set on the request. A snapshot is emitted whenever a text
+    /// delta changes the parsed document; other chunks are dropped.
+    ///
+    /// # Returns
+    ///
+    /// A stream of best-effort snapshots of the generated JSON document.
+    pub fn partial_json(self) -> impl Stream<Item = serde_json::Value> + Send {
+        crate::core::partial_json::partial_json_stream(self.text_deltas())
+    }
+
+    /// Turns the text deltas of a structured output stream into partial values of type `T`.
+    ///
+    /// Fields that have not been generated yet are missing, so `T` should use `Option`
+    /// or `#[serde(default)]` fields. Snapshots that cannot be deserialized are skipped.
+    ///
+    /// # Returns
+    ///
+    /// A stream of partial values of type `T`.
+    pub fn partial_objects<T: serde::de::DeserializeOwned + Send>(
+        self,
+    ) -> impl Stream<Item = T> + Send {
+        crate::core::partial_json::partial_object_stream(self.text_deltas())
+    }
+
+    fn text_deltas(self) -> impl Stream<Item = String> + Send {
+        self.filter_map(|chunk| async move {
+            match chunk {
+                LanguageModelStreamChunkType::Text(delta) => Some(delta),
+                _ => None,
+            }
+        })
+    }
 }
 
 impl Stream for LanguageModelStream {
@@ -569,12 +884,31 @@ pub enum StopReason {
     Provider(String),
     /// The user has explicitly provided a hook causing to stop
     Hook,
+    /// The input was rejected by the input guardrail, with the reason it gave.
+    Guardrail(String),
     /// Stopped due to an error.
     Error(Error),
+    /// The generation was aborted through an [`AbortHandle`] or by dropping its stream.
+    Aborted,
+    /// Tool calls are waiting for a [`ToolApproval`], see
+    /// [`pending_tool_approvals`](LanguageModelOptions::pending_tool_approvals).
+    ToolApprovalRequired,
+    /// Calls of deferred tools are waiting for results from the caller, see
+    /// [`pending_tool_calls`](LanguageModelOptions::pending_tool_calls).
+    DeferredToolCalls,
     /// Other unspecified reason.
     Other(String),
 }
 
+/// Reasons why a single step of generation finished.
+#[derive(Debug, Clone, PartialEq)]
+pub enum FinishReason {
+    /// The model finished its answer.
+    Stop,
+    /// The model called tools, whose results are sent to the model in the next step.
+    ToolCalls,
+}
+
 /// Levels of reasoning effort for language models that support it.
 #[derive(Debug, Clone, Copy, Default)]
 pub enum ReasoningEffort {
@@ -587,6 +921,30 @@ pub enum ReasoningEffort {
     High,
 }
 
+/// Controls how the model selects tools.
+///
+/// `Required` and `Tool` only apply until the model calls a tool. The following steps
+/// use `Auto`, unless an `on_step_start` hook sets another choice.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub enum ToolChoice {
+    /// The model decides whether to call tools.
+    #[default]
+    Auto,
+    /// The model must not call any tools.
+    None,
+    /// The model must call at least one tool.
+    Required,
+    /// The model must call the tool with the given name.
+    Tool(String),
+}
+
+impl ToolChoice {
+    /// Creates a choice that forces the tool with the given name.
+    pub fn tool(name: impl Into<String>) -> Self {
+        Self::Tool(name.into())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -982,4 +1340,329 @@ mod tests {
             assert_eq!(result.tool.name, format!("tool{i}"));
         }
     }
+
+    fn wait_tool(execute: crate::core::tools::ToolExecute) -> LanguageModelOptions {
+        LanguageModelOptions {
+            tools: Some(ToolList::new(vec![crate::core::tools::Tool {
+                name: "wait".to_string(),
+                execute,
+                ..Default::default()
+            }])),
+            ..Default::default()
+        }
+    }
+
+    fn wait_calls(count: usize) -> Vec<ToolCallInfo> {
+        (0..count)
+            .map(|i| {
+                let mut call = ToolCallInfo::new("wait");
+                call.id(format!("call_{i}"));
+                call.input(serde_json::json!({ "id": i }));
+                call
+            })
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn test_handle_tool_calls_runs_concurrently_in_call_order() {
+        // every call waits for all others, so this only completes when they run concurrently
+        let barrier = Arc::new(tokio::sync::Barrier::new(3));
+        let mut options = wait_tool(crate::core::tools::ToolExecute::new_async(Box::new(
+            move |input| {
+                let barrier = barrier.clone();
+                Box::pin(async move {
+                    barrier.wait().await;
+                    Ok(input["id"].to_string())
+                })
+            },
+        )));
+
+        tokio::time::timeout(
+            std::time::Duration::from_secs(5),
+            options.handle_tool_calls(&wait_calls(3)),
+        )
+        .await
+        .expect("tool calls should run concurrently");
+
+        let results = options.tool_results().unwrap();
+        assert_eq!(results.len(), 3);
+        for (i, result) in results.iter().enumerate() {
+            assert_eq!(result.tool.id, format!("call_{i}"));
+            assert_eq!(
+                result.output.clone().unwrap(),
+                ToolOutput::Text(i.to_string())
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn test_handle_tool_calls_respects_concurrency_limit() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        let in_flight = Arc::new(AtomicUsize::new(0));
+        let max_seen = Arc::new(AtomicUsize::new(0));
+        let (in_flight_c, max_seen_c) = (in_flight.clone(), max_seen.clone());
+
+        let mut options = wait_tool(crate::core::tools::ToolExecute::new_async(Box::new(
+            move |_| {
+                let (in_flight, max_seen) = (in_flight_c.clone(), max_seen_c.clone());
+                Box::pin(async move {
+                    let current = in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+                    max_seen.fetch_max(current, Ordering::SeqCst);
+                    tokio::time::sleep(std::time::Duration::from_millis(20)).await;
+                    in_flight.fetch_sub(1, Ordering::SeqCst);
+                    Ok(String::new())
+                })
+            },
+        )));
+        options.max_tool_concurrency = Some(2);
+
+        options.handle_tool_calls(&wait_calls(5)).await;
+
+        assert_eq!(options.tool_results().unwrap().len(), 5);
+        assert_eq!(max_seen.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn test_handle_tool_calls_reports_tool_errors() {
+        let mut options = wait_tool(crate::core::tools::ToolExecute::new(Box::new(|_| {
+            Err("database unavailable".to_string())
+        })));
+
+        options.handle_tool_calls(&wait_calls(1)).await;
+
+        assert_eq!(
+            options.tool_results().unwrap()[0].output,
+            Err(Error::ToolCallError("database unavailable".to_string()))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_handle_tool_calls_keeps_structured_output() {
+        let mut options = wait_tool(crate::core::tools::ToolExecute::new_structured(Box::new(
+            |input, _| Box::pin(async move { Ok(ToolOutput::Json(input)) }),
+        )));
+
+        options.handle_tool_calls(&wait_calls(1)).await;
+
+        assert_eq!(
+            options.tool_results().unwrap()[0].output,
+            Ok(ToolOutput::Json(serde_json::json!({ "id": 0 })))
+        );
+    }
+
+    #[test]
+    fn test_tool_result_output_serialization() {
+        let mut result = ToolResultInfo::new("screenshot");
+        result.output(vec![crate::core::UserContentPart::Text("page".to_string())]);
+        let json = serde_json::to_value(&result).unwrap();
+        assert_eq!(
+            json["output"],
+            serde_json::json!({ "content": [{ "text": "page" }] })
+        );
+        let restored: ToolResultInfo = serde_json::from_value(json).unwrap();
+        assert_eq!(restored.output, result.output);
+
+        // text and JSON outputs keep the plain value form
+        let restored: ToolResultInfo = serde_json::from_value(serde_json::json!({
+            "tool": { "id": "call_1", "name": "lookup" },
+            "output": { "ok": "found" }
+        }))
+        .unwrap();
+        assert_eq!(restored.output, Ok(ToolOutput::Text("found".to_string())));
+    }
+
+    #[derive(Debug, Default, serde::Serialize, serde::Deserialize)]
+    struct TestSignature {
+        value: Option<String>,
+    }
+
+    impl crate::extensions::PersistedExtension for TestSignature {
+        const KEY: &'static str = "test.signature";
+    }
+
+    #[derive(Debug, Default)]
+    struct TestTransient {
+        value: u32,
+    }
+
+    fn sample_conversation() -> Vec<TaggedMessage> {
+        let reasoning = LanguageModelResponseContentType::Reasoning {
+            content: "thinking".to_string(),
+            extensions: crate::extensions::Extensions::default(),
+        };
+        if let LanguageModelResponseContentType::Reasoning { ref extensions, .. } = reasoning {
+            extensions.get_persisted_mut::<TestSignature>().value = Some("sig".to_string());
+            extensions.get_mut::<TestTransient>().value = 7;
+        }
+
+        let mut tool_call = ToolCallInfo::new("get_weather");
+        tool_call.id("call_1");
+        tool_call.input(serde_json::json!({ "city": "Paris" }));
+
+        let mut ok_result = ToolResultInfo::new("get_weather");
+        ok_result.id("call_1");
+        ok_result.output(serde_json::json!("sunny"));
+
+        let mut err_result = ToolResultInfo::new("get_time");
+        err_result.output = Err(Error::ToolCallError("timeout".to_string()));
+
+        vec![
+            TaggedMessage::initial_step_msg(Message::System("be brief".into())),
+            TaggedMessage::initial_step_msg(Message::User(
+                crate::core::UserMessage::new("weather?").with_image(vec![1u8, 2, 3], "image/png"),
+            )),
+            TaggedMessage::new(
+                1,
+                Message::Assistant(AssistantMessage::new(reasoning, None)),
+            ),
+            TaggedMessage::new(
+                1,
+                Message::Assistant(AssistantMessage::new(
+                    LanguageModelResponseContentType::ToolCall(tool_call),
+                    Some(Usage {
+                        input_tokens: Some(3),
+                        ..Default::default()
+                    }),
+                )),
+            ),
+            TaggedMessage::new(1, Message::Tool(ok_result)),
+            TaggedMessage::new(1, Message::Tool(err_result)),
+            TaggedMessage::new(2, Message::Assistant("It is sunny.".to_string().into())),
+        ]
+    }
+
+    #[test]
+    fn test_conversation_state_round_trip() {
+        let state = ConversationState::from_tagged(sample_conversation());
+        let json = state.to_json().unwrap();
+        let restored = ConversationState::from_json(&json).unwrap();
+
+        assert_eq!(
+            restored.version,
+            crate::core::messages::CONVERSATION_STATE_VERSION
+        );
+        assert_eq!(restored.last_step_id(), 2);
+        assert_eq!(restored.to_json().unwrap(), json);
+
+        let messages = restored.messages();
+        assert_eq!(messages.len(), 7);
+        match &messages[1] {
+            Message::User(user) => assert_eq!(
+                user.parts[0],
+                crate::core::UserContentPart::image(
+                    crate::core::DataContent::base64("AQID"),
+                    "image/png"
+                )
+            ),
+            other => panic!("unexpected message {other:?}"),
+        }
+        match &messages[2] {
+            Message::Assistant(AssistantMessage {
+                content: LanguageModelResponseContentType::Reasoning { extensions, .. },
+                ..
+            }) => {
+                assert_eq!(
+                    extensions.get_persisted::<TestSignature>().value.as_deref(),
+                    Some("sig")
+                );
+                assert_eq!(extensions.get::<TestTransient>().value, 0);
+            }
+            other => panic!("unexpected message {other:?}"),
+        }
+        match &messages[5] {
+            Message::Tool(result) => assert_eq!(
+                result.output,
+                Err(Error::ToolCallError("timeout".to_string()))
+            ),
+            other => panic!("unexpected message {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_conversation_state_keeps_unknown_extensions() {
+        let json = serde_json::json!({
+            "version": 1,
+            "messages": [{
+                "step_id": 1,
+                "message": { "assistant": { "content": { "reasoning": {
+                    "content": "thinking",
+                    "extensions": { "other.provider": { "x": 1 } }
+                } } } }
+            }]
+        })
+        .to_string();
+
+        let state = ConversationState::from_json(&json).unwrap();
+        let reserialized: serde_json::Value =
+            serde_json::from_str(&state.to_json().unwrap()).unwrap();
+        assert_eq!(
+            reserialized,
+            serde_json::from_str::<serde_json::Value>(&json).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_conversation_state_rejects_unknown_version() {
+        let json = r#"{"version":99,"messages":[]}"#;
+        assert!(matches!(
+            ConversationState::from_json(json),
+            Err(Error::InvalidInput(_))
+        ));
+    }
+
+    #[test]
+    fn test_conversation_state_from_options() {
+        let options = LanguageModelOptions {
+            messages: sample_conversation(),
+            ..Default::default()
+        };
+        let mut state = options.conversation_state();
+        state.push(Message::User("and tomorrow?".into()));
+        state.push(Message::User("and in Rome?".into()));
+
+        assert_eq!(state.last_step_id(), 3);
+        assert_eq!(state.messages().len(), 9);
+
+        // The follow-up input forms its own step after the previous answer.
+        let options = LanguageModelOptions {
+            messages: state.into_tagged(),
+            ..Default::default()
+        };
+        let steps = options.steps();
+        assert_eq!(
+            steps.iter().map(|s| s.step_id).collect::<Vec<_>>(),
+            vec![0, 1, 2, 3]
+        );
+        assert_eq!(steps[2].messages.len(), 1);
+        assert_eq!(steps[3].messages.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_language_model_stream_partial_objects() {
+        #[derive(Debug, serde::Deserialize)]
+        struct City {
+            name: Option<String>,
+        }
+
+        let (tx, stream) = LanguageModelStream::new();
+        for chunk in [
+            LanguageModelStreamChunkType::Start,
+            LanguageModelStreamChunkType::Text(r#"{"na"#.into()),
+            LanguageModelStreamChunkType::Reasoning("thinking".into()),
+            LanguageModelStreamChunkType::Text(r#"me": "Ro"#.into()),
+            LanguageModelStreamChunkType::Text(r#"me"}"#.into()),
+        ] {
+            tx.send(chunk).unwrap();
+        }
+        drop(tx);
+
+        let names: Vec<Option<String>> = stream
+            .partial_objects::<City>()
+            .map(|city| city.name)
+            .collect()
+            .await;
+
+        assert_eq!(names, vec![None, Some("Ro".into()), Some("Rome".into())]);
+    }
 }
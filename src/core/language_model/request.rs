@@ -6,8 +6,14 @@
 
 use crate::core::Messages;
 use crate::core::capabilities::*;
-use crate::core::language_model::{LanguageModel, LanguageModelOptions};
-use crate::core::tools::Tool;
+use crate::core::client::RetryPolicy;
+use crate::core::language_model::{
+    AbortHandle, InputGuardrailFuture, LanguageModel, LanguageModelOptions,
+};
+use crate::core::messages::{
+    ConversationState, DataContent, Message, UserContentPart, UserMessage,
+};
+use crate::core::tools::{Tool, ToolApproval};
 use schemars::{JsonSchema, schema_for};
 use std::fmt::Debug;
 use std::ops::{Deref, DerefMut};
@@ -90,6 +96,7 @@ pub struct OptionsStage {}
 pub struct LanguageModelRequestBuilder<M: LanguageModel, State = ModelStage> {
     model: Option<M>,
     prompt: Option<String>,
+    attachments: Vec<UserContentPart>,
     options: LanguageModelOptions,
     state: std::marker::PhantomData<State>,
 }
@@ -119,6 +126,7 @@ impl<M: LanguageModel> LanguageModelRequestBuilder<M> {
         LanguageModelRequestBuilder {
             model: None,
             prompt: None,
+            attachments: Vec::new(),
             options: LanguageModelOptions::default(),
             state: std::marker::PhantomData,
         }
@@ -143,6 +151,7 @@ impl<M: LanguageModel> LanguageModelRequestBuilder<M, ModelStage> {
             model: Some(model),
             prompt: self.prompt,
             options: self.options,
+            attachments: self.attachments,
             state: std::marker::PhantomData,
         }
     }
@@ -172,6 +181,7 @@ impl<M: LanguageModel> LanguageModelRequestBuilder<M, SystemStage> {
                 system: Some(system.into()),
                 ..self.options
             },
+            attachments: self.attachments,
             state: std::marker::PhantomData,
         }
     }
@@ -192,6 +202,7 @@ impl<M: LanguageModel> LanguageModelRequestBuilder<M, SystemStage> {
             model: self.model,
             prompt: Some(prompt.into()),
             options: self.options,
+            attachments: self.attachments,
             state: std::marker::PhantomData,
         }
     }
@@ -215,6 +226,36 @@ impl<M: LanguageModel> LanguageModelRequestBuilder<M, SystemStage> {
                 messages: messages.into_iter().map(|msg| msg.into()).collect(),
                 ..self.options
             },
+            attachments: self.attachments,
+            state: std::marker::PhantomData,
+        }
+    }
+
+    /// Resumes a previously saved conversation.
+    ///
+    /// Step ids of the saved messages are kept, so new steps continue after the
+    /// last saved one.
+    ///
+    /// # Parameters
+    ///
+    /// * `state` - A [`ConversationState`] returned by an earlier request.
+    ///
+    /// # Returns
+    ///
+    /// The builder in the [`OptionsStage`] state.
+    pub fn conversation(
+        self,
+        state: ConversationState,
+    ) -> LanguageModelRequestBuilder<M, OptionsStage> {
+        LanguageModelRequestBuilder {
+            model: self.model,
+            prompt: self.prompt,
+            options: LanguageModelOptions {
+                current_step_id: state.last_step_id(),
+                messages: state.into_tagged(),
+                ..self.options
+            },
+            attachments: self.attachments,
             state: std::marker::PhantomData,
         }
     }
@@ -242,6 +283,7 @@ impl<M: LanguageModel> LanguageModelRequestBuilder<M, ConversationStage> {
             model: self.model,
             prompt: Some(prompt.into()),
             options: self.options,
+            attachments: self.attachments,
             state: std::marker::PhantomData,
         }
     }
@@ -269,6 +311,39 @@ impl<M: LanguageModel> LanguageModelRequestBuilder<M, ConversationStage> {
                 messages: messages.into_iter().map(|msg| msg.into()).collect(),
                 ..self.options
             },
+            attachments: self.attachments,
+            state: std::marker::PhantomData,
+        }
+    }
+
+    /// Resumes a previously saved conversation.
+    ///
+    /// Step ids of the saved messages are kept, so new steps continue after the
+    /// last saved one.
+    ///
+    /// # Parameters
+    ///
+    /// * `state` - A [`ConversationState`] returned by an earlier request.
+    ///
+    /// # Returns
+    ///
+    /// The builder in the [`OptionsStage`] state.
+    pub fn conversation(
+        self,
+        state: ConversationState,
+    ) -> LanguageModelRequestBuilder<M, OptionsStage>
+    where
+        M: TextInputSupport,
+    {
+        LanguageModelRequestBuilder {
+            model: self.model,
+            prompt: self.prompt,
+            options: LanguageModelOptions {
+                current_step_id: state.last_step_id(),
+                messages: state.into_tagged(),
+                ..self.options
+            },
+            attachments: self.attachments,
             state: std::marker::PhantomData,
         }
     }
@@ -382,6 +457,86 @@ impl<M: LanguageModel> LanguageModelRequestBuilder<M, OptionsStage> {
         self
     }
 
+    /// Sets how many times `generate_object` re-prompts the model with the
+    /// validation error of an invalid object.
+    ///
+    /// # Parameters
+    ///
+    /// * `max_repair_attempts` - The maximum number of repair attempts.
+    ///
+    /// # Returns
+    ///
+    /// The builder with max repair attempts set.
+    pub fn max_repair_attempts(mut self, max_repair_attempts: impl Into<u32>) -> Self {
+        self.max_repair_attempts = Some(max_repair_attempts.into());
+        self
+    }
+
+    /// Sets the retry policy for failed requests, replacing the provider's policy.
+    ///
+    /// # Parameters
+    ///
+    /// * `retry_policy` - The [`RetryPolicy`] to use for this request.
+    ///
+    /// # Returns
+    ///
+    /// The builder with the retry policy set.
+    pub fn retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = Some(retry_policy);
+        self
+    }
+
+    /// Sets a handle to abort the generation, e.g. from another task.
+    ///
+    /// # Parameters
+    ///
+    /// * `abort_handle` - The [`AbortHandle`] to abort the generation with.
+    ///
+    /// # Returns
+    ///
+    /// The builder with the abort handle set.
+    pub fn abort_handle(mut self, abort_handle: AbortHandle) -> Self {
+        self.abort_handle = Some(abort_handle);
+        self
+    }
+
+    /// Decides on a tool call that needs approval.
+    ///
+    /// Use it together with [`conversation`](LanguageModelRequestBuilder::conversation)
+    /// to resume a generation that stopped with
+    /// [`StopReason::ToolApprovalRequired`](crate::core::language_model::StopReason::ToolApprovalRequired).
+    ///
+    /// # Parameters
+    ///
+    /// * `id` - The id of the pending tool call.
+    /// * `decision` - Whether to run, edit or deny the call.
+    ///
+    /// # Returns
+    ///
+    /// The builder with the decision recorded.
+    pub fn tool_approval(mut self, id: impl Into<String>, decision: ToolApproval) -> Self {
+        self.tool_approvals.insert(id.into(), decision);
+        self
+    }
+
+    /// Attaches a value to the [`ToolContext`](crate::core::tools::ToolContext) of every
+    /// tool call of the request.
+    ///
+    /// Values are stored by type, so a later value of the same type replaces the
+    /// earlier one.
+    ///
+    /// # Parameters
+    ///
+    /// * `value` - The value, e.g. the authenticated user or a database pool.
+    ///
+    /// # Returns
+    ///
+    /// The builder with the value attached.
+    pub fn with_tool_context<T: Send + Sync + 'static>(self, value: T) -> Self {
+        self.tool_context.insert(value);
+        self
+    }
+
     /// Sets the frequency penalty to reduce repetition.
     ///
     /// # Parameters
@@ -413,6 +568,70 @@ impl<M: LanguageModel> LanguageModelRequestBuilder<M, OptionsStage> {
         self
     }
 
+    /// Sets how the model selects tools.
+    ///
+    /// A forced choice ([`ToolChoice::Required`](crate::core::language_model::ToolChoice::Required)
+    /// or [`ToolChoice::Tool`](crate::core::language_model::ToolChoice::Tool)) only applies
+    /// until the first step with tool calls, the following steps use `Auto` so the model
+    /// can answer with the tool results.
+    ///
+    /// # Parameters
+    ///
+    /// * `tool_choice` - The tool choice.
+    ///
+    /// # Returns
+    ///
+    /// The builder with the tool choice set.
+    pub fn tool_choice(
+        mut self,
+        tool_choice: impl Into<crate::core::language_model::ToolChoice>,
+    ) -> Self
+    where
+        M: ToolCallSupport,
+    {
+        self.tool_choice = Some(tool_choice.into());
+        self
+    }
+
+    /// Sets whether the model may return multiple tool calls in a single step.
+    ///
+    /// Only applied by providers that support the setting.
+    ///
+    /// # Parameters
+    ///
+    /// * `parallel_tool_calls` - `true` to allow parallel tool calls.
+    ///
+    /// # Returns
+    ///
+    /// The builder with the setting applied.
+    pub fn parallel_tool_calls(mut self, parallel_tool_calls: bool) -> Self
+    where
+        M: ToolCallSupport,
+    {
+        self.parallel_tool_calls = Some(parallel_tool_calls);
+        self
+    }
+
+    /// Limits how many tool calls from a single step are executed concurrently.
+    ///
+    /// By default all tool calls of a step run at the same time. Results are
+    /// always added to the conversation in call order.
+    ///
+    /// # Parameters
+    ///
+    /// * `max_tool_concurrency` - The maximum number of concurrent tool executions.
+    ///
+    /// # Returns
+    ///
+    /// The builder with the limit set.
+    pub fn max_tool_concurrency(mut self, max_tool_concurrency: usize) -> Self
+    where
+        M: ToolCallSupport,
+    {
+        self.max_tool_concurrency = Some(max_tool_concurrency);
+        self
+    }
+
     /// Sets a condition to stop the generation loop.
     ///
     /// # Parameters
@@ -464,6 +683,29 @@ impl<M: LanguageModel> LanguageModelRequestBuilder<M, OptionsStage> {
         self
     }
 
+    /// Sets a guardrail checking the input before the first generation step.
+    ///
+    /// Generation stops with [`StopReason::Guardrail`](crate::core::language_model::StopReason::Guardrail)
+    /// when the guardrail rejects the input, and fails when the guardrail returns an error.
+    /// Use [`moderation_guardrail`](crate::core::moderation_model::moderation_guardrail)
+    /// to moderate the user input.
+    ///
+    /// # Parameters
+    ///
+    /// * `hook` - An asynchronous function returning the reason for rejecting the input,
+    ///   or `None` to continue.
+    ///
+    /// # Returns
+    ///
+    /// The builder with the guardrail set.
+    pub fn input_guardrail<F>(mut self, hook: F) -> Self
+    where
+        F: Fn(&LanguageModelOptions) -> InputGuardrailFuture + Send + Sync + 'static,
+    {
+        self.input_guardrail = Some(Arc::new(hook));
+        self
+    }
+
     /// Sets the reasoning effort level.
     ///
     /// # Parameters
@@ -484,6 +726,85 @@ impl<M: LanguageModel> LanguageModelRequestBuilder<M, OptionsStage> {
         self
     }
 
+    /// Attaches an image to the user input.
+    ///
+    /// The image is added to the prompt, or to the last user message when
+    /// conversation messages were provided.
+    ///
+    /// # Parameters
+    ///
+    /// * `data` - The image data or URL.
+    /// * `media_type` - The IANA media type of the image, e.g. `image/png`.
+    ///
+    /// # Returns
+    ///
+    /// The builder with the image attached.
+    pub fn image(mut self, data: impl Into<DataContent>, media_type: impl Into<String>) -> Self
+    where
+        M: ImageInputSupport,
+    {
+        self.attachments
+            .push(UserContentPart::image(data, media_type));
+        self
+    }
+
+    /// Attaches an audio clip to the user input.
+    ///
+    /// # Parameters
+    ///
+    /// * `data` - The audio data or URL.
+    /// * `media_type` - The IANA media type of the audio, e.g. `audio/wav`.
+    ///
+    /// # Returns
+    ///
+    /// The builder with the audio attached.
+    pub fn audio(mut self, data: impl Into<DataContent>, media_type: impl Into<String>) -> Self
+    where
+        M: AudioInputSupport,
+    {
+        self.attachments
+            .push(UserContentPart::audio(data, media_type));
+        self
+    }
+
+    /// Attaches a video to the user input.
+    ///
+    /// # Parameters
+    ///
+    /// * `data` - The video data or URL.
+    /// * `media_type` - The IANA media type of the video, e.g. `video/mp4`.
+    ///
+    /// # Returns
+    ///
+    /// The builder with the video attached.
+    pub fn video(mut self, data: impl Into<DataContent>, media_type: impl Into<String>) -> Self
+    where
+        M: VideoInputSupport,
+    {
+        self.attachments
+            .push(UserContentPart::video(data, media_type));
+        self
+    }
+
+    /// Attaches a file such as a PDF document to the user input.
+    ///
+    /// # Parameters
+    ///
+    /// * `data` - The file data or URL.
+    /// * `media_type` - The IANA media type of the file, e.g. `application/pdf`.
+    ///
+    /// # Returns
+    ///
+    /// The builder with the file attached.
+    pub fn file(mut self, data: impl Into<DataContent>, media_type: impl Into<String>) -> Self
+    where
+        M: FileInputSupport,
+    {
+        self.attachments
+            .push(UserContentPart::file(data, media_type));
+        self
+    }
+
     /// Builds the `LanguageModelRequest`.
     ///
     /// This method consumes the builder and returns the configured request.
@@ -491,11 +812,19 @@ impl<M: LanguageModel> LanguageModelRequestBuilder<M, OptionsStage> {
     /// # Returns
     ///
     /// The constructed `LanguageModelRequest`.
-    pub fn build(self) -> LanguageModelRequest<M> {
+    pub fn build(mut self) -> LanguageModelRequest<M> {
         let model = self
             .model
             .unwrap_or_else(|| unreachable!("Model must be set"));
 
+        if !self.attachments.is_empty() {
+            attach_parts(
+                &mut self.options,
+                self.prompt.as_deref(),
+                std::mem::take(&mut self.attachments),
+            );
+        }
+
         LanguageModelRequest {
             model,
             prompt: self.prompt,
@@ -503,3 +832,44 @@ impl<M: LanguageModel> LanguageModelRequestBuilder<M, OptionsStage> {
         }
     }
 }
+
+/// Adds content parts to the user input of the request.
+///
+/// With conversation messages the parts are appended to the last user message. With a
+/// plain prompt the prompt is materialized into messages so the parts can travel along.
+fn attach_parts(
+    options: &mut LanguageModelOptions,
+    prompt: Option<&str>,
+    parts: Vec<UserContentPart>,
+) {
+    if options.messages.is_empty() {
+        if let Some(system) = options.system.as_ref().filter(|s| !s.is_empty()) {
+            options
+                .messages
+                .push(Message::System(system.clone().into()).into());
+        }
+        options
+            .messages
+            .push(Message::User(UserMessage::new(prompt.unwrap_or_default())).into());
+    }
+
+    let last_user = options
+        .messages
+        .iter_mut()
+        .rev()
+        .find_map(|m| match m.message {
+            Message::User(ref mut user) => Some(user),
+            _ => None,
+        });
+
+    match last_user {
+        Some(user) => user.parts.extend(parts),
+        None => options.messages.push(
+            Message::User(UserMessage {
+                content: String::new(),
+                parts,
+            })
+            .into(),
+        ),
+    }
+}
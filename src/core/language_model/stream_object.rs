@@ -0,0 +1,620 @@
+//! Typed structured output streaming impl for the `LanguageModelRequest` trait.
+
+use crate::core::LanguageModelStreamChunkType;
+use crate::core::capabilities::StructuredOutputSupport;
+use crate::core::language_model::{
+    LanguageModel, LanguageModelOptions, LanguageModelStream, StopReason, Usage,
+    generate_object::OutputSchema, request::LanguageModelRequest, stream_text::StreamTextResponse,
+};
+use crate::core::partial_json::PartialJsonParser;
+use crate::error::Result;
+use futures::Stream;
+use schemars::{JsonSchema, schema_for};
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+use std::collections::VecDeque;
+use std::marker::PhantomData;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::{Context, Poll};
+use tokio::sync::Mutex;
+
+impl<M: LanguageModel + StructuredOutputSupport> LanguageModelRequest<M> {
+    /// Streams a structured object of type `T` using the language model.
+    ///
+    /// The stream yields a [`ObjectStreamChunk::Partial`] snapshot whenever a new part
+    /// of the object has been generated, followed by the validated
+    /// [`ObjectStreamChunk::Object`] once generation completes.
+    ///
+    /// # Type Parameters
+    ///
+    /// * `T` - The type of the generated object.
+    ///
+    /// # Returns
+    ///
+    /// A [`StreamObjectResponse`] containing the [`ObjectStream`] and the conversation.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error` if the underlying language model fails to start streaming.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    ///# #[cfg(feature = "openai")]
+    ///# {
+    ///    use aisdk::{
+    ///        core::{LanguageModelRequest, language_model::stream_object::ObjectStreamChunk},
+    ///        providers::OpenAI,
+    ///    };
+    ///    use futures::StreamExt;
+    ///    use schemars::JsonSchema;
+    ///    use serde::Deserialize;
+    ///
+    ///    #[derive(Debug, Deserialize, JsonSchema)]
+    ///    struct Recipe {
+    ///        name: String,
+    ///        steps: Vec<String>,
+    ///    }
+    ///
+    ///    async fn run() -> aisdk::Result<()> {
+    ///        let mut stream = LanguageModelRequest::builder()
+    ///            .model(OpenAI::gpt_5())
+    ///            .prompt("A recipe for pancakes")
+    ///            .build()
+    ///            .stream_object::<Recipe>()
+    ///            .await?
+    ///            .stream;
+    ///
+    ///        while let Some(chunk) = stream.next().await {
+    ///            match chunk {
+    ///                ObjectStreamChunk::Partial(partial) => println!("{partial}"),
+    ///                ObjectStreamChunk::Object(recipe) => println!("{recipe:?}"),
+    ///                ObjectStreamChunk::Failed(error) => eprintln!("{error}"),
+    ///            }
+    ///        }
+    ///        Ok(())
+    ///    }
+    ///# }
+    /// ```
+    pub async fn stream_object<T: JsonSchema + DeserializeOwned>(
+        &mut self,
+    ) -> Result<StreamObjectResponse<ObjectStream<T>>> {
+        let output = OutputSchema::object(schema_for!(T));
+        let (stream, options) = self.stream_output(&output).await?;
+
+        Ok(StreamObjectResponse {
+            stream: ObjectStream {
+                inner: stream,
+                output,
+                parser: PartialJsonParser::new(),
+                finished: false,
+                object: PhantomData,
+            },
+            options,
+        })
+    }
+
+    /// Streams an array of elements of type `T` using the language model.
+    ///
+    /// The stream yields an [`ArrayStreamChunk::Element`] as soon as an element is
+    /// complete and valid, so elements can be processed while the rest of the array
+    /// is generated.
+    ///
+    /// # Type Parameters
+    ///
+    /// * `T` - The type of the array elements.
+    ///
+    /// # Returns
+    ///
+    /// A [`StreamObjectResponse`] containing the [`ArrayStream`] and the conversation.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error` if the underlying language model fails to start streaming.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    ///# #[cfg(feature = "openai")]
+    ///# {
+    ///    use aisdk::{
+    ///        core::{LanguageModelRequest, language_model::stream_object::ArrayStreamChunk},
+    ///        providers::OpenAI,
+    ///    };
+    ///    use futures::StreamExt;
+    ///    use schemars::JsonSchema;
+    ///    use serde::Deserialize;
+    ///
+    ///    #[derive(Debug, Deserialize, JsonSchema)]
+    ///    struct Hero {
+    ///        name: String,
+    ///        power: String,
+    ///    }
+    ///
+    ///    async fn run() -> aisdk::Result<()> {
+    ///        let mut stream = LanguageModelRequest::builder()
+    ///            .model(OpenAI::gpt_5())
+    ///            .prompt("Invent three heroes")
+    ///            .build()
+    ///            .stream_array::<Hero>()
+    ///            .await?
+    ///            .stream;
+    ///
+    ///        while let Some(chunk) = stream.next().await {
+    ///            match chunk {
+    ///                ArrayStreamChunk::Element(hero) => println!("{hero:?}"),
+    ///                ArrayStreamChunk::Failed(error) => eprintln!("{error}"),
+    ///            }
+    ///        }
+    ///        Ok(())
+    ///    }
+    ///# }
+    /// ```
+    pub async fn stream_array<T: JsonSchema + DeserializeOwned>(
+        &mut self,
+    ) -> Result<StreamObjectResponse<ArrayStream<T>>> {
+        let output = OutputSchema::array(schema_for!(T));
+        let (stream, options) = self.stream_output(&output).await?;
+
+        Ok(StreamObjectResponse {
+            stream: ArrayStream {
+                inner: stream,
+                output,
+                parser: PartialJsonParser::new(),
+                emitted: 0,
+                pending: VecDeque::new(),
+                finished: false,
+            },
+            options,
+        })
+    }
+
+    /// Starts streaming text constrained to `output`.
+    async fn stream_output(
+        &mut self,
+        output: &OutputSchema,
+    ) -> Result<(LanguageModelStream, Arc<Mutex<LanguageModelOptions>>)> {
+        let mut request = LanguageModelRequest {
+            model: self.model.clone(),
+            prompt: self.prompt.clone(),
+            options: LanguageModelOptions {
+                schema: Some(output.schema().clone()),
+                ..self.options.clone()
+            },
+        };
+
+        let StreamTextResponse { stream, options } = request.stream_text().await?;
+        Ok((stream, options))
+    }
+}
+
+// ============================================================================
+// Section: response types
+// ============================================================================
+
+/// A chunk of a structured object stream.
+#[derive(Debug, Clone)]
+pub enum ObjectStreamChunk<T> {
+    /// A best-effort snapshot of the object generated so far.
+    Partial(Value),
+    /// The complete object, validated against the schema.
+    Object(T),
+    /// Generation failed, or the final object does not match the schema.
+    Failed(String),
+}
+
+/// A stream of partial objects followed by the final object.
+///
+/// The stream ends without a chunk when the input guardrail rejects the input;
+/// [`StreamObjectResponse::stop_reason`] then returns [`StopReason::Guardrail`].
+pub struct ObjectStream<T> {
+    inner: LanguageModelStream,
+    output: OutputSchema,
+    parser: PartialJsonParser,
+    finished: bool,
+    object: PhantomData<fn() -> T>,
+}
+
+impl<T: DeserializeOwned> Stream for ObjectStream<T> {
+    type Item = ObjectStreamChunk<T>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = &mut *self;
+        loop {
+            if this.finished {
+                return Poll::Ready(None);
+            }
+
+            match Pin::new(&mut this.inner).poll_next(cx) {
+                Poll::Pending => return Poll::Pending,
+                Poll::Ready(Some(LanguageModelStreamChunkType::Text(delta))) => {
+                    let partial = this
+                        .parser
+                        .push(&delta)
+                        .and_then(|snapshot| this.output.unwrap(&snapshot).cloned());
+                    if let Some(partial) = partial {
+                        return Poll::Ready(Some(ObjectStreamChunk::Partial(partial)));
+                    }
+                }
+                // No object is generated when the guardrail rejects the input.
+                Poll::Ready(Some(LanguageModelStreamChunkType::Finish {
+                    stop_reason: StopReason::Guardrail(_),
+                    ..
+                })) => {
+                    this.finished = true;
+                    return Poll::Ready(None);
+                }
+                // Each step answers anew, e.g. after a tool call.
+                Poll::Ready(Some(LanguageModelStreamChunkType::StepStart { .. })) => {
+                    this.parser.reset();
+                }
+                Poll::Ready(Some(
+                    LanguageModelStreamChunkType::Failed(error)
+                    | LanguageModelStreamChunkType::Incomplete(error),
+                )) => {
+                    this.finished = true;
+                    return Poll::Ready(Some(ObjectStreamChunk::Failed(error)));
+                }
+                Poll::Ready(Some(_)) => {}
+                Poll::Ready(None) => {
+                    this.finished = true;
+                    let chunk = match this.output.parse(this.parser.text()) {
+                        Ok(object) => ObjectStreamChunk::Object(object),
+                        Err(e) => ObjectStreamChunk::Failed(e.to_string()),
+                    };
+                    return Poll::Ready(Some(chunk));
+                }
+            }
+        }
+    }
+}
+
+/// A chunk of a structured array stream.
+#[derive(Debug, Clone)]
+pub enum ArrayStreamChunk<T> {
+    /// A complete element, validated against the element schema.
+    Element(T),
+    /// Generation failed, or an element does not match the schema.
+    Failed(String),
+}
+
+/// A stream of array elements, each yielded once it is complete.
+///
+/// The stream ends without a chunk when the input guardrail rejects the input;
+/// [`StreamObjectResponse::stop_reason`] then returns [`StopReason::Guardrail`].
+pub struct ArrayStream<T> {
+    inner: LanguageModelStream,
+    output: OutputSchema,
+    parser: PartialJsonParser,
+    // The number of elements already parsed.
+    emitted: usize,
+    // Chunks to yield before polling the inner stream, in order.
+    pending: VecDeque<ArrayStreamChunk<T>>,
+    finished: bool,
+}
+
+// The stream is never pinned structurally, so pending elements need not be `Unpin`.
+impl<T> Unpin for ArrayStream<T> {}
+
+impl<T: DeserializeOwned> ArrayStream<T> {
+    /// Queues the elements followed by another element in the latest snapshot.
+    ///
+    /// An invalid element queues a failure after the valid elements before it
+    /// and finishes the stream.
+    fn take_complete_elements(&mut self) {
+        let Some(elements) = self
+            .parser
+            .snapshot()
+            .and_then(|snapshot| self.output.unwrap(snapshot))
+            .and_then(Value::as_array)
+        else {
+            return;
+        };
+
+        // The last element may still be incomplete until the array is closed.
+        let complete = elements.len().saturating_sub(1);
+        for (index, element) in elements
+            .iter()
+            .enumerate()
+            .take(complete)
+            .skip(self.emitted)
+        {
+            match self.output.parse_element(element, index) {
+                Ok(element) => self.pending.push_back(ArrayStreamChunk::Element(element)),
+                Err(e) => {
+                    self.pending
+                        .push_back(ArrayStreamChunk::Failed(e.to_string()));
+                    self.finished = true;
+                    return;
+                }
+            }
+        }
+        self.emitted = self.emitted.max(complete);
+    }
+}
+
+impl<T: DeserializeOwned> Stream for ArrayStream<T> {
+    type Item = ArrayStreamChunk<T>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = &mut *self;
+        loop {
+            if let Some(chunk) = this.pending.pop_front() {
+                return Poll::Ready(Some(chunk));
+            }
+            if this.finished {
+                return Poll::Ready(None);
+            }
+
+            match Pin::new(&mut this.inner).poll_next(cx) {
+                Poll::Pending => return Poll::Pending,
+                Poll::Ready(Some(LanguageModelStreamChunkType::Text(delta))) => {
+                    this.parser.push(&delta);
+                    this.take_complete_elements();
+                }
+                // No object is generated when the guardrail rejects the input.
+                Poll::Ready(Some(LanguageModelStreamChunkType::Finish {
+                    stop_reason: StopReason::Guardrail(_),
+                    ..
+                })) => {
+                    this.finished = true;
+                    return Poll::Ready(None);
+                }
+                // Each step answers anew, e.g. after a tool call.
+                Poll::Ready(Some(LanguageModelStreamChunkType::StepStart { .. })) => {
+                    this.parser.reset();
+                    this.emitted = 0;
+                }
+                Poll::Ready(Some(
+                    LanguageModelStreamChunkType::Failed(error)
+                    | LanguageModelStreamChunkType::Incomplete(error),
+                )) => {
+                    this.finished = true;
+                    return Poll::Ready(Some(ArrayStreamChunk::Failed(error)));
+                }
+                Poll::Ready(Some(_)) => {}
+                Poll::Ready(None) => {
+                    this.finished = true;
+                    match this.output.parse::<Vec<T>>(this.parser.text()) {
+                        Ok(elements) => this.pending.extend(
+                            elements
+                                .into_iter()
+                                .skip(this.emitted)
+                                .map(ArrayStreamChunk::Element),
+                        ),
+                        Err(e) => {
+                            return Poll::Ready(Some(ArrayStreamChunk::Failed(e.to_string())));
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Response from a `stream_object` or `stream_array` call.
+pub struct StreamObjectResponse<S> {
+    /// The stream of partial objects and the final object, or of array elements.
+    pub stream: S,
+    // The conversation state shared with the generation task.
+    options: Arc<Mutex<LanguageModelOptions>>,
+}
+
+impl<S> StreamObjectResponse<S> {
+    /// Returns the total token usage, once the stream has completed.
+    pub async fn usage(&self) -> Usage {
+        self.options.lock().await.usage()
+    }
+
+    /// Returns the reason generation stopped, once the stream has completed.
+    pub async fn stop_reason(&self) -> Option<StopReason> {
+        self.options.lock().await.stop_reason()
+    }
+
+    /// Returns the options of the request, including the generated messages.
+    pub async fn options(&self) -> LanguageModelOptions {
+        self.options.lock().await.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::language_model::mock::fixtures::{echo_call, echo_tool};
+    use crate::core::language_model::{
+        LanguageModelResponseContentType, LanguageModelStreamChunk, MockLanguageModel,
+    };
+    use crate::core::messages::AssistantMessage;
+    use crate::error::Error;
+    use futures::StreamExt;
+    use serde::Deserialize;
+    use serde_json::json;
+
+    #[derive(Debug, PartialEq, Deserialize, JsonSchema)]
+    struct Recipe {
+        name: String,
+        steps: Vec<String>,
+    }
+
+    async fn collect(model: MockLanguageModel) -> Vec<ObjectStreamChunk<Recipe>> {
+        LanguageModelRequest::builder()
+            .model(model)
+            .prompt("A recipe")
+            .build()
+            .stream_object::<Recipe>()
+            .await
+            .unwrap()
+            .stream
+            .collect()
+            .await
+    }
+
+    #[tokio::test]
+    async fn test_stream_object_yields_partials_then_object() {
+        let model = MockLanguageModel::new().with_text_stream([
+            r#"{"name": "Pan"#,
+            r#"cakes", "steps": ["Mix"#,
+            r#"", "Fry"]}"#,
+        ]);
+
+        let chunks = collect(model.clone()).await;
+
+        let partials: Vec<Value> = chunks
+            .iter()
+            .filter_map(|c| match c {
+                ObjectStreamChunk::Partial(v) => Some(v.clone()),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(
+            partials,
+            vec![
+                json!({"name": "Pan"}),
+                json!({"name": "Pancakes", "steps": ["Mix"]}),
+                json!({"name": "Pancakes", "steps": ["Mix", "Fry"]}),
+            ]
+        );
+        assert!(matches!(
+            chunks.last(),
+            Some(ObjectStreamChunk::Object(recipe)) if recipe.steps.len() == 2
+        ));
+        assert!(model.last_call().unwrap().schema.is_some());
+    }
+
+    #[tokio::test]
+    async fn test_stream_object_reports_invalid_object() {
+        let model = MockLanguageModel::new().with_text_stream([r#"{"name": "Pancakes"}"#]);
+
+        let chunks = collect(model).await;
+
+        assert!(matches!(
+            chunks.last(),
+            Some(ObjectStreamChunk::Failed(e)) if e.contains("missing required property 'steps'")
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_stream_object_reports_model_failure() {
+        let model = MockLanguageModel::new().with_stream_error(Error::Other("down".into()));
+
+        let chunks = collect(model).await;
+
+        assert!(matches!(
+            chunks.as_slice(),
+            [ObjectStreamChunk::Failed(e)] if e.contains("down")
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_stream_object_parses_only_the_last_step() {
+        let model = MockLanguageModel::new()
+            .with_stream(vec![
+                LanguageModelStreamChunk::Delta(LanguageModelStreamChunkType::Text(
+                    "Let me check.".to_string(),
+                )),
+                LanguageModelStreamChunk::Done(AssistantMessage::new(
+                    LanguageModelResponseContentType::ToolCall(echo_call()),
+                    None,
+                )),
+            ])
+            .with_text_stream([r#"{"name": "Pancakes", "steps": ["Mix"]}"#]);
+
+        let chunks: Vec<ObjectStreamChunk<Recipe>> = LanguageModelRequest::builder()
+            .model(model)
+            .prompt("A recipe")
+            .with_tool(echo_tool())
+            .build()
+            .stream_object::<Recipe>()
+            .await
+            .unwrap()
+            .stream
+            .collect()
+            .await;
+
+        assert!(matches!(
+            chunks.last(),
+            Some(ObjectStreamChunk::Object(recipe)) if recipe.name == "Pancakes"
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_stream_array_yields_complete_elements() {
+        let model = MockLanguageModel::new().with_text_stream([
+            r#"{"elements": [{"name": "Pan"#,
+            r#"cakes", "steps": ["Mix"]}, {"na"#,
+            r#"me": "Eggs", "steps": []}]}"#,
+        ]);
+
+        let chunks: Vec<ArrayStreamChunk<Recipe>> = LanguageModelRequest::builder()
+            .model(model)
+            .prompt("Two recipes")
+            .build()
+            .stream_array::<Recipe>()
+            .await
+            .unwrap()
+            .stream
+            .collect()
+            .await;
+
+        let names: Vec<String> = chunks
+            .into_iter()
+            .map(|c| match c {
+                ArrayStreamChunk::Element(recipe) => recipe.name,
+                ArrayStreamChunk::Failed(e) => panic!("unexpected failure: {e}"),
+            })
+            .collect();
+        assert_eq!(names, vec!["Pancakes", "Eggs"]);
+    }
+
+    #[tokio::test]
+    async fn test_stream_array_fails_on_invalid_element() {
+        let model = MockLanguageModel::new().with_text_stream([
+            r#"{"elements": [{"name": "Pancakes"}, "#,
+            r#"{"name": "Eggs", "steps": []}]}"#,
+        ]);
+
+        let chunks: Vec<ArrayStreamChunk<Recipe>> = LanguageModelRequest::builder()
+            .model(model)
+            .prompt("Two recipes")
+            .build()
+            .stream_array::<Recipe>()
+            .await
+            .unwrap()
+            .stream
+            .collect()
+            .await;
+
+        assert!(matches!(
+            chunks.as_slice(),
+            [ArrayStreamChunk::Failed(e)] if e.contains("/elements/0: missing required property 'steps'")
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_stream_array_yields_valid_elements_before_failure() {
+        let model = MockLanguageModel::new().with_text_stream([
+            r#"{"elements": [{"name": "Pancakes", "steps": []}, {"name": "Eggs"}, "#,
+            r#"{"name": "Toast", "steps": []}]}"#,
+        ]);
+
+        let chunks: Vec<ArrayStreamChunk<Recipe>> = LanguageModelRequest::builder()
+            .model(model)
+            .prompt("Three recipes")
+            .build()
+            .stream_array::<Recipe>()
+            .await
+            .unwrap()
+            .stream
+            .collect()
+            .await;
+
+        assert!(matches!(
+            chunks.as_slice(),
+            [
+                ArrayStreamChunk::Element(recipe),
+                ArrayStreamChunk::Failed(e),
+            ] if recipe.name == "Pancakes" && e.contains("/elements/1: missing required property 'steps'")
+        ));
+    }
+}
@@ -1,11 +1,12 @@
 //! Text Streaming impl for the `LanguageModelRequest` trait.
 
 use crate::core::{
-    AssistantMessage, LanguageModelStreamChunkType, Message, Messages, ToolCallInfo,
-    ToolResultInfo,
+    AssistantMessage, ConversationState, LanguageModelStreamChunkType, Message, Messages,
+    ToolCallInfo, ToolResultInfo,
     language_model::{
-        LanguageModel, LanguageModelOptions, LanguageModelResponseContentType, LanguageModelStream,
-        LanguageModelStreamChunk, Step, StopReason, Usage, request::LanguageModelRequest,
+        FinishReason, LanguageModel, LanguageModelOptions, LanguageModelResponseContentType,
+        LanguageModelStream, LanguageModelStreamChunk, Step, StopReason, Usage,
+        request::LanguageModelRequest,
     },
     messages::TaggedMessage,
     utils::resolve_message,
@@ -75,9 +76,15 @@ impl<M: LanguageModel> LanguageModelRequest<M> {
             schema: self.options.schema.to_owned(),
             stop_sequences: self.options.stop_sequences.to_owned(),
             tools: self.options.tools.to_owned(),
+            tool_choice: self.options.tool_choice.clone(),
+            retry_policy: self.options.retry_policy.clone(),
+            abort_handle: self.options.abort_handle.clone(),
+            tool_approvals: self.options.tool_approvals.clone(),
+            tool_context: self.options.tool_context.clone(),
             stop_when: self.options.stop_when.clone(),
             on_step_start: self.options.on_step_start.clone(),
             on_step_finish: self.options.on_step_finish.clone(),
+            input_guardrail: self.options.input_guardrail.clone(),
             stop_reason: None,
             ..self.options
         }));
@@ -87,118 +94,182 @@ impl<M: LanguageModel> LanguageModelRequest<M> {
 
         let mut model = self.model.clone();
 
+        let abort = self.options.abort_handle.clone().unwrap_or_default();
         let thread_options = options.clone();
         tokio::spawn(async move {
-            loop {
-                let mut options = thread_options.lock().await;
-                // Update the current step
-                options.current_step_id += 1;
-                let current_step_id = options.current_step_id;
-
-                // Prepare the next step
-                if let Some(hook) = options.on_step_start.clone() {
-                    hook(&mut options);
+            let generation = async {
+                // Check the input before anything reaches the model or the tools
+                {
+                    let mut options = thread_options.lock().await;
+                    if let Some(hook) = options.input_guardrail.clone() {
+                        match hook(&options).await {
+                            // The finish chunk reports the rejection.
+                            Ok(Some(reason)) => {
+                                options.stop_reason = Some(StopReason::Guardrail(reason));
+                                return Ok(());
+                            }
+                            Ok(None) => {}
+                            Err(e) => {
+                                options.stop_reason = Some(StopReason::Error(e.clone()));
+                                let _ = tx.send(LanguageModelStreamChunkType::Failed(format!(
+                                    "Input guardrail failed: {e}"
+                                )));
+                                return Err(e);
+                            }
+                        }
+                    }
                 }
 
-                let response_result = model.stream_text(options.clone()).await;
-                let mut response = match response_result {
-                    Ok(r) => r,
-                    Err(e) => {
-                        options.stop_reason = Some(StopReason::Error(e.clone()));
-                        let _ = tx.send(LanguageModelStreamChunkType::Failed(format!(
-                            "Model streaming failed: {e}"
-                        )));
-                        return Err(e);
+                // Run the tool calls a resumed conversation stopped at
+                {
+                    let mut options = thread_options.lock().await;
+                    let unresolved = options.unresolved_tool_calls();
+                    let outcome = options.handle_tool_calls(&unresolved).await;
+                    for result in outcome.results.iter().cloned() {
+                        let _ = tx.send(LanguageModelStreamChunkType::ToolOutputAvailable(result));
                     }
-                };
-
-                while let Some(ref chunk) = response.next().await {
-                    match chunk {
-                        Ok(chunk) => {
-                            for output in chunk {
-                                match output {
-                                    LanguageModelStreamChunk::Done(final_msg) => {
-                                        match final_msg.content {
-                                            LanguageModelResponseContentType::Text(_) => {
-                                                let assistant_msg =
-                                                    Message::Assistant(AssistantMessage {
-                                                        content: final_msg.content.clone(),
-                                                        usage: final_msg.usage.clone(),
-                                                    });
-                                                options.messages.push(TaggedMessage::new(
-                                                    current_step_id,
-                                                    assistant_msg,
-                                                ));
-                                                options.stop_reason = Some(StopReason::Finish);
-                                            }
-                                            LanguageModelResponseContentType::Reasoning {
-                                                ref content,
-                                                ref extensions,
-                                            } => {
-                                                options.messages.push(TaggedMessage::new(
-                                                current_step_id,
-                                                Message::Assistant(AssistantMessage {
-                                                    content:
-                                                        LanguageModelResponseContentType::Reasoning {
-                                                            content: content.clone(),
-                                                            extensions: extensions.clone(),
-                                                        },
-                                                    usage: final_msg.usage.clone(),
-                                                    }),
-                                                ));
-                                                options.stop_reason = Some(StopReason::Finish);
-                                            }
-                                            LanguageModelResponseContentType::ToolCall(
-                                                ref tool_info,
-                                            ) => {
-                                                // add tool message
-                                                let usage = final_msg.usage.clone();
-                                                let _ = &options.messages.push(TaggedMessage::new(
-                                                    current_step_id.to_owned(),
-                                                    Message::Assistant(AssistantMessage::new(
-                                                        LanguageModelResponseContentType::ToolCall(
-                                                            tool_info.clone(),
-                                                        ),
-                                                        usage,
-                                                    )),
-                                                ));
-                                                options.handle_tool_call(tool_info).await;
-                                            }
-                                            _ => {}
-                                        }
+                    for call in outcome.pending.iter().cloned() {
+                        let _ = tx.send(LanguageModelStreamChunkType::ToolApprovalRequest(call));
+                    }
+                    if let Some(stop_reason) = outcome.stop_reason() {
+                        options.stop_reason = Some(stop_reason);
+                        return Ok(());
+                    }
+                }
 
-                                        // Finish the step
-                                        if let Some(ref hook) = options.on_step_finish {
-                                            hook(&options);
-                                        }
+                loop {
+                    let mut options = thread_options.lock().await;
+                    // Update the current step
+                    options.current_step_id += 1;
+                    let current_step_id = options.current_step_id;
+
+                    // Prepare the next step
+                    if let Some(hook) = options.on_step_start.clone() {
+                        hook(&mut options);
+                    }
+                    let _ = tx.send(LanguageModelStreamChunkType::StepStart {
+                        step_id: current_step_id,
+                    });
+
+                    let response_result = model.stream_text(options.clone()).await;
+                    let mut response = match response_result {
+                        Ok(r) => r,
+                        Err(e) => {
+                            options.stop_reason = Some(StopReason::Error(e.clone()));
+                            let _ = tx.send(LanguageModelStreamChunkType::Failed(format!(
+                                "Model streaming failed: {e}"
+                            )));
+                            return Err(e);
+                        }
+                    };
 
-                                        // Stop If
-                                        if let Some(hook) = &options.stop_when.clone()
-                                            && hook(&options)
-                                        {
-                                            let _ =
-                                                tx.send(LanguageModelStreamChunkType::Incomplete(
-                                                    "Stopped by hook".to_string(),
-                                                ));
-                                            options.stop_reason = Some(StopReason::Hook);
-                                            break;
+                    // Collect the messages of the step until the provider stream ends
+                    let mut step_done = false;
+                    let mut finished = false;
+                    let mut tool_calls = Vec::new();
+                    while let Some(chunk) = response.next().await {
+                        let chunk = match chunk {
+                            Ok(chunk) => chunk,
+                            Err(e) => {
+                                let _ =
+                                    tx.send(LanguageModelStreamChunkType::Failed(e.to_string()));
+                                options.stop_reason = Some(StopReason::Error(e));
+                                break;
+                            }
+                        };
+
+                        for output in chunk {
+                            match output {
+                                LanguageModelStreamChunk::Done(final_msg) => {
+                                    step_done = true;
+                                    match final_msg.content {
+                                        LanguageModelResponseContentType::Text(_)
+                                        | LanguageModelResponseContentType::Reasoning { .. } => {
+                                            options.messages.push(TaggedMessage::new(
+                                                current_step_id,
+                                                Message::Assistant(final_msg),
+                                            ));
+                                            finished = true;
                                         }
-                                    }
-                                    LanguageModelStreamChunk::Delta(other) => match other {
-                                        // Propagate text and reasoning chunks
-                                        LanguageModelStreamChunkType::Text(_)
-                                        | LanguageModelStreamChunkType::Reasoning(_) => {
-                                            let _ = tx.send(other.clone());
+                                        LanguageModelResponseContentType::ToolCall(tool_info) => {
+                                            // add tool message
+                                            options.messages.push(TaggedMessage::new(
+                                                current_step_id,
+                                                Message::Assistant(AssistantMessage::new(
+                                                    LanguageModelResponseContentType::ToolCall(
+                                                        tool_info.clone(),
+                                                    ),
+                                                    final_msg.usage,
+                                                )),
+                                            ));
+                                            let _ = tx.send(
+                                                LanguageModelStreamChunkType::ToolInputAvailable(
+                                                    tool_info.clone(),
+                                                ),
+                                            );
+                                            tool_calls.push(tool_info);
                                         }
                                         _ => {}
-                                    },
+                                    }
                                 }
+                                LanguageModelStreamChunk::Delta(other) => match other {
+                                    // Propagate text, reasoning and tool input chunks
+                                    LanguageModelStreamChunkType::Text(_)
+                                    | LanguageModelStreamChunkType::Reasoning(_)
+                                    | LanguageModelStreamChunkType::ToolInputStart { .. }
+                                    | LanguageModelStreamChunkType::ToolInputDelta { .. } => {
+                                        let _ = tx.send(other);
+                                    }
+                                    _ => {}
+                                },
                             }
                         }
-                        Err(e) => {
-                            let _ = tx.send(LanguageModelStreamChunkType::Failed(e.to_string()));
-                            options.stop_reason = Some(StopReason::Error(e.clone()));
-                            break;
+                    }
+
+                    if options.stop_reason.is_none() && step_done {
+                        // Run the tool calls of this step concurrently
+                        let outcome = options.handle_tool_calls(&tool_calls).await;
+                        if !tool_calls.is_empty() {
+                            options.relax_tool_choice();
+                        }
+                        for result in outcome.results.iter().cloned() {
+                            let _ =
+                                tx.send(LanguageModelStreamChunkType::ToolOutputAvailable(result));
+                        }
+                        for call in outcome.pending.iter().cloned() {
+                            let _ =
+                                tx.send(LanguageModelStreamChunkType::ToolApprovalRequest(call));
+                        }
+
+                        // Finish the step
+                        if let Some(ref hook) = options.on_step_finish {
+                            hook(&options);
+                        }
+                        let _ = tx.send(LanguageModelStreamChunkType::StepFinish {
+                            step_id: current_step_id,
+                            usage: options
+                                .step(current_step_id)
+                                .map(|step| step.usage())
+                                .unwrap_or_default(),
+                            finish_reason: if tool_calls.is_empty() {
+                                FinishReason::Stop
+                            } else {
+                                FinishReason::ToolCalls
+                            },
+                        });
+
+                        // Stop If
+                        if let Some(stop_reason) = outcome.stop_reason() {
+                            options.stop_reason = Some(stop_reason);
+                        } else if let Some(hook) = &options.stop_when.clone()
+                            && hook(&options)
+                        {
+                            let _ = tx.send(LanguageModelStreamChunkType::Incomplete(
+                                "Stopped by hook".to_string(),
+                            ));
+                            options.stop_reason = Some(StopReason::Hook);
+                        } else if finished && tool_calls.is_empty() {
+                            options.stop_reason = Some(StopReason::Finish);
                         }
                     }
 
@@ -207,16 +278,25 @@ impl<M: LanguageModel> LanguageModelRequest<M> {
                         _ => break,
                     };
                 }
+                Ok(())
+            };
 
-                match options.stop_reason {
-                    None => {}
-                    _ => break,
-                };
-            }
+            // Dropping the generation closes the model stream and skips the
+            // remaining tool calls.
+            let (result, aborted) = tokio::select! {
+                biased;
+                _ = abort.aborted() => (Ok(()), true),
+                _ = tx.closed() => (Ok(()), true),
+                result = generation => (result, false),
+            };
 
-            drop(tx);
+            let mut options = thread_options.lock().await;
+            if aborted {
+                options.stop_reason = Some(StopReason::Aborted);
+            }
+            let _ = tx.send(finish_chunk(&options));
 
-            Ok(())
+            result
         });
 
         let result = StreamTextResponse { stream, options };
@@ -225,6 +305,14 @@ impl<M: LanguageModel> LanguageModelRequest<M> {
     }
 }
 
+/// Builds the last chunk of the stream, summarizing the whole generation.
+fn finish_chunk(options: &LanguageModelOptions) -> LanguageModelStreamChunkType {
+    LanguageModelStreamChunkType::Finish {
+        total_usage: options.usage(),
+        stop_reason: options.stop_reason.clone().unwrap_or_default(),
+    }
+}
+
 // ============================================================================
 // Section: response types
 // ============================================================================
@@ -237,7 +325,7 @@ pub struct StreamTextResponse {
     /// The stream of response chunks from the language model.
     pub stream: LanguageModelStream,
     // The reason the model stopped generating text.
-    options: Arc<Mutex<LanguageModelOptions>>,
+    pub(crate) options: Arc<Mutex<LanguageModelOptions>>,
 }
 
 impl StreamTextResponse {
@@ -269,6 +357,18 @@ impl StreamTextResponse {
         self.options.lock().await.messages()
     }
 
+    /// Returns a serializable snapshot of the conversation.
+    ///
+    /// The snapshot keeps step ids and provider-specific data, so it can be stored
+    /// and passed to the `conversation` method of the request builder later on.
+    ///
+    /// # Returns
+    ///
+    /// The [`ConversationState`] of the conversation.
+    pub async fn conversation_state(&self) -> ConversationState {
+        self.options.lock().await.conversation_state()
+    }
+
     /// Returns the conversation step with the specified index.
     ///
     /// A step represents all messages exchanged during one cycle of model interaction,
@@ -377,4 +477,377 @@ impl StreamTextResponse {
     pub async fn stop_reason(&self) -> Option<StopReason> {
         self.options.lock().await.stop_reason()
     }
+
+    /// Returns the tool calls waiting for a [`ToolApproval`](crate::core::tools::ToolApproval).
+    ///
+    /// # Returns
+    ///
+    /// The pending tool calls, empty unless generation stopped with
+    /// [`StopReason::ToolApprovalRequired`].
+    pub async fn pending_tool_approvals(&self) -> Vec<ToolCallInfo> {
+        self.options.lock().await.pending_tool_approvals()
+    }
+
+    /// Returns the calls of deferred tools waiting for a result from the caller.
+    ///
+    /// # Returns
+    ///
+    /// The pending tool calls, empty unless generation stopped with
+    /// [`StopReason::DeferredToolCalls`].
+    pub async fn pending_tool_calls(&self) -> Vec<ToolCallInfo> {
+        self.options.lock().await.pending_tool_calls()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Error;
+    use crate::core::MockLanguageModel;
+    use crate::core::language_model::mock::fixtures::{
+        approval_tool, echo_call, echo_tool, pending_tool,
+    };
+    use crate::core::language_model::{LanguageModelStreamChunk, Usage};
+
+    #[tokio::test]
+    async fn test_stream_text_reports_tool_input_and_output() {
+        let model = MockLanguageModel::new()
+            .with_stream(vec![
+                LanguageModelStreamChunk::Delta(LanguageModelStreamChunkType::ToolInputStart {
+                    id: "call_1".to_string(),
+                    name: "echo".to_string(),
+                }),
+                LanguageModelStreamChunk::Delta(LanguageModelStreamChunkType::ToolInputDelta {
+                    id: "call_1".to_string(),
+                    delta: r#"{"value": 42}"#.to_string(),
+                }),
+                LanguageModelStreamChunk::Done(AssistantMessage::new(
+                    LanguageModelResponseContentType::ToolCall(echo_call()),
+                    None,
+                )),
+            ])
+            .with_text_stream(["done"]);
+
+        let response = LanguageModelRequest::builder()
+            .model(model)
+            .prompt("Call echo")
+            .with_tool(echo_tool())
+            .build()
+            .stream_text()
+            .await
+            .unwrap();
+
+        let chunks: Vec<_> = response.stream.collect().await;
+        let tool_chunks: Vec<String> = chunks
+            .iter()
+            .filter_map(|c| match c {
+                LanguageModelStreamChunkType::ToolInputStart { id, name } => {
+                    Some(format!("start {id} {name}"))
+                }
+                LanguageModelStreamChunkType::ToolInputDelta { id, delta } => {
+                    Some(format!("delta {id} {delta}"))
+                }
+                LanguageModelStreamChunkType::ToolInputAvailable(call) => {
+                    Some(format!("input {} {}", call.tool.id, call.input))
+                }
+                LanguageModelStreamChunkType::ToolOutputAvailable(result) => Some(format!(
+                    "output {} {}",
+                    result.tool.id,
+                    result.output.as_ref().unwrap().to_value()
+                )),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(
+            tool_chunks,
+            vec![
+                "start call_1 echo",
+                r#"delta call_1 {"value": 42}"#,
+                r#"input call_1 {"value":42}"#,
+                r#"output call_1 "{\"value\":42}""#,
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_stream_text_emits_step_events() {
+        let usage = Usage {
+            input_tokens: Some(10),
+            output_tokens: Some(5),
+            ..Default::default()
+        };
+        let model = MockLanguageModel::new()
+            .with_stream(vec![LanguageModelStreamChunk::Done(AssistantMessage::new(
+                LanguageModelResponseContentType::ToolCall(echo_call()),
+                Some(usage.clone()),
+            ))])
+            .with_stream(vec![LanguageModelStreamChunk::Done(AssistantMessage::new(
+                LanguageModelResponseContentType::Text("done".to_string()),
+                Some(usage.clone()),
+            ))]);
+
+        let response = LanguageModelRequest::builder()
+            .model(model)
+            .prompt("Call echo")
+            .with_tool(echo_tool())
+            .build()
+            .stream_text()
+            .await
+            .unwrap();
+
+        let events: Vec<_> = response
+            .stream
+            .filter(|c| {
+                futures::future::ready(matches!(
+                    c,
+                    LanguageModelStreamChunkType::StepStart { .. }
+                        | LanguageModelStreamChunkType::StepFinish { .. }
+                        | LanguageModelStreamChunkType::Finish { .. }
+                ))
+            })
+            .collect()
+            .await;
+
+        assert!(matches!(
+            events.as_slice(),
+            [
+                LanguageModelStreamChunkType::StepStart { step_id: 1 },
+                LanguageModelStreamChunkType::StepFinish {
+                    step_id: 1,
+                    finish_reason: FinishReason::ToolCalls,
+                    ..
+                },
+                LanguageModelStreamChunkType::StepStart { step_id: 2 },
+                LanguageModelStreamChunkType::StepFinish {
+                    step_id: 2,
+                    finish_reason: FinishReason::Stop,
+                    ..
+                },
+                LanguageModelStreamChunkType::Finish {
+                    stop_reason: StopReason::Finish,
+                    ..
+                },
+            ]
+        ));
+        let LanguageModelStreamChunkType::StepFinish {
+            usage: step_usage, ..
+        } = &events[1]
+        else {
+            unreachable!()
+        };
+        assert_eq!(step_usage, &usage);
+        let LanguageModelStreamChunkType::Finish { total_usage, .. } = &events[4] else {
+            unreachable!()
+        };
+        assert_eq!(total_usage.input_tokens, Some(20));
+    }
+
+    #[tokio::test]
+    async fn test_stream_text_counts_parallel_tool_call_usage_once() {
+        let usage = Usage {
+            input_tokens: Some(10),
+            output_tokens: Some(5),
+            ..Default::default()
+        };
+        let mut second_call = echo_call();
+        second_call.id("call_2");
+        // Providers report the usage of a turn on its last message only.
+        let model = MockLanguageModel::new()
+            .with_stream(vec![
+                LanguageModelStreamChunk::Done(AssistantMessage::new(
+                    LanguageModelResponseContentType::ToolCall(echo_call()),
+                    None,
+                )),
+                LanguageModelStreamChunk::Done(AssistantMessage::new(
+                    LanguageModelResponseContentType::ToolCall(second_call),
+                    Some(usage.clone()),
+                )),
+            ])
+            .with_stream(vec![LanguageModelStreamChunk::Done(AssistantMessage::new(
+                LanguageModelResponseContentType::Text("done".to_string()),
+                Some(usage.clone()),
+            ))]);
+
+        let response = LanguageModelRequest::builder()
+            .model(model)
+            .prompt("Call echo twice")
+            .with_tool(echo_tool())
+            .build()
+            .stream_text()
+            .await
+            .unwrap();
+
+        let usages: Vec<_> = response
+            .stream
+            .filter_map(|c| {
+                futures::future::ready(match c {
+                    LanguageModelStreamChunkType::StepFinish { usage, .. } => Some(usage),
+                    LanguageModelStreamChunkType::Finish { total_usage, .. } => Some(total_usage),
+                    _ => None,
+                })
+            })
+            .collect()
+            .await;
+
+        assert_eq!(
+            usages,
+            vec![
+                usage.clone(),
+                usage.clone(),
+                Usage {
+                    input_tokens: Some(20),
+                    output_tokens: Some(10),
+                    ..Default::default()
+                },
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_stream_text_finishes_after_model_error() {
+        let model = MockLanguageModel::new().with_stream_error(Error::Other("down".into()));
+
+        let response = LanguageModelRequest::builder()
+            .model(model)
+            .prompt("Hello")
+            .build()
+            .stream_text()
+            .await
+            .unwrap();
+
+        let chunks: Vec<_> = response.stream.collect().await;
+        assert!(matches!(
+            chunks.last(),
+            Some(LanguageModelStreamChunkType::Finish {
+                stop_reason: StopReason::Error(_),
+                ..
+            })
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_stream_text_aborts_when_stream_is_dropped() {
+        let model = MockLanguageModel::new()
+            .with_stream(vec![LanguageModelStreamChunk::Done(AssistantMessage::new(
+                LanguageModelResponseContentType::ToolCall(echo_call()),
+                None,
+            ))])
+            .with_text_stream(["never sent"]);
+
+        let response = LanguageModelRequest::builder()
+            .model(model.clone())
+            .prompt("Call echo")
+            .with_tool(pending_tool())
+            .build()
+            .stream_text()
+            .await
+            .unwrap();
+        let options = response.options.clone();
+        let mut stream = response.stream;
+        while let Some(chunk) = stream.next().await {
+            if let LanguageModelStreamChunkType::ToolInputAvailable(_) = chunk {
+                break;
+            }
+        }
+        drop(stream);
+
+        tokio::time::timeout(std::time::Duration::from_secs(1), async {
+            while options.lock().await.stop_reason.is_none() {
+                tokio::time::sleep(std::time::Duration::from_millis(5)).await;
+            }
+        })
+        .await
+        .unwrap();
+        assert_eq!(options.lock().await.stop_reason, Some(StopReason::Aborted));
+        assert_eq!(model.calls().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_stream_text_requests_tool_approval() {
+        let model = MockLanguageModel::new()
+            .with_stream(vec![LanguageModelStreamChunk::Done(AssistantMessage::new(
+                LanguageModelResponseContentType::ToolCall(echo_call()),
+                None,
+            ))])
+            .with_text_stream(["never sent"]);
+
+        let mut response = LanguageModelRequest::builder()
+            .model(model.clone())
+            .prompt("Call echo")
+            .with_tool(approval_tool())
+            .build()
+            .stream_text()
+            .await
+            .unwrap();
+        let chunks: Vec<_> = response.stream.by_ref().collect().await;
+
+        assert!(chunks.iter().any(|c| matches!(
+            c,
+            LanguageModelStreamChunkType::ToolApprovalRequest(call) if call.tool.id == "call_1"
+        )));
+        assert!(
+            !chunks
+                .iter()
+                .any(|c| matches!(c, LanguageModelStreamChunkType::ToolOutputAvailable(_)))
+        );
+        assert!(matches!(
+            chunks.last(),
+            Some(LanguageModelStreamChunkType::Finish {
+                stop_reason: StopReason::ToolApprovalRequired,
+                ..
+            })
+        ));
+        assert_eq!(response.pending_tool_approvals().await, vec![echo_call()]);
+        assert_eq!(model.calls().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_stream_text_finishes_step_when_provider_stream_ends() {
+        // The text and the tool call of the first step arrive in separate events
+        let model = MockLanguageModel::new()
+            .with_stream(vec![
+                LanguageModelStreamChunk::Done(AssistantMessage::new(
+                    LanguageModelResponseContentType::Text("Let me check.".to_string()),
+                    None,
+                )),
+                LanguageModelStreamChunk::Done(AssistantMessage::new(
+                    LanguageModelResponseContentType::ToolCall(echo_call()),
+                    None,
+                )),
+            ])
+            .with_text_stream(["done"]);
+
+        let mut response = LanguageModelRequest::builder()
+            .model(model.clone())
+            .prompt("Call echo")
+            .with_tool(echo_tool())
+            .build()
+            .stream_text()
+            .await
+            .unwrap();
+        let chunks: Vec<_> = response.stream.by_ref().collect().await;
+
+        let step_finishes: Vec<_> = chunks
+            .iter()
+            .filter_map(|c| match c {
+                LanguageModelStreamChunkType::StepFinish {
+                    step_id,
+                    finish_reason,
+                    ..
+                } => Some((*step_id, finish_reason.clone())),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(
+            step_finishes,
+            vec![(1, FinishReason::ToolCalls), (2, FinishReason::Stop)]
+        );
+        assert!(
+            chunks
+                .iter()
+                .any(|c| matches!(c, LanguageModelStreamChunkType::ToolOutputAvailable(_)))
+        );
+        assert_eq!(response.stop_reason().await, Some(StopReason::Finish));
+        assert_eq!(model.calls().len(), 2);
+    }
 }
@@ -4,9 +4,12 @@ use crate::core::{
     language_model::{LanguageModelResponseContentType, Usage},
     tools::{ToolCallInfo, ToolResultInfo},
 };
+use crate::error::{Error, Result};
+use serde::{Deserialize, Serialize};
 
 /// The role of a participant in a conversation.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
 pub enum Role {
     /// System-level instructions or context.
     System,
@@ -17,7 +20,8 @@ pub enum Role {
 }
 
 /// A message in a conversation with a language model.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
 pub enum Message {
     /// A system message providing context or instructions.
     System(SystemMessage),
@@ -77,7 +81,7 @@ impl Message {
 }
 
 /// A system message that provides context or instructions to the model.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SystemMessage {
     /// The text content of the system message.
     pub content: String,
@@ -105,10 +109,26 @@ impl From<&str> for SystemMessage {
 }
 
 /// A user message containing input from the human participant.
-#[derive(Debug, Clone)]
+///
+/// Besides the text `content`, a user message can carry additional content parts
+/// such as images, audio clips, videos or documents.
+///
+/// # Example
+/// ```
+/// use aisdk::core::{DataContent, UserMessage};
+///
+/// let msg = UserMessage::new("What is in this picture?")
+///     .with_image(DataContent::url("https://example.com/cat.png"), "image/png");
+///
+/// assert_eq!(msg.parts.len(), 1);
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct UserMessage {
     /// The text content of the user message.
     pub content: String,
+    /// Additional content parts sent after the text content.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub parts: Vec<UserContentPart>,
 }
 
 impl UserMessage {
@@ -116,8 +136,92 @@ impl UserMessage {
     pub fn new(content: impl Into<String>) -> Self {
         Self {
             content: content.into(),
+            parts: Vec::new(),
         }
     }
+
+    /// Appends a content part to the message.
+    ///
+    /// # Parameters
+    ///
+    /// * `part` - The content part to append.
+    ///
+    /// # Returns
+    ///
+    /// The message with the part added.
+    pub fn with_part(mut self, part: UserContentPart) -> Self {
+        self.parts.push(part);
+        self
+    }
+
+    /// Appends an image to the message.
+    ///
+    /// # Parameters
+    ///
+    /// * `data` - The image data or URL.
+    /// * `media_type` - The IANA media type of the image, e.g. `image/png`.
+    ///
+    /// # Returns
+    ///
+    /// The message with the image added.
+    pub fn with_image(self, data: impl Into<DataContent>, media_type: impl Into<String>) -> Self {
+        self.with_part(UserContentPart::image(data, media_type))
+    }
+
+    /// Appends an audio clip to the message.
+    ///
+    /// # Parameters
+    ///
+    /// * `data` - The audio data or URL.
+    /// * `media_type` - The IANA media type of the audio, e.g. `audio/wav`.
+    ///
+    /// # Returns
+    ///
+    /// The message with the audio added.
+    pub fn with_audio(self, data: impl Into<DataContent>, media_type: impl Into<String>) -> Self {
+        self.with_part(UserContentPart::audio(data, media_type))
+    }
+
+    /// Appends a video to the message.
+    ///
+    /// # Parameters
+    ///
+    /// * `data` - The video data or URL.
+    /// * `media_type` - The IANA media type of the video, e.g. `video/mp4`.
+    ///
+    /// # Returns
+    ///
+    /// The message with the video added.
+    pub fn with_video(self, data: impl Into<DataContent>, media_type: impl Into<String>) -> Self {
+        self.with_part(UserContentPart::video(data, media_type))
+    }
+
+    /// Appends a file (for example a PDF document) to the message.
+    ///
+    /// # Parameters
+    ///
+    /// * `data` - The file data or URL.
+    /// * `media_type` - The IANA media type of the file, e.g. `application/pdf`.
+    ///
+    /// # Returns
+    ///
+    /// The message with the file added.
+    pub fn with_file(self, data: impl Into<DataContent>, media_type: impl Into<String>) -> Self {
+        self.with_part(UserContentPart::file(data, media_type))
+    }
+
+    /// Returns all content of the message as an ordered list of parts.
+    ///
+    /// The text content comes first, followed by the additional parts. Empty text is
+    /// only kept when there are no other parts.
+    pub fn into_parts(self) -> Vec<UserContentPart> {
+        let mut parts = Vec::with_capacity(self.parts.len() + 1);
+        if !self.content.is_empty() || self.parts.is_empty() {
+            parts.push(UserContentPart::Text(self.content));
+        }
+        parts.extend(self.parts);
+        parts
+    }
 }
 
 impl From<String> for UserMessage {
@@ -132,12 +236,209 @@ impl From<&str> for UserMessage {
     }
 }
 
+/// A single piece of content in a [`UserMessage`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum UserContentPart {
+    /// Plain text content.
+    Text(String),
+    /// An image input.
+    Image(MediaPart),
+    /// An audio input.
+    Audio(MediaPart),
+    /// A video input.
+    Video(MediaPart),
+    /// A file input such as a PDF document.
+    File(MediaPart),
+}
+
+impl UserContentPart {
+    /// Creates an image part.
+    pub fn image(data: impl Into<DataContent>, media_type: impl Into<String>) -> Self {
+        Self::Image(MediaPart::new(data, media_type))
+    }
+
+    /// Creates an audio part.
+    pub fn audio(data: impl Into<DataContent>, media_type: impl Into<String>) -> Self {
+        Self::Audio(MediaPart::new(data, media_type))
+    }
+
+    /// Creates a video part.
+    pub fn video(data: impl Into<DataContent>, media_type: impl Into<String>) -> Self {
+        Self::Video(MediaPart::new(data, media_type))
+    }
+
+    /// Creates a file part.
+    pub fn file(data: impl Into<DataContent>, media_type: impl Into<String>) -> Self {
+        Self::File(MediaPart::new(data, media_type))
+    }
+}
+
+impl From<String> for UserContentPart {
+    fn from(value: String) -> Self {
+        Self::Text(value)
+    }
+}
+
+impl From<&str> for UserContentPart {
+    fn from(value: &str) -> Self {
+        Self::Text(value.to_string())
+    }
+}
+
+/// Binary content attached to a user message, together with its media type.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MediaPart {
+    /// The data or location of the content.
+    pub data: DataContent,
+    /// The IANA media type of the content, e.g. `image/png` or `application/pdf`.
+    pub media_type: String,
+    /// An optional file name, used by providers that require one for documents.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub filename: Option<String>,
+}
+
+impl MediaPart {
+    /// Creates a new media part without a file name.
+    pub fn new(data: impl Into<DataContent>, media_type: impl Into<String>) -> Self {
+        Self {
+            data: data.into(),
+            media_type: media_type.into(),
+            filename: None,
+        }
+    }
+
+    /// Sets the file name of the media part.
+    pub fn with_filename(mut self, filename: impl Into<String>) -> Self {
+        self.filename = Some(filename.into());
+        self
+    }
+
+    /// Returns the content as a URL, encoding inline data as a `data:` URL.
+    pub fn to_url(&self) -> String {
+        match &self.data {
+            DataContent::Url(url) => url.clone(),
+            _ => format!(
+                "data:{};base64,{}",
+                self.media_type,
+                self.data.to_base64().unwrap_or_default()
+            ),
+        }
+    }
+}
+
+/// The source of binary content such as an image, an audio clip or a document.
+///
+/// Raw bytes are serialized as base64 and deserialize as [`DataContent::Base64`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(into = "SerializedDataContent", from = "SerializedDataContent")]
+pub enum DataContent {
+    /// A URL pointing to the content. Depending on the provider this can be an
+    /// `https` URL, a `data:` URL or a provider file URI.
+    Url(String),
+    /// Base64 encoded content.
+    Base64(String),
+    /// Raw bytes, base64 encoded when sent to the provider.
+    Bytes(Vec<u8>),
+}
+
+impl DataContent {
+    /// Creates a URL data source.
+    pub fn url(url: impl Into<String>) -> Self {
+        Self::Url(url.into())
+    }
+
+    /// Creates a data source from base64 encoded content.
+    pub fn base64(data: impl Into<String>) -> Self {
+        Self::Base64(data.into())
+    }
+
+    /// Creates a data source from raw bytes.
+    pub fn bytes(data: impl Into<Vec<u8>>) -> Self {
+        Self::Bytes(data.into())
+    }
+
+    /// Returns the content as a base64 string.
+    ///
+    /// Base64 `data:` URLs are decoded to their content; other URLs return `None`.
+    pub fn to_base64(&self) -> Option<String> {
+        use base64::Engine;
+
+        match self {
+            Self::Url(url) => data_url_content(url).map(str::to_string),
+            Self::Base64(data) => Some(data.clone()),
+            Self::Bytes(bytes) => Some(base64::engine::general_purpose::STANDARD.encode(bytes)),
+        }
+    }
+
+    /// Returns the content as raw bytes.
+    ///
+    /// Base64 `data:` URLs are decoded to their content; other URLs and invalid base64
+    /// return `None`.
+    pub fn to_bytes(&self) -> Option<Vec<u8>> {
+        use base64::Engine;
+
+        match self {
+            Self::Url(_) | Self::Base64(_) => self
+                .to_base64()
+                .and_then(|data| base64::engine::general_purpose::STANDARD.decode(data).ok()),
+            Self::Bytes(bytes) => Some(bytes.clone()),
+        }
+    }
+}
+
+/// Returns the base64 content of a `data:<media type>;base64,<content>` URL.
+fn data_url_content(url: &str) -> Option<&str> {
+    let (header, content) = url.strip_prefix("data:")?.split_once(',')?;
+    header.ends_with(";base64").then_some(content)
+}
+
+impl From<Vec<u8>> for DataContent {
+    fn from(value: Vec<u8>) -> Self {
+        Self::Bytes(value)
+    }
+}
+
+impl From<&[u8]> for DataContent {
+    fn from(value: &[u8]) -> Self {
+        Self::Bytes(value.to_vec())
+    }
+}
+
+/// The serialized form of [`DataContent`].
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+enum SerializedDataContent {
+    Url(String),
+    Base64(String),
+}
+
+impl From<DataContent> for SerializedDataContent {
+    fn from(value: DataContent) -> Self {
+        match value {
+            DataContent::Url(url) => Self::Url(url),
+            other => Self::Base64(other.to_base64().unwrap_or_default()),
+        }
+    }
+}
+
+impl From<SerializedDataContent> for DataContent {
+    fn from(value: SerializedDataContent) -> Self {
+        match value {
+            SerializedDataContent::Url(url) => Self::Url(url),
+            SerializedDataContent::Base64(data) => Self::Base64(data),
+        }
+    }
+}
+
 /// A message generated by the language model assistant.
-#[derive(Default, Debug, Clone)]
+#[derive(Default, Debug, Clone, Serialize, Deserialize)]
 pub struct AssistantMessage {
     /// The content of the assistant's response.
     pub content: LanguageModelResponseContentType,
-    /// Optional usage statistics for the response.
+    /// Optional usage statistics for the response. A model turn that produces several
+    /// messages reports its usage on the last one only.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub usage: Option<Usage>,
 }
 
@@ -229,13 +530,13 @@ impl MessageBuilder<Initial> {
     ///
     /// # Parameters
     ///
-    /// * `content` - The user message content.
+    /// * `content` - The user message content, either text or a [`UserMessage`].
     ///
     /// # Returns
     ///
     /// The builder in the conversation state.
-    pub fn user(mut self, content: impl Into<String>) -> MessageBuilder<Conversation> {
-        self.messages.push(Message::User(content.into().into()));
+    pub fn user(mut self, content: impl Into<UserMessage>) -> MessageBuilder<Conversation> {
+        self.messages.push(Message::User(content.into()));
         MessageBuilder {
             messages: self.messages,
             state: std::marker::PhantomData,
@@ -248,13 +549,13 @@ impl MessageBuilder<Conversation> {
     ///
     /// # Parameters
     ///
-    /// * `content` - The user message content.
+    /// * `content` - The user message content, either text or a [`UserMessage`].
     ///
     /// # Returns
     ///
     /// The builder with the message added.
-    pub fn user(mut self, content: impl Into<String>) -> MessageBuilder<Conversation> {
-        self.messages.push(Message::User(content.into().into()));
+    pub fn user(mut self, content: impl Into<UserMessage>) -> MessageBuilder<Conversation> {
+        self.messages.push(Message::User(content.into()));
         MessageBuilder {
             messages: self.messages,
             state: std::marker::PhantomData,
@@ -282,7 +583,7 @@ impl MessageBuilder<Conversation> {
 
 /// A message tagged with its step id in a list of messages
 /// used for tracking steps in a conversation
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub(crate) struct TaggedMessage {
     pub step_id: usize,
     pub message: Message,
@@ -315,6 +616,123 @@ impl From<TaggedMessage> for Message {
     }
 }
 
+/// The current version of the [`ConversationState`] format.
+pub const CONVERSATION_STATE_VERSION: u32 = 1;
+
+/// A serializable snapshot of a conversation, including the step each message belongs to.
+///
+/// Use it to persist a conversation between requests and resume it later with
+/// the `conversation` method of the request builder. Provider-specific data that
+/// must be sent back on the next turn, such as thinking signatures, is kept.
+///
+/// # Example
+/// ```
+/// use aisdk::core::{ConversationState, Message};
+///
+/// let messages = Message::builder()
+///     .system("You are a helpful assistant.")
+///     .user("Hello!")
+///     .build();
+///
+/// let json = ConversationState::new(messages).to_json().unwrap();
+/// let state = ConversationState::from_json(&json).unwrap();
+///
+/// assert_eq!(state.messages().len(), 2);
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConversationState {
+    /// The version of the state format.
+    pub version: u32,
+    messages: Vec<TaggedMessage>,
+}
+
+impl ConversationState {
+    /// Creates a new conversation state from untagged messages.
+    ///
+    /// All messages are assigned to the initial step.
+    pub fn new(messages: Messages) -> Self {
+        Self::from_tagged(messages.into_iter().map(TaggedMessage::from).collect())
+    }
+
+    pub(crate) fn from_tagged(messages: Vec<TaggedMessage>) -> Self {
+        Self {
+            version: CONVERSATION_STATE_VERSION,
+            messages,
+        }
+    }
+
+    pub(crate) fn into_tagged(self) -> Vec<TaggedMessage> {
+        self.messages
+    }
+
+    /// Appends a message to the conversation, e.g. the next user input.
+    ///
+    /// Tool results join the last step, which holds the tool calls they answer. Other
+    /// messages after a model turn start a new step, so the next input is not counted
+    /// as part of the previous answer; further input joins that step.
+    pub fn push(&mut self, message: impl Into<Message>) {
+        let message = message.into();
+        let last_step_id = self.last_step_id();
+        let after_model_turn = matches!(
+            self.messages.last().map(|m| &m.message),
+            Some(Message::Assistant(_) | Message::Tool(_))
+        );
+        let step_id = if after_model_turn && !matches!(message, Message::Tool(_)) {
+            last_step_id + 1
+        } else {
+            last_step_id
+        };
+        self.messages.push(TaggedMessage::new(step_id, message));
+    }
+
+    /// Returns the messages of the conversation.
+    pub fn messages(&self) -> Messages {
+        self.messages.iter().map(|m| m.message.clone()).collect()
+    }
+
+    /// Returns the id of the last step in the conversation.
+    pub fn last_step_id(&self) -> usize {
+        self.messages.iter().map(|m| m.step_id).max().unwrap_or(0)
+    }
+
+    /// Serializes the conversation state to a JSON string.
+    ///
+    /// # Returns
+    ///
+    /// The JSON string or an error if serialization fails.
+    pub fn to_json(&self) -> Result<String> {
+        serde_json::to_string(self).map_err(|e| Error::Other(e.to_string()))
+    }
+
+    /// Restores a conversation state from a JSON string.
+    ///
+    /// # Parameters
+    ///
+    /// * `json` - A string produced by [`ConversationState::to_json`].
+    ///
+    /// # Returns
+    ///
+    /// The conversation state, or [`Error::InvalidInput`] if the JSON is malformed
+    /// or was written by an unsupported version.
+    pub fn from_json(json: &str) -> Result<Self> {
+        let state: Self =
+            serde_json::from_str(json).map_err(|e| Error::InvalidInput(e.to_string()))?;
+        if state.version != CONVERSATION_STATE_VERSION {
+            return Err(Error::InvalidInput(format!(
+                "Unsupported conversation state version {} (expected {})",
+                state.version, CONVERSATION_STATE_VERSION
+            )));
+        }
+        Ok(state)
+    }
+}
+
+impl From<Messages> for ConversationState {
+    fn from(value: Messages) -> Self {
+        Self::new(value)
+    }
+}
+
 /// Helper trait for extracting messages from TaggedMessage collections
 pub(crate) trait TaggedMessageHelpers {
     fn extract_tool_calls(&self) -> Option<Vec<ToolCallInfo>>;
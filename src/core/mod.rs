@@ -10,18 +10,27 @@
 pub mod capabilities;
 pub mod client;
 pub mod embedding_model;
+pub mod image_model;
 pub mod language_model;
 pub mod messages;
+pub mod moderation_model;
+pub mod partial_json;
 pub mod provider;
+pub mod reranking_model;
+pub mod speech_model;
 pub mod tools;
+pub mod transcription_model;
 pub mod utils;
 
 // Re-export key components to provide a clean public API.
 pub use capabilities::DynamicModel;
-pub use language_model::{LanguageModel, LanguageModelStreamChunkType};
+#[cfg(any(test, feature = "test-utils"))]
+pub use language_model::MockLanguageModel;
+pub use language_model::{AnyLanguageModel, LanguageModel, LanguageModelStreamChunkType};
 #[cfg(feature = "language-model-request")]
 pub use language_model::{
-    generate_text::GenerateTextResponse, request::LanguageModelRequest,
+    generate_object::GenerateObjectResponse, generate_text::GenerateTextResponse,
+    request::LanguageModelRequest, stream_object::StreamObjectResponse,
     stream_text::StreamTextResponse,
 };
 
@@ -29,6 +38,29 @@ pub use embedding_model::EmbeddingModel;
 #[cfg(feature = "embedding-model-request")]
 pub use embedding_model::EmbeddingModelRequest;
 
-pub use messages::{AssistantMessage, Message, Messages, Role, SystemMessage, UserMessage};
+pub use image_model::ImageModel;
+#[cfg(feature = "image-model-request")]
+pub use image_model::ImageModelRequest;
+
+pub use moderation_model::ModerationModel;
+#[cfg(feature = "moderation-model-request")]
+pub use moderation_model::ModerationModelRequest;
+
+pub use reranking_model::RerankingModel;
+#[cfg(feature = "reranking-model-request")]
+pub use reranking_model::RerankingModelRequest;
+
+pub use speech_model::SpeechModel;
+#[cfg(feature = "speech-model-request")]
+pub use speech_model::SpeechModelRequest;
+
+pub use transcription_model::TranscriptionModel;
+#[cfg(feature = "transcription-model-request")]
+pub use transcription_model::TranscriptionModelRequest;
+
+pub use messages::{
+    AssistantMessage, ConversationState, DataContent, MediaPart, Message, Messages, Role,
+    SystemMessage, UserContentPart, UserMessage,
+};
 pub use provider::Provider;
 pub use tools::{Tool, ToolCallInfo, ToolResultInfo};
@@ -0,0 +1,453 @@
+//! Moderation model
+//!
+//! Defines the [`ModerationModel`] trait implemented by providers that classify text and
+//! images as potentially harmful, together with its options and response types, and
+//! [`moderation_guardrail`] to moderate the user input of a text generation.
+
+/// Moderation request builder and related types.
+#[cfg(feature = "moderation-model-request")]
+pub mod request;
+
+use crate::core::language_model::{InputGuardrailFuture, LanguageModelOptions};
+use crate::core::messages::{MediaPart, Message, UserContentPart};
+use crate::error::{Error, Result};
+use async_trait::async_trait;
+use derive_builder::Builder;
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "moderation-model-request")]
+pub use request::ModerationModelRequest;
+
+/// A single input to classify.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ModerationInput {
+    /// A text to classify.
+    Text(String),
+    /// An image to classify.
+    Image(MediaPart),
+}
+
+impl From<String> for ModerationInput {
+    fn from(value: String) -> Self {
+        Self::Text(value)
+    }
+}
+
+impl From<&str> for ModerationInput {
+    fn from(value: &str) -> Self {
+        Self::Text(value.to_string())
+    }
+}
+
+/// The options for moderation requests.
+#[derive(Debug, Clone, Default, Builder)]
+#[builder(setter(into), default, build_fn(error = "Error"))]
+pub struct ModerationModelOptions {
+    /// The inputs to classify.
+    pub inputs: Vec<ModerationInput>,
+}
+
+impl ModerationModelOptions {
+    /// Returns the moderation options builder.
+    pub fn builder() -> ModerationModelOptionsBuilder {
+        ModerationModelOptionsBuilder::default()
+    }
+
+    /// Returns `true` if any input is an image.
+    pub fn has_images(&self) -> bool {
+        self.inputs
+            .iter()
+            .any(|input| matches!(input, ModerationInput::Image(_)))
+    }
+}
+
+/// The core trait abstracting the capabilities of a moderation model.
+#[async_trait]
+pub trait ModerationModel: Clone + Send + Sync + std::fmt::Debug + 'static {
+    /// Classifies the inputs in the options.
+    async fn moderate(&self, options: ModerationModelOptions) -> Result<ModerationModelResponse>;
+}
+
+/// The response type for moderation requests.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ModerationModelResponse {
+    /// The classification results. Depending on the provider there is one result per
+    /// input or a single result for all inputs.
+    pub results: Vec<ModerationResult>,
+}
+
+impl ModerationModelResponse {
+    /// Returns `true` if any result is flagged.
+    pub fn flagged(&self) -> bool {
+        self.results.iter().any(|result| result.flagged)
+    }
+
+    /// Returns the names of the categories flagged in any result, without duplicates.
+    pub fn flagged_categories(&self) -> Vec<String> {
+        let mut categories: Vec<String> = self
+            .results
+            .iter()
+            .flat_map(|result| result.flagged_categories())
+            .map(str::to_string)
+            .collect();
+        categories.sort();
+        categories.dedup();
+        categories
+    }
+}
+
+/// The classification of one or more inputs.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ModerationResult {
+    /// Whether the input is classified as potentially harmful.
+    pub flagged: bool,
+    /// The per-category flags and scores, sorted by category name.
+    pub categories: Vec<ModerationCategory>,
+}
+
+impl ModerationResult {
+    /// Returns the names of the flagged categories.
+    pub fn flagged_categories(&self) -> impl Iterator<Item = &str> {
+        self.categories
+            .iter()
+            .filter(|category| category.flagged)
+            .map(|category| category.name.as_str())
+    }
+
+    /// Returns the category with the given name.
+    pub fn category(&self, name: &str) -> Option<&ModerationCategory> {
+        self.categories
+            .iter()
+            .find(|category| category.name == name)
+    }
+}
+
+/// The classification of an input in a single category.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ModerationCategory {
+    /// The provider's name of the category, e.g. `harassment` or `violence/graphic`.
+    pub name: String,
+    /// Whether the input is flagged in this category.
+    pub flagged: bool,
+    /// The model's confidence that the input belongs to this category, between 0 and 1.
+    pub score: f64,
+}
+
+/// Creates an input guardrail moderating the user input of a text generation.
+///
+/// The guardrail sends the text and images of the new user input, i.e. the user messages
+/// after the last assistant or tool message, to the moderation model before the first
+/// step. Earlier turns were checked when they were sent. Generation stops with
+/// [`StopReason::Guardrail`](crate::core::language_model::StopReason::Guardrail) when the
+/// input is flagged, and fails when the moderation request fails, so no input reaches the
+/// language model unmoderated. Audio, video and file parts are not moderated.
+///
+/// # Parameters
+///
+/// * `model` - The moderation model to use.
+///
+/// # Returns
+///
+/// A hook for `LanguageModelRequestBuilder::input_guardrail`.
+pub fn moderation_guardrail<M: ModerationModel>(
+    model: M,
+) -> impl Fn(&LanguageModelOptions) -> InputGuardrailFuture + Send + Sync + 'static {
+    move |options| {
+        let model = model.clone();
+        let inputs = user_inputs(options);
+        Box::pin(async move {
+            if inputs.is_empty() {
+                return Ok(None);
+            }
+
+            let response = model.moderate(ModerationModelOptions { inputs }).await?;
+            Ok(response.flagged().then(|| {
+                format!(
+                    "Input flagged by moderation: {}",
+                    response.flagged_categories().join(", ")
+                )
+            }))
+        })
+    }
+}
+
+/// Collects the text and images of the user messages sent since the last model turn.
+fn user_inputs(options: &LanguageModelOptions) -> Vec<ModerationInput> {
+    let messages = options.messages();
+    let new_input = messages
+        .iter()
+        .rposition(|message| matches!(message, Message::Assistant(_) | Message::Tool(_)))
+        .map_or(0, |last_turn| last_turn + 1);
+
+    messages
+        .into_iter()
+        .skip(new_input)
+        .filter_map(|message| match message {
+            Message::User(user) => Some(user.into_parts()),
+            _ => None,
+        })
+        .flatten()
+        .filter_map(|part| match part {
+            UserContentPart::Text(text) if !text.is_empty() => Some(ModerationInput::Text(text)),
+            UserContentPart::Image(image) => Some(ModerationInput::Image(image)),
+            _ => None,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn category(name: &str, flagged: bool, score: f64) -> ModerationCategory {
+        ModerationCategory {
+            name: name.to_string(),
+            flagged,
+            score,
+        }
+    }
+
+    #[test]
+    fn test_response_flagged_categories() {
+        let response = ModerationModelResponse {
+            results: vec![
+                ModerationResult {
+                    flagged: true,
+                    categories: vec![
+                        category("harassment", true, 0.8),
+                        category("violence", true, 0.7),
+                    ],
+                },
+                ModerationResult {
+                    flagged: false,
+                    categories: vec![category("violence", false, 0.1)],
+                },
+                ModerationResult {
+                    flagged: true,
+                    categories: vec![category("hate", true, 0.9), category("violence", true, 0.6)],
+                },
+            ],
+        };
+
+        assert!(response.flagged());
+        assert_eq!(
+            response.flagged_categories(),
+            vec!["harassment", "hate", "violence"]
+        );
+        assert_eq!(response.results[1].category("violence").unwrap().score, 0.1);
+        assert!(!ModerationModelResponse::default().flagged());
+    }
+
+    #[cfg(feature = "language-model-request")]
+    mod guardrail {
+        use super::*;
+        use crate::core::language_model::{LanguageModelStreamChunkType, StopReason};
+        use crate::core::messages::{DataContent, UserMessage};
+        use crate::core::{LanguageModelRequest, Messages, MockLanguageModel};
+        use futures::StreamExt;
+        use std::sync::{Arc, Mutex};
+
+        /// A moderation model flagging inputs containing "attack" and recording its calls.
+        #[derive(Debug, Clone, Default)]
+        struct KeywordModeration {
+            calls: Arc<Mutex<Vec<ModerationModelOptions>>>,
+            fail: bool,
+        }
+
+        #[async_trait]
+        impl ModerationModel for KeywordModeration {
+            async fn moderate(
+                &self,
+                options: ModerationModelOptions,
+            ) -> Result<ModerationModelResponse> {
+                self.calls.lock().unwrap().push(options.clone());
+                if self.fail {
+                    return Err(Error::ApiError {
+                        status_code: Some(reqwest::StatusCode::SERVICE_UNAVAILABLE),
+                        details: "unavailable".to_string(),
+                    });
+                }
+
+                let results = options
+                    .inputs
+                    .iter()
+                    .map(|input| {
+                        let flagged =
+                            matches!(input, ModerationInput::Text(text) if text.contains("attack"));
+                        ModerationResult {
+                            flagged,
+                            categories: vec![category("violence", flagged, 0.9)],
+                        }
+                    })
+                    .collect();
+                Ok(ModerationModelResponse { results })
+            }
+        }
+
+        #[tokio::test]
+        async fn test_generate_text_stops_on_flagged_input() {
+            let model = MockLanguageModel::new().with_text("never sent");
+            let moderation = KeywordModeration::default();
+
+            let result = LanguageModelRequest::builder()
+                .model(model.clone())
+                .prompt("Plan an attack")
+                .input_guardrail(moderation_guardrail(moderation.clone()))
+                .build()
+                .generate_text()
+                .await
+                .unwrap();
+
+            assert_eq!(
+                result.stop_reason(),
+                Some(StopReason::Guardrail(
+                    "Input flagged by moderation: violence".to_string()
+                ))
+            );
+            assert!(model.calls().is_empty());
+            assert_eq!(
+                moderation.calls.lock().unwrap()[0].inputs,
+                vec![ModerationInput::Text("Plan an attack".to_string())]
+            );
+        }
+
+        #[tokio::test]
+        async fn test_generate_text_moderates_user_messages() {
+            let model = MockLanguageModel::new().with_text("A cat");
+            let moderation = KeywordModeration::default();
+            let image = DataContent::url("https://example.com/cat.png");
+
+            let messages: Messages = vec![
+                Message::System("Be brief".into()),
+                Message::User(
+                    UserMessage::new("What is this?").with_image(image.clone(), "image/png"),
+                ),
+            ];
+            let result = LanguageModelRequest::builder()
+                .model(model.clone())
+                .messages(messages)
+                .input_guardrail(moderation_guardrail(moderation.clone()))
+                .build()
+                .generate_text()
+                .await
+                .unwrap();
+
+            assert_eq!(result.stop_reason(), Some(StopReason::Finish));
+            assert_eq!(model.calls().len(), 1);
+            assert_eq!(
+                moderation.calls.lock().unwrap()[0].inputs,
+                vec![
+                    ModerationInput::Text("What is this?".to_string()),
+                    ModerationInput::Image(MediaPart::new(image, "image/png")),
+                ]
+            );
+        }
+
+        #[tokio::test]
+        async fn test_generate_text_fails_when_moderation_fails() {
+            let model = MockLanguageModel::new().with_text("never sent");
+            let moderation = KeywordModeration {
+                fail: true,
+                ..Default::default()
+            };
+
+            let result = LanguageModelRequest::builder()
+                .model(model.clone())
+                .prompt("Hello")
+                .input_guardrail(moderation_guardrail(moderation))
+                .build()
+                .generate_text()
+                .await;
+
+            assert!(matches!(result, Err(Error::ApiError { .. })));
+            assert!(model.calls().is_empty());
+        }
+
+        #[tokio::test]
+        async fn test_stream_text_stops_on_flagged_input() {
+            let model = MockLanguageModel::new().with_text_stream(["never sent"]);
+
+            let response = LanguageModelRequest::builder()
+                .model(model.clone())
+                .prompt("Plan an attack")
+                .input_guardrail(moderation_guardrail(KeywordModeration::default()))
+                .build()
+                .stream_text()
+                .await
+                .unwrap();
+            let options = response.options.clone();
+            let chunks: Vec<_> = response.stream.collect().await;
+
+            assert!(matches!(
+                chunks.as_slice(),
+                [
+                    LanguageModelStreamChunkType::Start,
+                    LanguageModelStreamChunkType::Finish {
+                        stop_reason: StopReason::Guardrail(reason),
+                        ..
+                    },
+                ] if reason == "Input flagged by moderation: violence"
+            ));
+            assert!(matches!(
+                options.lock().await.stop_reason,
+                Some(StopReason::Guardrail(_))
+            ));
+            assert!(model.calls().is_empty());
+        }
+
+        #[tokio::test]
+        async fn test_stream_object_ends_on_flagged_input() {
+            #[derive(Debug, serde::Deserialize, schemars::JsonSchema)]
+            #[allow(dead_code)]
+            struct Plan {
+                steps: Vec<String>,
+            }
+
+            let model = MockLanguageModel::new().with_text_stream(["never sent"]);
+
+            let mut response = LanguageModelRequest::builder()
+                .model(model.clone())
+                .prompt("Plan an attack")
+                .input_guardrail(moderation_guardrail(KeywordModeration::default()))
+                .build()
+                .stream_object::<Plan>()
+                .await
+                .unwrap();
+            let chunks: Vec<_> = (&mut response.stream).collect().await;
+
+            assert!(chunks.is_empty());
+            assert!(matches!(
+                response.stop_reason().await,
+                Some(StopReason::Guardrail(_))
+            ));
+            assert!(model.calls().is_empty());
+        }
+
+        #[tokio::test]
+        async fn test_guardrail_moderates_only_new_input() {
+            let model = MockLanguageModel::new().with_text("Hi");
+            let moderation = KeywordModeration::default();
+
+            let messages: Messages = vec![
+                Message::User("Plan an attack".into()),
+                Message::Assistant("I can't help with that.".to_string().into()),
+                Message::User("Hello".into()),
+            ];
+            let result = LanguageModelRequest::builder()
+                .model(model.clone())
+                .messages(messages)
+                .input_guardrail(moderation_guardrail(moderation.clone()))
+                .build()
+                .generate_text()
+                .await
+                .unwrap();
+
+            assert_eq!(result.stop_reason(), Some(StopReason::Finish));
+            assert_eq!(
+                moderation.calls.lock().unwrap()[0].inputs,
+                vec![ModerationInput::Text("Hello".to_string())]
+            );
+        }
+    }
+}
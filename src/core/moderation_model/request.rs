@@ -0,0 +1,206 @@
+use std::ops::{Deref, DerefMut};
+
+use crate::core::messages::{DataContent, MediaPart};
+use crate::core::moderation_model::{
+    ModerationInput, ModerationModel, ModerationModelOptions, ModerationModelResponse,
+};
+use crate::error::Result;
+
+/// Options for moderation requests to be used by `moderate`.
+#[derive(Debug, Clone)]
+pub struct ModerationModelRequest<M: ModerationModel> {
+    /// The moderation model to use for classifying the inputs.
+    pub model: M,
+    /// Inputs for the moderation model request.
+    pub(crate) options: ModerationModelOptions,
+}
+
+impl<M: ModerationModel> ModerationModelRequest<M> {
+    /// Creates a new builder for constructing an `ModerationModelRequest`.
+    ///
+    /// This method initiates the type-state builder pattern, starting with the
+    /// [`ModelStage`] where you must specify the moderation model.
+    pub fn builder() -> ModerationModelRequestBuilder<M> {
+        ModerationModelRequestBuilder::default()
+    }
+
+    /// Classifies the inputs as potentially harmful.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing the per-category flags and scores, or an error if the
+    /// moderation request fails.
+    pub async fn moderate(&self) -> Result<ModerationModelResponse> {
+        self.model.moderate(self.options.clone()).await
+    }
+}
+
+impl<M: ModerationModel> Deref for ModerationModelRequest<M> {
+    type Target = ModerationModelOptions;
+
+    fn deref(&self) -> &Self::Target {
+        &self.options
+    }
+}
+
+impl<M: ModerationModel> DerefMut for ModerationModelRequest<M> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.options
+    }
+}
+
+/// Type-state markers for the `ModerationModelRequestBuilder`.
+///
+/// These zero-sized types ensure the builder is used in the correct order,
+/// preventing invalid request configurations at compile time.
+///
+/// The initial builder state where the moderation model must be set.
+///
+/// Transitions to [`OptionsStage`] after calling [`model`](ModerationModelRequestBuilder::model).
+pub struct ModelStage {}
+
+/// The final state where the inputs can be added before building.
+///
+/// Transitions to the completed `ModerationModelRequest` after calling [`build`](ModerationModelRequestBuilder::build).
+pub struct OptionsStage {}
+
+/// A type-state builder for constructing `ModerationModelRequest` instances.
+///
+/// This builder uses phantom types to enforce a specific construction order,
+/// ensuring that required fields (like the model) are set before optional ones.
+///
+/// # Type Parameters
+///
+/// * `M` - The moderation model type.
+/// * `State` - The current builder state, determining available methods.
+pub struct ModerationModelRequestBuilder<M: ModerationModel, State = ModelStage> {
+    model: Option<M>,
+    options: ModerationModelOptions,
+    state: std::marker::PhantomData<State>,
+}
+
+impl<M: ModerationModel, State> Deref for ModerationModelRequestBuilder<M, State> {
+    type Target = ModerationModelOptions;
+
+    /// Dereferences to the underlying `ModerationModelOptions`.
+    ///
+    /// This allows direct access to the options fields during building.
+    fn deref(&self) -> &Self::Target {
+        &self.options
+    }
+}
+
+impl<M: ModerationModel, State> DerefMut for ModerationModelRequestBuilder<M, State> {
+    /// Mutably dereferences to the underlying `ModerationModelOptions`.
+    ///
+    /// This allows direct mutation of the options fields during building.
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.options
+    }
+}
+
+impl<M: ModerationModel> ModerationModelRequestBuilder<M> {
+    fn default() -> Self {
+        ModerationModelRequestBuilder {
+            model: None,
+            options: ModerationModelOptions::default(),
+            state: std::marker::PhantomData,
+        }
+    }
+}
+
+/// Methods available in the [`ModelStage`] state.
+impl<M: ModerationModel> ModerationModelRequestBuilder<M, ModelStage> {
+    /// Sets the moderation model for the request.
+    ///
+    /// This is the first required step in building a request.
+    ///
+    /// # Parameters
+    ///
+    /// * `model` - The moderation model instance to use.
+    ///
+    /// # Returns
+    ///
+    /// The builder in the [`OptionsStage`] state.
+    pub fn model(self, model: M) -> ModerationModelRequestBuilder<M, OptionsStage> {
+        ModerationModelRequestBuilder {
+            model: Some(model),
+            options: self.options,
+            state: std::marker::PhantomData,
+        }
+    }
+}
+
+/// Methods available in the [`OptionsStage`] state.
+impl<M: ModerationModel> ModerationModelRequestBuilder<M, OptionsStage> {
+    /// Adds a text to classify.
+    ///
+    /// # Parameters
+    ///
+    /// * `text` - The text.
+    ///
+    /// # Returns
+    ///
+    /// The builder with the text added.
+    pub fn text(mut self, text: impl Into<String>) -> Self {
+        self.options.inputs.push(ModerationInput::Text(text.into()));
+        self
+    }
+
+    /// Adds several texts to classify.
+    ///
+    /// # Parameters
+    ///
+    /// * `texts` - The texts.
+    ///
+    /// # Returns
+    ///
+    /// The builder with the texts added.
+    pub fn texts<I, S>(mut self, texts: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.options.inputs.extend(
+            texts
+                .into_iter()
+                .map(|text| ModerationInput::Text(text.into())),
+        );
+        self
+    }
+
+    /// Adds an image to classify, for models that support image inputs.
+    ///
+    /// # Parameters
+    ///
+    /// * `data` - The image data or URL.
+    /// * `media_type` - The IANA media type of the image, e.g. `image/png`.
+    ///
+    /// # Returns
+    ///
+    /// The builder with the image added.
+    pub fn image(mut self, data: impl Into<DataContent>, media_type: impl Into<String>) -> Self {
+        self.options
+            .inputs
+            .push(ModerationInput::Image(MediaPart::new(data, media_type)));
+        self
+    }
+
+    /// Builds the `ModerationModelRequest`.
+    ///
+    /// This method consumes the builder and returns the configured request.
+    ///
+    /// # Returns
+    ///
+    /// The constructed `ModerationModelRequest`.
+    pub fn build(self) -> ModerationModelRequest<M> {
+        let model = self
+            .model
+            .unwrap_or_else(|| unreachable!("Model must be set"));
+
+        ModerationModelRequest {
+            model,
+            options: self.options,
+        }
+    }
+}
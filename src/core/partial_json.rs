@@ -0,0 +1,512 @@
+//! A tolerant parser for incomplete JSON documents.
+//!
+//! Structured output and tool call arguments are streamed as deltas of a JSON
+//! document that is only valid once the stream completes. The parser turns any prefix
+//! of such a document into a best-effort [`Value`] snapshot, so fields can be used as
+//! soon as they arrive.
+//!
+//! # Example
+//!
+//! ```rust
+//! use aisdk::core::partial_json::PartialJsonParser;
+//! use serde::Deserialize;
+//! use serde_json::json;
+//!
+//! #[derive(Debug, Default, Deserialize)]
+//! struct Weather {
+//!     city: Option<String>,
+//!     forecast: Option<Vec<String>>,
+//! }
+//!
+//! let mut parser = PartialJsonParser::new();
+//! assert_eq!(parser.push(r#"{"city": "Par"#), Some(json!({"city": "Par"})));
+//! assert_eq!(parser.push(r#"is", "forecast": ["sun"#), Some(json!({"city": "Paris", "forecast": ["sun"]})));
+//!
+//! let weather: Weather = parser.partial().unwrap();
+//! assert_eq!(weather.city.as_deref(), Some("Paris"));
+//! assert!(!parser.is_complete());
+//! ```
+
+use futures::{Stream, StreamExt};
+use serde::de::DeserializeOwned;
+use serde_json::{Map, Number, Value};
+
+/// Parses a possibly incomplete JSON document.
+///
+/// Unterminated strings, objects and arrays are closed, and trailing tokens that
+/// cannot be completed yet (a dangling key, a partial `true`) are dropped. A leading
+/// markdown code fence is skipped and text after a complete document is ignored.
+///
+/// # Returns
+///
+/// The snapshot and whether the document is complete, or `None` if nothing can be
+/// parsed yet or the text is not JSON.
+pub fn parse_partial_json(text: &str) -> Option<(Value, bool)> {
+    let text = text.trim_start();
+    let text = match text.strip_prefix("```") {
+        // Skip the fence line, e.g. ```json
+        Some(fenced) => fenced.split_once('\n')?.1,
+        None => text,
+    };
+    let mut parser = Parser {
+        bytes: text.as_bytes(),
+        pos: 0,
+    };
+    parser.parse_value().ok()?
+}
+
+/// Parses a possibly incomplete JSON document into a partial value of type `T`.
+///
+/// Fields that have not been generated yet are missing from the snapshot, so `T`
+/// should use `Option` or `#[serde(default)]` fields.
+///
+/// # Returns
+///
+/// The partial value, or `None` if the snapshot cannot be deserialized into `T` yet.
+pub fn parse_partial<T: DeserializeOwned>(text: &str) -> Option<T> {
+    parse_partial_json(text).and_then(|(value, _)| serde_json::from_value(value).ok())
+}
+
+/// An incremental parser turning JSON deltas into snapshots of the document.
+///
+/// Works for any stream of JSON text deltas, such as structured output text or
+/// streamed tool call arguments.
+#[derive(Debug, Clone, Default)]
+pub struct PartialJsonParser {
+    text: String,
+    snapshot: Option<Value>,
+    complete: bool,
+}
+
+impl PartialJsonParser {
+    /// Creates an empty parser.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Appends a delta to the document.
+    ///
+    /// # Parameters
+    ///
+    /// * `delta` - The next piece of the JSON document.
+    ///
+    /// # Returns
+    ///
+    /// The new snapshot if the delta changed it, otherwise `None`.
+    pub fn push(&mut self, delta: &str) -> Option<Value> {
+        self.text.push_str(delta);
+        let (value, complete) = parse_partial_json(&self.text)?;
+        self.complete = complete;
+        if self.snapshot.as_ref() == Some(&value) {
+            return None;
+        }
+        self.snapshot = Some(value.clone());
+        Some(value)
+    }
+
+    /// Returns the latest snapshot of the document.
+    pub fn snapshot(&self) -> Option<&Value> {
+        self.snapshot.as_ref()
+    }
+
+    /// Deserializes the latest snapshot into a partial value of type `T`.
+    ///
+    /// See [`parse_partial`] for the requirements on `T`.
+    pub fn partial<T: DeserializeOwned>(&self) -> Option<T> {
+        self.snapshot
+            .clone()
+            .and_then(|value| serde_json::from_value(value).ok())
+    }
+
+    /// Returns `true` once the document is complete.
+    pub fn is_complete(&self) -> bool {
+        self.complete
+    }
+
+    /// Returns the text received so far.
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// Clears the parser to start a new document.
+    pub fn reset(&mut self) {
+        *self = Self::default();
+    }
+}
+
+/// Turns a stream of JSON deltas into a stream of snapshots.
+///
+/// A snapshot is emitted whenever a delta changes the parsed document.
+pub fn partial_json_stream<S>(deltas: S) -> impl Stream<Item = Value> + Send
+where
+    S: Stream + Send,
+    S::Item: AsRef<str>,
+{
+    deltas
+        .scan(PartialJsonParser::new(), |parser, delta| {
+            futures::future::ready(Some(parser.push(delta.as_ref())))
+        })
+        .filter_map(futures::future::ready)
+}
+
+/// Turns a stream of JSON deltas into a stream of partial values of type `T`.
+///
+/// A value is emitted whenever a delta changes the parsed document and the snapshot
+/// can be deserialized into `T`. See [`parse_partial`] for the requirements on `T`.
+pub fn partial_object_stream<T, S>(deltas: S) -> impl Stream<Item = T> + Send
+where
+    T: DeserializeOwned + Send,
+    S: Stream + Send,
+    S::Item: AsRef<str>,
+{
+    partial_json_stream(deltas)
+        .filter_map(|value| futures::future::ready(serde_json::from_value(value).ok()))
+}
+
+/// The result of parsing one value: `Ok(None)` when the input ends before the value
+/// starts, `Err(())` when the input is not JSON.
+type Parsed = Result<Option<(Value, bool)>, ()>;
+
+struct Parser<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl Parser<'_> {
+    fn peek(&self) -> Option<u8> {
+        self.bytes.get(self.pos).copied()
+    }
+
+    fn skip_whitespace(&mut self) {
+        while matches!(self.peek(), Some(b' ' | b'\n' | b'\r' | b'\t')) {
+            self.pos += 1;
+        }
+    }
+
+    fn parse_value(&mut self) -> Parsed {
+        self.skip_whitespace();
+        match self.peek() {
+            None => Ok(None),
+            Some(b'{') => self.parse_object().map(Some),
+            Some(b'[') => self.parse_array().map(Some),
+            Some(b'"') => {
+                let (s, complete) = self.parse_string()?;
+                Ok(Some((Value::String(s), complete)))
+            }
+            Some(b't') => self.parse_literal("true", Value::Bool(true)),
+            Some(b'f') => self.parse_literal("false", Value::Bool(false)),
+            Some(b'n') => self.parse_literal("null", Value::Null),
+            Some(b'-' | b'0'..=b'9') => self.parse_number(),
+            Some(_) => Err(()),
+        }
+    }
+
+    fn parse_object(&mut self) -> Result<(Value, bool), ()> {
+        self.pos += 1;
+        let mut map = Map::new();
+        loop {
+            self.skip_whitespace();
+            match self.peek() {
+                None => return Ok((Value::Object(map), false)),
+                Some(b'}') => {
+                    self.pos += 1;
+                    return Ok((Value::Object(map), true));
+                }
+                Some(b',') => self.pos += 1,
+                Some(b'"') => {
+                    let (key, complete) = self.parse_string()?;
+                    self.skip_whitespace();
+                    if !complete || self.peek().is_none() {
+                        return Ok((Value::Object(map), false));
+                    }
+                    if self.peek() != Some(b':') {
+                        return Err(());
+                    }
+                    self.pos += 1;
+                    match self.parse_value()? {
+                        None => return Ok((Value::Object(map), false)),
+                        Some((value, complete)) => {
+                            map.insert(key, value);
+                            if !complete {
+                                return Ok((Value::Object(map), false));
+                            }
+                        }
+                    }
+                }
+                Some(_) => return Err(()),
+            }
+        }
+    }
+
+    fn parse_array(&mut self) -> Result<(Value, bool), ()> {
+        self.pos += 1;
+        let mut items = Vec::new();
+        loop {
+            self.skip_whitespace();
+            match self.peek() {
+                None => return Ok((Value::Array(items), false)),
+                Some(b']') => {
+                    self.pos += 1;
+                    return Ok((Value::Array(items), true));
+                }
+                Some(b',') => self.pos += 1,
+                Some(_) => match self.parse_value()? {
+                    None => return Ok((Value::Array(items), false)),
+                    Some((value, complete)) => {
+                        items.push(value);
+                        if !complete {
+                            return Ok((Value::Array(items), false));
+                        }
+                    }
+                },
+            }
+        }
+    }
+
+    /// Parses a string, dropping an escape sequence cut off by the end of input.
+    fn parse_string(&mut self) -> Result<(String, bool), ()> {
+        self.pos += 1;
+        let mut out = String::new();
+        let mut run_start = self.pos;
+        loop {
+            match self.peek() {
+                None => {
+                    out.push_str(self.utf8(run_start, self.pos)?);
+                    return Ok((out, false));
+                }
+                Some(b'"') => {
+                    out.push_str(self.utf8(run_start, self.pos)?);
+                    self.pos += 1;
+                    return Ok((out, true));
+                }
+                Some(b'\\') => {
+                    out.push_str(self.utf8(run_start, self.pos)?);
+                    let Some(escape) = self.bytes.get(self.pos + 1).copied() else {
+                        self.pos = self.bytes.len();
+                        return Ok((out, false));
+                    };
+                    self.pos += 2;
+                    match escape {
+                        b'"' => out.push('"'),
+                        b'\\' => out.push('\\'),
+                        b'/' => out.push('/'),
+                        b'b' => out.push('\u{8}'),
+                        b'f' => out.push('\u{c}'),
+                        b'n' => out.push('\n'),
+                        b'r' => out.push('\r'),
+                        b't' => out.push('\t'),
+                        b'u' => match self.parse_unicode_escape()? {
+                            Some(c) => out.push(c),
+                            None => return Ok((out, false)),
+                        },
+                        _ => return Err(()),
+                    }
+                    run_start = self.pos;
+                }
+                Some(_) => self.pos += 1,
+            }
+        }
+    }
+
+    /// Parses the hex digits of a `\u` escape, including a trailing low surrogate.
+    fn parse_unicode_escape(&mut self) -> Result<Option<char>, ()> {
+        let Some(high) = self.hex4()? else {
+            return Ok(None);
+        };
+        if !(0xD800..0xDC00).contains(&high) {
+            return char::from_u32(high).map(Some).ok_or(());
+        }
+        if self.bytes.len() < self.pos + 2 {
+            self.pos = self.bytes.len();
+            return Ok(None);
+        }
+        if &self.bytes[self.pos..self.pos + 2] != b"\\u" {
+            return Err(());
+        }
+        self.pos += 2;
+        let Some(low) = self.hex4()? else {
+            return Ok(None);
+        };
+        if !(0xDC00..0xE000).contains(&low) {
+            return Err(());
+        }
+        char::from_u32(0x10000 + ((high - 0xD800) << 10) + (low - 0xDC00))
+            .map(Some)
+            .ok_or(())
+    }
+
+    fn hex4(&mut self) -> Result<Option<u32>, ()> {
+        if self.bytes.len() < self.pos + 4 {
+            self.pos = self.bytes.len();
+            return Ok(None);
+        }
+        let digits = std::str::from_utf8(&self.bytes[self.pos..self.pos + 4]).map_err(|_| ())?;
+        self.pos += 4;
+        u32::from_str_radix(digits, 16).map(Some).map_err(|_| ())
+    }
+
+    fn parse_literal(&mut self, literal: &str, value: Value) -> Parsed {
+        let rest = &self.bytes[self.pos..];
+        if rest.starts_with(literal.as_bytes()) {
+            self.pos += literal.len();
+            Ok(Some((value, true)))
+        } else if literal.as_bytes().starts_with(rest) {
+            // The literal is cut off by the end of input.
+            self.pos = self.bytes.len();
+            Ok(None)
+        } else {
+            Err(())
+        }
+    }
+
+    /// Parses a number. A number at the end of input may still grow, so it is
+    /// reported as incomplete.
+    fn parse_number(&mut self) -> Parsed {
+        let start = self.pos;
+        while matches!(
+            self.peek(),
+            Some(b'-' | b'+' | b'.' | b'e' | b'E' | b'0'..=b'9')
+        ) {
+            self.pos += 1;
+        }
+        let complete = self.peek().is_some();
+        let text = std::str::from_utf8(&self.bytes[start..self.pos]).map_err(|_| ())?;
+        // Drop a dangling sign, decimal point or exponent of a cut off number.
+        let text = if complete {
+            text
+        } else {
+            text.trim_end_matches(['-', '+', '.', 'e', 'E'])
+        };
+        if text.is_empty() {
+            return Ok(None);
+        }
+        let number = text
+            .parse::<i64>()
+            .map(Number::from)
+            .ok()
+            .or_else(|| text.parse::<f64>().ok().and_then(Number::from_f64))
+            .ok_or(())?;
+        Ok(Some((Value::Number(number), complete)))
+    }
+
+    fn utf8(&self, start: usize, end: usize) -> Result<&str, ()> {
+        std::str::from_utf8(&self.bytes[start..end]).map_err(|_| ())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::Deserialize;
+    use serde_json::json;
+
+    #[test]
+    fn test_parse_partial_json_prefixes() {
+        let cases = [
+            ("", None),
+            ("{", Some(json!({}))),
+            (r#"{"na"#, Some(json!({}))),
+            (r#"{"name""#, Some(json!({}))),
+            (r#"{"name": "Al"#, Some(json!({"name": "Al"}))),
+            (
+                r#"{"name": "Alice", "tags": ["a", "b"#,
+                Some(json!({"name": "Alice", "tags": ["a", "b"]})),
+            ),
+            (r#"{"ok": tr"#, Some(json!({}))),
+            (r#"{"n": -"#, Some(json!({}))),
+            (r#"{"n": 1.5"#, Some(json!({"n": 1.5}))),
+            (r#"{"n": 12, "#, Some(json!({"n": 12}))),
+            (r#"[{"a": 1}, {"b"#, Some(json!([{"a": 1}, {}]))),
+            (r#""line\"#, Some(json!("line"))),
+            (r#""café \u00"#, Some(json!("café "))),
+            (r#""\uD83D\uDE00"#, Some(json!("😀"))),
+        ];
+        for (text, expected) in cases {
+            assert_eq!(
+                parse_partial_json(text).map(|(value, _)| value),
+                expected,
+                "{text}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_parse_partial_json_completeness() {
+        assert_eq!(
+            parse_partial_json(r#"{"a": [1, 2]}"#),
+            Some((json!({"a": [1, 2]}), true))
+        );
+        assert_eq!(parse_partial_json("12"), Some((json!(12), false)));
+        assert!(!parse_partial_json(r#"{"a": 1"#).unwrap().1);
+    }
+
+    #[test]
+    fn test_parse_partial_json_skips_code_fence() {
+        assert_eq!(parse_partial_json("```json"), None);
+        assert_eq!(
+            parse_partial_json("```json\n{\"a\": [1]}\n```"),
+            Some((json!({"a": [1]}), true))
+        );
+    }
+
+    #[test]
+    fn test_partial_json_parser_tool_call_argument_deltas() {
+        #[derive(Debug, Deserialize, PartialEq)]
+        struct Args {
+            location: Option<String>,
+            unit: Option<String>,
+        }
+
+        let mut parser = PartialJsonParser::new();
+        let snapshots: Vec<Option<Value>> =
+            ["{\"loc", "ation\": \"Par", "is\"", ", \"unit\": \"c\"}"]
+                .into_iter()
+                .map(|delta| parser.push(delta))
+                .collect();
+
+        assert_eq!(
+            snapshots,
+            vec![
+                Some(json!({})),
+                Some(json!({"location": "Par"})),
+                Some(json!({"location": "Paris"})),
+                Some(json!({"location": "Paris", "unit": "c"})),
+            ]
+        );
+        assert!(parser.is_complete());
+        assert_eq!(
+            parser.partial::<Args>(),
+            Some(Args {
+                location: Some("Paris".to_string()),
+                unit: Some("c".to_string()),
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn test_partial_object_stream() {
+        #[derive(Debug, Deserialize)]
+        struct Summary {
+            title: String,
+            points: Option<Vec<String>>,
+        }
+
+        let deltas =
+            futures::stream::iter(["{\"ti", "tle\": \"Rust\", \"poi", "nts\": [\"safe\"]}"]);
+        let summaries: Vec<Summary> = partial_object_stream(deltas).collect().await;
+
+        // The first snapshot has no title yet and is skipped.
+        assert_eq!(summaries.len(), 2);
+        assert_eq!(summaries[0].title, "Rust");
+        assert_eq!(summaries[1].points, Some(vec!["safe".to_string()]));
+    }
+
+    #[test]
+    fn test_parse_partial_json_rejects_invalid_input() {
+        assert_eq!(parse_partial_json("Sure! Here it is"), None);
+        assert_eq!(parse_partial_json(r#"{"a" 1}"#), None);
+        // A high surrogate must be followed by a low surrogate.
+        assert_eq!(parse_partial_json(r#""\uD800\u0041""#), None);
+        assert_eq!(parse_partial_json(r#""\uDC00""#), None);
+    }
+}
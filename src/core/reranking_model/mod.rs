@@ -0,0 +1,123 @@
+//! Reranking model
+//!
+//! Defines the [`RerankingModel`] trait implemented by providers that order documents
+//! by their relevance to a query, together with its options and response types.
+
+/// Reranking request builder and related types.
+#[cfg(feature = "reranking-model-request")]
+pub mod request;
+
+use crate::error::{Error, Result};
+use async_trait::async_trait;
+use derive_builder::Builder;
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "reranking-model-request")]
+pub use request::RerankingModelRequest;
+
+/// The options for reranking requests.
+#[derive(Debug, Clone, Default, Builder)]
+#[builder(setter(into), default, build_fn(error = "Error"))]
+pub struct RerankingModelOptions {
+    /// The query to rank the documents against.
+    pub query: String,
+    /// The documents to rank.
+    pub documents: Vec<String>,
+    /// The number of most relevant documents to return. Defaults to all documents.
+    pub top_n: Option<usize>,
+}
+
+impl RerankingModelOptions {
+    /// Returns the reranking options builder.
+    pub fn builder() -> RerankingModelOptionsBuilder {
+        RerankingModelOptionsBuilder::default()
+    }
+}
+
+/// The core trait abstracting the capabilities of a reranking model.
+#[async_trait]
+pub trait RerankingModel: Clone + Send + Sync + std::fmt::Debug + 'static {
+    /// Ranks the documents in the options by their relevance to the query.
+    async fn rerank(&self, options: RerankingModelOptions) -> Result<RerankingModelResponse>;
+}
+
+/// The response type for reranking requests.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct RerankingModelResponse {
+    /// The ranked documents, most relevant first.
+    pub results: Vec<RankedDocument>,
+}
+
+impl RerankingModelResponse {
+    /// Creates a response from the `(index, score)` pairs returned by a provider.
+    ///
+    /// The results are sorted by descending score and the documents are looked up in
+    /// the request documents. Indices outside of the documents are rejected.
+    pub fn from_scores(
+        documents: &[String],
+        scores: impl IntoIterator<Item = (usize, f64)>,
+    ) -> Result<Self> {
+        let mut results = scores
+            .into_iter()
+            .map(|(index, score)| {
+                let document = documents
+                    .get(index)
+                    .cloned()
+                    .ok_or_else(|| Error::ApiError {
+                        status_code: None,
+                        details: format!("Reranking result refers to unknown document {index}"),
+                    })?;
+                Ok(RankedDocument {
+                    index,
+                    score,
+                    document,
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        results.sort_by(|a, b| b.score.total_cmp(&a.score));
+        Ok(Self { results })
+    }
+}
+
+/// A document with its relevance to the query.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct RankedDocument {
+    /// The index of the document in the request documents.
+    pub index: usize,
+    /// The relevance score of the document. Higher is more relevant; the scale
+    /// depends on the model.
+    pub score: f64,
+    /// The document text.
+    pub document: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_response_from_scores() {
+        let documents = vec!["a".to_string(), "b".to_string(), "c".to_string()];
+
+        let response =
+            RerankingModelResponse::from_scores(&documents, [(0, 0.1), (2, 0.9)]).unwrap();
+        assert_eq!(
+            response.results,
+            vec![
+                RankedDocument {
+                    index: 2,
+                    score: 0.9,
+                    document: "c".to_string(),
+                },
+                RankedDocument {
+                    index: 0,
+                    score: 0.1,
+                    document: "a".to_string(),
+                },
+            ]
+        );
+
+        assert!(RerankingModelResponse::from_scores(&documents, [(3, 0.5)]).is_err());
+    }
+}
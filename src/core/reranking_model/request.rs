@@ -0,0 +1,196 @@
+use std::ops::{Deref, DerefMut};
+
+use crate::core::reranking_model::{RerankingModel, RerankingModelOptions, RerankingModelResponse};
+use crate::error::Result;
+
+/// Options for reranking requests to be used by `rerank`.
+#[derive(Debug, Clone)]
+pub struct RerankingModelRequest<M: RerankingModel> {
+    /// The reranking model to use for ranking the documents.
+    pub model: M,
+    /// Query, documents and configuration options for the reranking model request.
+    pub(crate) options: RerankingModelOptions,
+}
+
+impl<M: RerankingModel> RerankingModelRequest<M> {
+    /// Creates a new builder for constructing an `RerankingModelRequest`.
+    ///
+    /// This method initiates the type-state builder pattern, starting with the
+    /// [`ModelStage`] where you must specify the reranking model.
+    pub fn builder() -> RerankingModelRequestBuilder<M> {
+        RerankingModelRequestBuilder::default()
+    }
+
+    /// Ranks the documents by their relevance to the query.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing the ranked documents with their indices and scores, most
+    /// relevant first, or an error if the reranking request fails.
+    pub async fn rerank(&self) -> Result<RerankingModelResponse> {
+        self.model.rerank(self.options.clone()).await
+    }
+}
+
+impl<M: RerankingModel> Deref for RerankingModelRequest<M> {
+    type Target = RerankingModelOptions;
+
+    fn deref(&self) -> &Self::Target {
+        &self.options
+    }
+}
+
+impl<M: RerankingModel> DerefMut for RerankingModelRequest<M> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.options
+    }
+}
+
+/// Type-state markers for the `RerankingModelRequestBuilder`.
+///
+/// These zero-sized types ensure the builder is used in the correct order,
+/// preventing invalid request configurations at compile time.
+///
+/// The initial builder state where the reranking model must be set.
+///
+/// Transitions to [`OptionsStage`] after calling [`model`](RerankingModelRequestBuilder::model).
+pub struct ModelStage {}
+
+/// The final state where the query, documents and options can be configured before building.
+///
+/// Transitions to the completed `RerankingModelRequest` after calling [`build`](RerankingModelRequestBuilder::build).
+pub struct OptionsStage {}
+
+/// A type-state builder for constructing `RerankingModelRequest` instances.
+///
+/// This builder uses phantom types to enforce a specific construction order,
+/// ensuring that required fields (like the model) are set before optional ones.
+///
+/// # Type Parameters
+///
+/// * `M` - The reranking model type.
+/// * `State` - The current builder state, determining available methods.
+pub struct RerankingModelRequestBuilder<M: RerankingModel, State = ModelStage> {
+    model: Option<M>,
+    options: RerankingModelOptions,
+    state: std::marker::PhantomData<State>,
+}
+
+impl<M: RerankingModel, State> Deref for RerankingModelRequestBuilder<M, State> {
+    type Target = RerankingModelOptions;
+
+    /// Dereferences to the underlying `RerankingModelOptions`.
+    ///
+    /// This allows direct access to the options fields during building.
+    fn deref(&self) -> &Self::Target {
+        &self.options
+    }
+}
+
+impl<M: RerankingModel, State> DerefMut for RerankingModelRequestBuilder<M, State> {
+    /// Mutably dereferences to the underlying `RerankingModelOptions`.
+    ///
+    /// This allows direct mutation of the options fields during building.
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.options
+    }
+}
+
+impl<M: RerankingModel> RerankingModelRequestBuilder<M> {
+    fn default() -> Self {
+        RerankingModelRequestBuilder {
+            model: None,
+            options: RerankingModelOptions::default(),
+            state: std::marker::PhantomData,
+        }
+    }
+}
+
+/// Methods available in the [`ModelStage`] state.
+impl<M: RerankingModel> RerankingModelRequestBuilder<M, ModelStage> {
+    /// Sets the reranking model for the request.
+    ///
+    /// This is the first required step in building a request.
+    ///
+    /// # Parameters
+    ///
+    /// * `model` - The reranking model instance to use.
+    ///
+    /// # Returns
+    ///
+    /// The builder in the [`OptionsStage`] state.
+    pub fn model(self, model: M) -> RerankingModelRequestBuilder<M, OptionsStage> {
+        RerankingModelRequestBuilder {
+            model: Some(model),
+            options: self.options,
+            state: std::marker::PhantomData,
+        }
+    }
+}
+
+/// Methods available in the [`OptionsStage`] state.
+impl<M: RerankingModel> RerankingModelRequestBuilder<M, OptionsStage> {
+    /// Sets the query to rank the documents against.
+    ///
+    /// # Parameters
+    ///
+    /// * `query` - The query text.
+    ///
+    /// # Returns
+    ///
+    /// The builder with the query set.
+    pub fn query(mut self, query: impl Into<String>) -> Self {
+        self.options.query = query.into();
+        self
+    }
+
+    /// Sets the documents to rank.
+    ///
+    /// # Parameters
+    ///
+    /// * `documents` - The document texts.
+    ///
+    /// # Returns
+    ///
+    /// The builder with the documents set.
+    pub fn documents<I, S>(mut self, documents: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.options.documents = documents.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Sets the number of most relevant documents to return.
+    ///
+    /// # Parameters
+    ///
+    /// * `top_n` - The number of documents to return.
+    ///
+    /// # Returns
+    ///
+    /// The builder with the number of documents set.
+    pub fn top_n(mut self, top_n: usize) -> Self {
+        self.options.top_n = Some(top_n);
+        self
+    }
+
+    /// Builds the `RerankingModelRequest`.
+    ///
+    /// This method consumes the builder and returns the configured request.
+    ///
+    /// # Returns
+    ///
+    /// The constructed `RerankingModelRequest`.
+    pub fn build(self) -> RerankingModelRequest<M> {
+        let model = self
+            .model
+            .unwrap_or_else(|| unreachable!("Model must be set"));
+
+        RerankingModelRequest {
+            model,
+            options: self.options,
+        }
+    }
+}
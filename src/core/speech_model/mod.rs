@@ -0,0 +1,135 @@
+//! Speech model
+//!
+//! Defines the [`SpeechModel`] trait implemented by providers that synthesize speech
+//! from text, together with its options and response types.
+
+/// Speech request builder and related types.
+#[cfg(feature = "speech-model-request")]
+pub mod request;
+
+use crate::error::{Error, Result};
+use async_trait::async_trait;
+use derive_builder::Builder;
+use futures::Stream;
+use serde::{Deserialize, Serialize};
+use std::pin::Pin;
+
+#[cfg(feature = "speech-model-request")]
+pub use request::SpeechModelRequest;
+
+/// The audio format of synthesized speech.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum AudioFormat {
+    /// MP3 audio.
+    #[default]
+    Mp3,
+    /// Opus audio.
+    Opus,
+    /// AAC audio.
+    Aac,
+    /// FLAC audio.
+    Flac,
+    /// WAV audio.
+    Wav,
+    /// Raw 16-bit PCM samples without a header.
+    Pcm,
+}
+
+impl AudioFormat {
+    /// Returns the name of the format, e.g. `mp3`.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Mp3 => "mp3",
+            Self::Opus => "opus",
+            Self::Aac => "aac",
+            Self::Flac => "flac",
+            Self::Wav => "wav",
+            Self::Pcm => "pcm",
+        }
+    }
+
+    /// Returns the IANA media type of the format, e.g. `audio/mpeg`.
+    pub fn media_type(&self) -> &'static str {
+        match self {
+            Self::Mp3 => "audio/mpeg",
+            Self::Opus => "audio/opus",
+            Self::Aac => "audio/aac",
+            Self::Flac => "audio/flac",
+            Self::Wav => "audio/wav",
+            Self::Pcm => "audio/pcm",
+        }
+    }
+}
+
+/// The options for speech synthesis requests.
+#[derive(Debug, Clone, Default, Builder)]
+#[builder(setter(into), default, build_fn(error = "Error"))]
+pub struct SpeechModelOptions {
+    /// The text to synthesize.
+    pub text: String,
+    /// The voice to use, e.g. `alloy`. Available voices depend on the model.
+    pub voice: Option<String>,
+    /// The audio format of the output. Defaults to the provider's default format.
+    pub format: Option<AudioFormat>,
+    /// The speed of the speech, where `1.0` is the normal speed.
+    pub speed: Option<f32>,
+    /// Instructions controlling the voice, such as tone or accent, for models that support them.
+    pub instructions: Option<String>,
+}
+
+impl SpeechModelOptions {
+    /// Returns the speech options builder.
+    pub fn builder() -> SpeechModelOptionsBuilder {
+        SpeechModelOptionsBuilder::default()
+    }
+}
+
+/// The core trait abstracting the capabilities of a speech synthesis model.
+#[async_trait]
+pub trait SpeechModel: Clone + Send + Sync + std::fmt::Debug + 'static {
+    /// Synthesizes the text in the options into audio.
+    async fn generate_speech(&self, options: SpeechModelOptions) -> Result<SpeechModelResponse>;
+
+    /// Synthesizes the text in the options, yielding the audio in chunks as it is produced.
+    async fn stream_speech(&self, options: SpeechModelOptions) -> Result<SpeechModelStream>;
+}
+
+/// The response type for speech synthesis requests.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SpeechModelResponse {
+    /// The synthesized audio.
+    pub audio: Vec<u8>,
+    /// The IANA media type of the audio, e.g. `audio/mpeg`.
+    pub media_type: String,
+}
+
+/// A stream of audio chunks.
+pub type AudioStream = Pin<Box<dyn Stream<Item = Result<Vec<u8>>> + Send>>;
+
+/// The response type for streaming speech synthesis requests.
+pub struct SpeechModelStream {
+    /// The audio chunks, in order.
+    pub stream: AudioStream,
+    /// The IANA media type of the audio, e.g. `audio/mpeg`.
+    pub media_type: String,
+}
+
+impl std::fmt::Debug for SpeechModelStream {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SpeechModelStream")
+            .field("media_type", &self.media_type)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Stream for SpeechModelStream {
+    type Item = Result<Vec<u8>>;
+
+    fn poll_next(
+        mut self: Pin<&mut Self>,
+        cx: &mut std::task::Context<'_>,
+    ) -> std::task::Poll<Option<Self::Item>> {
+        self.stream.as_mut().poll_next(cx)
+    }
+}
@@ -0,0 +1,247 @@
+use std::ops::{Deref, DerefMut};
+
+use crate::core::speech_model::{
+    AudioFormat, SpeechModel, SpeechModelOptions, SpeechModelResponse, SpeechModelStream,
+};
+use crate::error::Result;
+
+/// Options for speech synthesis requests to be used by `generate_speech` and `stream_speech`.
+#[derive(Debug, Clone)]
+pub struct SpeechModelRequest<M: SpeechModel> {
+    /// The speech model to use for synthesizing audio.
+    pub model: M,
+    /// Text and configuration options for the speech model request.
+    pub(crate) options: SpeechModelOptions,
+}
+
+impl<M: SpeechModel> SpeechModelRequest<M> {
+    /// Creates a new builder for constructing an `SpeechModelRequest`.
+    ///
+    /// This method initiates the type-state builder pattern, starting with the
+    /// [`ModelStage`] where you must specify the speech model.
+    pub fn builder() -> SpeechModelRequestBuilder<M> {
+        SpeechModelRequestBuilder::default()
+    }
+
+    /// Synthesizes the text into audio.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing the audio bytes and their media type, or an error if the
+    /// request fails.
+    pub async fn generate_speech(&self) -> Result<SpeechModelResponse> {
+        self.model.generate_speech(self.options.clone()).await
+    }
+
+    /// Synthesizes the text into audio, streaming the audio chunks as they arrive.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing a stream of audio chunks and their media type, or an error
+    /// if the request fails.
+    pub async fn stream_speech(&self) -> Result<SpeechModelStream> {
+        self.model.stream_speech(self.options.clone()).await
+    }
+}
+
+impl<M: SpeechModel> Deref for SpeechModelRequest<M> {
+    type Target = SpeechModelOptions;
+
+    fn deref(&self) -> &Self::Target {
+        &self.options
+    }
+}
+
+impl<M: SpeechModel> DerefMut for SpeechModelRequest<M> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.options
+    }
+}
+
+/// Type-state markers for the `SpeechModelRequestBuilder`.
+///
+/// These zero-sized types ensure the builder is used in the correct order,
+/// preventing invalid request configurations at compile time.
+///
+/// The initial builder state where the speech model must be set.
+///
+/// Transitions to [`TextStage`] after calling [`model`](SpeechModelRequestBuilder::model).
+pub struct ModelStage {}
+
+/// The state where the text must be set.
+///
+/// Transitions to [`OptionsStage`] after calling [`text`](SpeechModelRequestBuilder::text).
+pub struct TextStage {}
+
+/// The final state where the speech options can be configured before building.
+///
+/// Transitions to the completed `SpeechModelRequest` after calling [`build`](SpeechModelRequestBuilder::build).
+pub struct OptionsStage {}
+
+/// A type-state builder for constructing `SpeechModelRequest` instances.
+///
+/// This builder uses phantom types to enforce a specific construction order,
+/// ensuring that required fields (the model and the text) are set before optional ones.
+///
+/// # Type Parameters
+///
+/// * `M` - The speech model type.
+/// * `State` - The current builder state, determining available methods.
+pub struct SpeechModelRequestBuilder<M: SpeechModel, State = ModelStage> {
+    model: Option<M>,
+    options: SpeechModelOptions,
+    state: std::marker::PhantomData<State>,
+}
+
+impl<M: SpeechModel, State> Deref for SpeechModelRequestBuilder<M, State> {
+    type Target = SpeechModelOptions;
+
+    /// Dereferences to the underlying `SpeechModelOptions`.
+    ///
+    /// This allows direct access to the options fields during building.
+    fn deref(&self) -> &Self::Target {
+        &self.options
+    }
+}
+
+impl<M: SpeechModel, State> DerefMut for SpeechModelRequestBuilder<M, State> {
+    /// Mutably dereferences to the underlying `SpeechModelOptions`.
+    ///
+    /// This allows direct mutation of the options fields during building.
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.options
+    }
+}
+
+impl<M: SpeechModel> SpeechModelRequestBuilder<M> {
+    fn default() -> Self {
+        SpeechModelRequestBuilder {
+            model: None,
+            options: SpeechModelOptions::default(),
+            state: std::marker::PhantomData,
+        }
+    }
+}
+
+impl<M: SpeechModel, State> SpeechModelRequestBuilder<M, State> {
+    fn into_state<Next>(self) -> SpeechModelRequestBuilder<M, Next> {
+        SpeechModelRequestBuilder {
+            model: self.model,
+            options: self.options,
+            state: std::marker::PhantomData,
+        }
+    }
+}
+
+/// Methods available in the [`ModelStage`] state.
+impl<M: SpeechModel> SpeechModelRequestBuilder<M, ModelStage> {
+    /// Sets the speech model for the request.
+    ///
+    /// This is the first required step in building a request.
+    ///
+    /// # Parameters
+    ///
+    /// * `model` - The speech model instance to use.
+    ///
+    /// # Returns
+    ///
+    /// The builder in the [`TextStage`] state.
+    pub fn model(mut self, model: M) -> SpeechModelRequestBuilder<M, TextStage> {
+        self.model = Some(model);
+        self.into_state()
+    }
+}
+
+/// Methods available in the [`TextStage`] state.
+impl<M: SpeechModel> SpeechModelRequestBuilder<M, TextStage> {
+    /// Sets the text to synthesize.
+    ///
+    /// # Parameters
+    ///
+    /// * `text` - The text to speak.
+    ///
+    /// # Returns
+    ///
+    /// The builder in the [`OptionsStage`] state.
+    pub fn text(mut self, text: impl Into<String>) -> SpeechModelRequestBuilder<M, OptionsStage> {
+        self.options.text = text.into();
+        self.into_state()
+    }
+}
+
+/// Methods available in the [`OptionsStage`] state.
+impl<M: SpeechModel> SpeechModelRequestBuilder<M, OptionsStage> {
+    /// Sets the voice of the speech.
+    ///
+    /// # Parameters
+    ///
+    /// * `voice` - The voice name, e.g. `alloy`.
+    ///
+    /// # Returns
+    ///
+    /// The builder with the voice set.
+    pub fn voice(mut self, voice: impl Into<String>) -> Self {
+        self.options.voice = Some(voice.into());
+        self
+    }
+
+    /// Sets the audio format of the output.
+    ///
+    /// # Parameters
+    ///
+    /// * `format` - The [`AudioFormat`] to produce.
+    ///
+    /// # Returns
+    ///
+    /// The builder with the format set.
+    pub fn format(mut self, format: AudioFormat) -> Self {
+        self.options.format = Some(format);
+        self
+    }
+
+    /// Sets the speed of the speech.
+    ///
+    /// # Parameters
+    ///
+    /// * `speed` - The speed, where `1.0` is the normal speed.
+    ///
+    /// # Returns
+    ///
+    /// The builder with the speed set.
+    pub fn speed(mut self, speed: f32) -> Self {
+        self.options.speed = Some(speed);
+        self
+    }
+
+    /// Sets instructions controlling the voice, such as tone or accent.
+    ///
+    /// # Parameters
+    ///
+    /// * `instructions` - The voice instructions.
+    ///
+    /// # Returns
+    ///
+    /// The builder with the instructions set.
+    pub fn instructions(mut self, instructions: impl Into<String>) -> Self {
+        self.options.instructions = Some(instructions.into());
+        self
+    }
+
+    /// Builds the `SpeechModelRequest`.
+    ///
+    /// This method consumes the builder and returns the configured request.
+    ///
+    /// # Returns
+    ///
+    /// The constructed `SpeechModelRequest`.
+    pub fn build(self) -> SpeechModelRequest<M> {
+        let model = self
+            .model
+            .unwrap_or_else(|| unreachable!("Model must be set"));
+
+        SpeechModelRequest {
+            model,
+            options: self.options,
+        }
+    }
+}
@@ -31,6 +31,23 @@
 //!
 //! ```
 //!
+//! Tools that perform I/O can be declared as `async fn`; the macro wires them up with
+//! [`ToolExecute::new_async`].
+//!
+//! ```
+//! use aisdk::core::tools::Tool;
+//! use aisdk::macros::tool;
+//!
+//! #[tool]
+//! /// Looks up a user name.
+//! pub async fn get_username(id: String) -> Tool {
+//!     Ok(format!("user_{}", id))
+//! }
+//!
+//! let tool: Tool = get_username();
+//! assert_eq!(tool.name, "get_username");
+//! ```
+//!
 //! # Example with struct
 //!
 //! ```rust
@@ -55,6 +72,7 @@
 //!             let b = params["b"].as_u64().unwrap();
 //!             Ok(format!("{}", a + b))
 //!         })),
+//!     needs_approval: false,
 //! };
 //!
 //! assert_eq!(tool.name, "sum");
@@ -62,6 +80,8 @@
 //! ```
 //!
 
+use crate::core::Messages;
+use crate::core::messages::UserContentPart;
 use crate::error::{Error, Result};
 use crate::extensions::Extensions;
 use derive_builder::Builder;
@@ -69,34 +89,276 @@ use schemars::Schema;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use std::fmt::Debug;
+use std::future::Future;
+use std::pin::Pin;
 use std::sync::{Arc, Mutex};
 use tokio::task::JoinHandle;
 
 /// A function that will be called when the tool is executed.
 pub type ToolFn = Box<dyn Fn(Value) -> std::result::Result<String, String> + Send + Sync>;
 
+/// The future returned by an asynchronous tool function.
+pub type ToolFuture = Pin<Box<dyn Future<Output = std::result::Result<String, String>> + Send>>;
+
+/// An asynchronous function that will be called when the tool is executed.
+pub type AsyncToolFn = Box<dyn Fn(Value) -> ToolFuture + Send + Sync>;
+
+/// A function that will be called with the [`ToolContext`] when the tool is executed.
+pub type ContextToolFn =
+    Box<dyn Fn(Value, ToolContext) -> std::result::Result<String, String> + Send + Sync>;
+
+/// An asynchronous function that will be called with the [`ToolContext`] when the tool
+/// is executed.
+pub type AsyncContextToolFn = Box<dyn Fn(Value, ToolContext) -> ToolFuture + Send + Sync>;
+
+/// The future returned by a tool function with a structured output.
+pub type ToolOutputFuture =
+    Pin<Box<dyn Future<Output = std::result::Result<ToolOutput, String>> + Send>>;
+
+/// An asynchronous function that will be called with the [`ToolContext`] when the tool
+/// is executed, returning a structured [`ToolOutput`].
+pub type StructuredToolFn = Box<dyn Fn(Value, ToolContext) -> ToolOutputFuture + Send + Sync>;
+
+/// The output of a tool call.
+///
+/// Serialized as the JSON value for text and JSON outputs, and as
+/// `{"content": [...]}` for multi-part content.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ToolOutput {
+    /// Plain text.
+    Text(String),
+    /// Arbitrary JSON.
+    Json(Value),
+    /// Multi-part content such as text and images, for providers that support it.
+    Content(Vec<UserContentPart>),
+}
+
+impl ToolOutput {
+    /// Returns the output as JSON. Text becomes a JSON string.
+    pub fn to_value(&self) -> Value {
+        match self {
+            ToolOutput::Text(text) => Value::String(text.clone()),
+            ToolOutput::Json(value) => value.clone(),
+            ToolOutput::Content(parts) => serde_json::json!({ "content": parts }),
+        }
+    }
+
+    /// Returns the output as text. JSON is serialized and only the text parts of
+    /// multi-part content are kept.
+    pub fn to_text(&self) -> String {
+        match self {
+            ToolOutput::Text(text) => text.clone(),
+            ToolOutput::Json(value) => value.to_string(),
+            ToolOutput::Content(parts) => parts
+                .iter()
+                .filter_map(|part| match part {
+                    UserContentPart::Text(text) => Some(text.as_str()),
+                    _ => None,
+                })
+                .collect::<Vec<_>>()
+                .join("\n"),
+        }
+    }
+}
+
+impl From<String> for ToolOutput {
+    fn from(value: String) -> Self {
+        ToolOutput::Text(value)
+    }
+}
+
+impl From<&str> for ToolOutput {
+    fn from(value: &str) -> Self {
+        ToolOutput::Text(value.to_string())
+    }
+}
+
+impl From<Value> for ToolOutput {
+    /// JSON strings become [`ToolOutput::Text`].
+    fn from(value: Value) -> Self {
+        match value {
+            Value::String(text) => ToolOutput::Text(text),
+            value => ToolOutput::Json(value),
+        }
+    }
+}
+
+impl From<Vec<UserContentPart>> for ToolOutput {
+    fn from(value: Vec<UserContentPart>) -> Self {
+        ToolOutput::Content(value)
+    }
+}
+
+/// Request-scoped state passed to every tool call.
+///
+/// Values are attached to a request with its `with_tool_context` builder method, e.g.
+/// the authenticated user or a database pool.
+///
+/// # Example
+/// ```
+/// use aisdk::core::tools::{ToolContext, ToolExecute};
+///
+/// #[derive(Clone)]
+/// struct UserId(String);
+///
+/// let execute = ToolExecute::new_with_context(Box::new(|_, context: ToolContext| {
+///     let user = context.get::<UserId>().ok_or("not signed in")?;
+///     Ok(format!("orders of {}", user.0))
+/// }));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct ToolContext {
+    /// The id of the tool call.
+    pub tool_call_id: String,
+    /// The conversation messages, up to and including the tool call.
+    pub messages: Messages,
+    /// The values attached to the request.
+    pub extensions: Extensions,
+}
+
+impl ToolContext {
+    /// Returns a clone of the attached value of type `T`, if there is one.
+    pub fn get<T: Clone + Send + Sync + 'static>(&self) -> Option<T> {
+        self.extensions.try_get::<T>().map(|value| value.clone())
+    }
+}
+
+/// The function backing a `ToolExecute`, either synchronous or asynchronous.
+#[derive(Clone)]
+enum ToolFnKind {
+    Sync(Arc<ContextToolFn>),
+    Async(Arc<AsyncContextToolFn>),
+    Structured(Arc<StructuredToolFn>),
+    /// No function, the caller executes the tool.
+    Deferred,
+}
+
 /// Holds the function that will be called when the tool is executed. the function
 /// should take a single argument of type `Value` and returns a
-/// `Result<String, String>`.
+/// `Result<String, String>`, either directly or as a future.
 #[derive(Clone)]
 pub struct ToolExecute {
-    inner: Arc<ToolFn>,
+    inner: ToolFnKind,
 }
 
 impl ToolExecute {
     /// Calls the tool with the given input.
+    ///
+    /// Only synchronous tools can be called this way. Asynchronous tools return an
+    /// error, call them with [`call_async`](Self::call_async) instead.
     pub fn call(&self, map: Value) -> Result<String> {
-        (*self.inner)(map).map_err(Error::ToolCallError)
+        match &self.inner {
+            ToolFnKind::Sync(f) => (**f)(map, ToolContext::default()),
+            ToolFnKind::Async(_) | ToolFnKind::Structured(_) => Err(ASYNC_ERROR.to_string()),
+            ToolFnKind::Deferred => Err(DEFERRED_ERROR.to_string()),
+        }
+        .map_err(Error::ToolCallError)
+    }
+
+    /// Calls the tool with the given input, awaiting asynchronous tools.
+    pub async fn call_async(&self, map: Value) -> Result<String> {
+        self.call_with_context(map, ToolContext::default()).await
+    }
+
+    /// Calls the tool with the given input and context, awaiting asynchronous tools.
+    pub async fn call_with_context(&self, map: Value, context: ToolContext) -> Result<String> {
+        self.call_output(map, context)
+            .await
+            .map(|output| output.to_text())
+    }
+
+    /// Calls the tool with the given input and context and returns its structured output.
+    pub async fn call_output(&self, map: Value, context: ToolContext) -> Result<ToolOutput> {
+        match &self.inner {
+            ToolFnKind::Sync(f) => (**f)(map, context).map(ToolOutput::Text),
+            ToolFnKind::Async(f) => (**f)(map, context).await.map(ToolOutput::Text),
+            ToolFnKind::Structured(f) => (**f)(map, context).await,
+            ToolFnKind::Deferred => Err(DEFERRED_ERROR.to_string()),
+        }
+        .map_err(Error::ToolCallError)
     }
 
     /// Creates a new `ToolExecute` instance with the given function.
     /// The function should take a single argument of type `Value` and return a
     /// `Result<String, String>`.
     pub fn new(f: ToolFn) -> Self {
-        Self { inner: Arc::new(f) }
+        Self::new_with_context(Box::new(move |input, _| f(input)))
+    }
+
+    /// Creates a new `ToolExecute` instance with a function that also receives the
+    /// [`ToolContext`] of the call.
+    pub fn new_with_context(f: ContextToolFn) -> Self {
+        Self {
+            inner: ToolFnKind::Sync(Arc::new(f)),
+        }
+    }
+
+    /// Creates a new `ToolExecute` instance with the given asynchronous function.
+    /// The function should take a single argument of type `Value` and return a
+    /// boxed future resolving to `Result<String, String>`.
+    ///
+    /// # Example
+    /// ```
+    /// use aisdk::core::tools::ToolExecute;
+    /// use serde_json::Value;
+    ///
+    /// let execute = ToolExecute::new_async(Box::new(|params: Value| {
+    ///     Box::pin(async move { Ok(format!("hello {}", params["name"])) })
+    /// }));
+    /// ```
+    pub fn new_async(f: AsyncToolFn) -> Self {
+        Self::new_async_with_context(Box::new(move |input, _| f(input)))
+    }
+
+    /// Creates a new `ToolExecute` instance with an asynchronous function that also
+    /// receives the [`ToolContext`] of the call.
+    pub fn new_async_with_context(f: AsyncContextToolFn) -> Self {
+        Self {
+            inner: ToolFnKind::Async(Arc::new(f)),
+        }
+    }
+
+    /// Creates a new `ToolExecute` instance with an asynchronous function that returns
+    /// a structured [`ToolOutput`], such as JSON or text and images.
+    ///
+    /// # Example
+    /// ```
+    /// use aisdk::core::tools::{ToolExecute, ToolOutput};
+    /// use serde_json::json;
+    ///
+    /// let execute = ToolExecute::new_structured(Box::new(|params, _context| {
+    ///     Box::pin(async move { Ok(ToolOutput::Json(json!({ "echo": params }))) })
+    /// }));
+    /// ```
+    pub fn new_structured(f: StructuredToolFn) -> Self {
+        Self {
+            inner: ToolFnKind::Structured(Arc::new(f)),
+        }
+    }
+
+    /// Creates a `ToolExecute` without a function, for tools executed by the caller,
+    /// e.g. in the browser.
+    ///
+    /// Generation stops with
+    /// [`StopReason::DeferredToolCalls`](crate::core::language_model::StopReason::DeferredToolCalls)
+    /// when the model calls such a tool. Add a [`Message::Tool`](crate::core::Message::Tool)
+    /// result for each call to the conversation to continue.
+    pub fn deferred() -> Self {
+        Self {
+            inner: ToolFnKind::Deferred,
+        }
+    }
+
+    /// Returns `true` if the tool is executed by the caller.
+    pub fn is_deferred(&self) -> bool {
+        matches!(self.inner, ToolFnKind::Deferred)
     }
 }
 
+const DEFERRED_ERROR: &str = "Tool is executed by the caller";
+
+const ASYNC_ERROR: &str = "Asynchronous tools must be called with `call_async`";
+
 impl Default for ToolExecute {
     fn default() -> Self {
         Self::new(Box::new(|_| Ok("".to_string())))
@@ -158,6 +420,7 @@ impl<'de> Deserialize<'de> for ToolExecute {
 ///             let b = params["b"].as_u64().unwrap();
 ///             Ok(format!("{}", a + b))
 ///         })),
+///     needs_approval: false,
 /// };
 ///
 /// assert_eq!(tool.name, "sum");
@@ -173,7 +436,16 @@ pub struct Tool {
     /// The input schema of the tool as json schema
     pub input_schema: Schema,
     /// The output schema of the tool. AI will use this to generate outputs.
+    ///
+    /// Defaults to [`ToolExecute::deferred`] in the builder.
+    #[builder(default = "ToolExecute::deferred()")]
     pub execute: ToolExecute,
+    /// Whether a call of the tool must be approved before it is executed.
+    ///
+    /// Generation stops with [`StopReason::ToolApprovalRequired`](crate::core::language_model::StopReason::ToolApprovalRequired)
+    /// when the model calls such a tool, and resumes once a [`ToolApproval`] is given.
+    #[builder(default)]
+    pub needs_approval: bool,
 }
 
 impl Debug for Tool {
@@ -181,6 +453,7 @@ impl Debug for Tool {
         f.debug_struct("Tool")
             .field("name", &self.name)
             .field("description", &self.description)
+            .field("needs_approval", &self.needs_approval)
             .finish()
     }
 }
@@ -215,17 +488,42 @@ impl ToolList {
             .push(tool);
     }
 
-    /// Executes a tool.
-    pub async fn execute(&self, tool_info: ToolCallInfo) -> JoinHandle<Result<String>> {
-        let tools = self.tools.clone();
-        tokio::spawn(async move {
-            let tools = tools
-                .lock()
-                .unwrap_or_else(|poisoned| poisoned.into_inner());
-            let tool = tools.iter().find(|tool| tool.name == tool_info.tool.name);
+    /// Returns `true` if calls of the tool with the given name need approval.
+    pub fn needs_approval(&self, name: &str) -> bool {
+        self.tools
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .iter()
+            .any(|tool| tool.name == name && tool.needs_approval)
+    }
+
+    /// Returns `true` if the tool with the given name is executed by the caller.
+    pub fn is_deferred(&self, name: &str) -> bool {
+        self.tools
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .iter()
+            .any(|tool| tool.name == name && tool.execute.is_deferred())
+    }
 
-            match tool {
-                Some(tool) => tool.execute.call(tool_info.input),
+    /// Executes a tool with the given context.
+    pub async fn execute(
+        &self,
+        tool_info: ToolCallInfo,
+        context: ToolContext,
+    ) -> JoinHandle<Result<ToolOutput>> {
+        // release the lock before running the tool so other calls are not blocked
+        let execute = self
+            .tools
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .iter()
+            .find(|tool| tool.name == tool_info.tool.name)
+            .map(|tool| tool.execute.clone());
+
+        tokio::spawn(async move {
+            match execute {
+                Some(execute) => execute.call_output(tool_info.input, context).await,
                 None => Err(crate::error::Error::ToolCallError(
                     "Tool not found".to_string(),
                 )),
@@ -234,7 +532,21 @@ impl ToolList {
     }
 }
 
-#[derive(Default, Debug, Clone, PartialEq)]
+/// A decision on a tool call that needs approval.
+///
+/// Pass it with the call id to the `tool_approval` method of the request builder
+/// when resuming the conversation.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ToolApproval {
+    /// Execute the tool call as requested by the model.
+    Approve,
+    /// Execute the tool call with a different input.
+    Edit(serde_json::Value),
+    /// Do not execute the tool call. The reason is sent to the model as the tool result.
+    Deny(String),
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 /// Describes a tool
 pub struct ToolDetails {
     /// The name of the tool, usually a function name.
@@ -244,13 +556,14 @@ pub struct ToolDetails {
 }
 
 /// Contains information necessary to call a tool
-#[derive(Default, Debug, Clone)]
+#[derive(Default, Debug, Clone, Serialize, Deserialize)]
 pub struct ToolCallInfo {
     /// The details of the tool to be called.
     pub tool: ToolDetails,
     /// The input parameters for the tool.
     pub input: serde_json::Value,
     /// Provider-specific extensions.
+    #[serde(default, skip_serializing_if = "Extensions::is_empty")]
     pub extensions: Extensions,
 }
 
@@ -290,20 +603,23 @@ impl ToolCallInfo {
 }
 
 /// Contains information from a tool
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ToolResultInfo {
     /// The details of the tool.
     pub tool: ToolDetails,
 
-    /// The output of the tool.
-    pub output: Result<serde_json::Value>,
+    /// The output of the tool, or the error of a failed call.
+    ///
+    /// Serialized as `{"ok": <value>}`, `{"content": [...]}` or `{"error": "<message>"}`.
+    #[serde(with = "tool_output")]
+    pub output: Result<ToolOutput>,
 }
 
 impl Default for ToolResultInfo {
     fn default() -> Self {
         Self {
             tool: ToolDetails::default(),
-            output: Ok(serde_json::Value::Null),
+            output: Ok(ToolOutput::Json(serde_json::Value::Null)),
         }
     }
 }
@@ -331,7 +647,55 @@ impl ToolResultInfo {
     }
 
     /// Sets the output of the tool.
-    pub fn output(&mut self, inp: serde_json::Value) {
-        self.output = Ok(inp);
+    pub fn output(&mut self, inp: impl Into<ToolOutput>) {
+        self.output = Ok(inp.into());
+    }
+
+    /// Marks the tool call as failed with the given message.
+    pub fn error(&mut self, message: impl Into<String>) {
+        self.output = Err(Error::ToolCallError(message.into()));
+    }
+}
+
+/// Serde helpers for [`ToolResultInfo::output`].
+mod tool_output {
+    use super::ToolOutput;
+    use crate::core::messages::UserContentPart;
+    use crate::error::{Error, Result};
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    #[derive(Serialize, Deserialize)]
+    #[serde(rename_all = "snake_case")]
+    enum Output {
+        Ok(serde_json::Value),
+        Content(Vec<UserContentPart>),
+        Error(String),
+    }
+
+    pub fn serialize<S>(
+        output: &Result<ToolOutput>,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match output {
+            Ok(ToolOutput::Content(parts)) => Output::Content(parts.clone()),
+            Ok(output) => Output::Ok(output.to_value()),
+            Err(Error::ToolCallError(message)) => Output::Error(message.clone()),
+            Err(error) => Output::Error(String::from(error.clone())),
+        }
+        .serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> std::result::Result<Result<ToolOutput>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Ok(match Output::deserialize(deserializer)? {
+            Output::Ok(value) => Ok(value.into()),
+            Output::Content(parts) => Ok(ToolOutput::Content(parts)),
+            Output::Error(message) => Err(Error::ToolCallError(message)),
+        })
     }
 }
@@ -0,0 +1,202 @@
+//! Transcription model
+//!
+//! Defines the [`TranscriptionModel`] trait implemented by providers that transcribe
+//! speech to text, together with its options and response types.
+
+/// Transcription request builder and related types.
+#[cfg(feature = "transcription-model-request")]
+pub mod request;
+
+use crate::core::messages::{DataContent, MediaPart};
+use crate::error::{Error, Result};
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+#[cfg(feature = "transcription-model-request")]
+pub use request::TranscriptionModelRequest;
+
+/// The level of detail of the timestamps in a transcription.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TimestampGranularity {
+    /// Timestamps for each segment of the transcription.
+    Segment,
+    /// Timestamps for each word of the transcription.
+    Word,
+}
+
+impl TimestampGranularity {
+    /// Returns the name of the granularity, e.g. `segment`.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Segment => "segment",
+            Self::Word => "word",
+        }
+    }
+}
+
+/// The options for transcription requests.
+#[derive(Debug, Clone)]
+pub struct TranscriptionModelOptions {
+    /// The audio to transcribe. Its file name is sent to providers that detect the
+    /// audio format from the extension.
+    pub audio: MediaPart,
+    /// The language of the audio as an ISO-639-1 code, e.g. `en`.
+    pub language: Option<String>,
+    /// Text to guide the style of the transcription or continue a previous segment.
+    pub prompt: Option<String>,
+    /// The timestamp granularities to include in the response.
+    pub timestamp_granularities: Vec<TimestampGranularity>,
+}
+
+impl TranscriptionModelOptions {
+    /// Creates options for transcribing the given audio.
+    pub fn new(audio: MediaPart) -> Self {
+        Self {
+            audio,
+            language: None,
+            prompt: None,
+            timestamp_granularities: Vec::new(),
+        }
+    }
+
+    /// Creates options for transcribing an audio file, guessing its media type from the
+    /// file extension.
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Self> {
+        Ok(Self::new(read_audio_file(path)?))
+    }
+
+    /// Returns the audio bytes and a file name for uploading them.
+    ///
+    /// Returns an error for audio given as a URL, which cannot be uploaded.
+    pub fn audio_file(&self) -> Result<(String, Vec<u8>)> {
+        let data = self.audio.data.to_bytes().ok_or_else(|| {
+            Error::InvalidInput("Transcription audio must be inline data, not a URL".into())
+        })?;
+        let filename = self
+            .audio
+            .filename
+            .clone()
+            .unwrap_or_else(|| format!("audio.{}", audio_extension(&self.audio.media_type)));
+        Ok((filename, data))
+    }
+}
+
+/// The core trait abstracting the capabilities of a transcription model.
+#[async_trait]
+pub trait TranscriptionModel: Clone + Send + Sync + std::fmt::Debug + 'static {
+    /// Transcribes the audio in the options to text.
+    async fn transcribe(
+        &self,
+        options: TranscriptionModelOptions,
+    ) -> Result<TranscriptionModelResponse>;
+}
+
+/// The response type for transcription requests.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct TranscriptionModelResponse {
+    /// The transcribed text.
+    pub text: String,
+    /// The segments of the transcription with their timestamps, if returned by the provider.
+    pub segments: Vec<TranscriptionSegment>,
+    /// The words of the transcription with their timestamps, if requested and supported.
+    pub words: Vec<TranscriptionSegment>,
+    /// The detected language of the audio, if returned by the provider.
+    pub language: Option<String>,
+    /// The duration of the audio in seconds, if returned by the provider.
+    pub duration: Option<f64>,
+}
+
+/// A part of a transcription with its position in the audio.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct TranscriptionSegment {
+    /// The transcribed text of the segment.
+    pub text: String,
+    /// The start of the segment in seconds.
+    pub start: f64,
+    /// The end of the segment in seconds.
+    pub end: f64,
+}
+
+/// Reads an audio file into a [`MediaPart`], guessing its media type from the extension.
+fn read_audio_file(path: impl AsRef<Path>) -> Result<MediaPart> {
+    let path = path.as_ref();
+    let data = std::fs::read(path).map_err(|e| {
+        Error::InvalidInput(format!("Failed to read audio file {}: {e}", path.display()))
+    })?;
+
+    let extension = path
+        .extension()
+        .and_then(|ext| ext.to_str())
+        .unwrap_or_default()
+        .to_lowercase();
+    let media_type = match extension.as_str() {
+        "mp3" | "mpga" | "mpeg" => "audio/mpeg",
+        "mp4" | "m4a" => "audio/mp4",
+        "wav" => "audio/wav",
+        "webm" => "audio/webm",
+        "ogg" | "oga" => "audio/ogg",
+        "flac" => "audio/flac",
+        _ => "application/octet-stream",
+    };
+
+    let mut audio = MediaPart::new(DataContent::Bytes(data), media_type);
+    if let Some(filename) = path.file_name().and_then(|name| name.to_str()) {
+        audio = audio.with_filename(filename);
+    }
+    Ok(audio)
+}
+
+/// Returns a file extension for an audio media type.
+fn audio_extension(media_type: &str) -> &'static str {
+    match media_type {
+        "audio/wav" | "audio/x-wav" | "audio/wave" => "wav",
+        "audio/mp4" | "audio/m4a" | "audio/x-m4a" => "m4a",
+        "audio/webm" => "webm",
+        "audio/ogg" => "ogg",
+        "audio/flac" | "audio/x-flac" => "flac",
+        _ => "mp3",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_audio_file_names() {
+        let options = TranscriptionModelOptions::new(MediaPart::new(vec![1, 2, 3], "audio/wav"));
+        assert_eq!(
+            options.audio_file().unwrap(),
+            ("audio.wav".to_string(), vec![1, 2, 3])
+        );
+
+        let options = TranscriptionModelOptions::new(
+            MediaPart::new(vec![1], "audio/mpeg").with_filename("call.mp3"),
+        );
+        assert_eq!(options.audio_file().unwrap().0, "call.mp3");
+
+        let options = TranscriptionModelOptions::new(MediaPart::new(
+            DataContent::url("https://example.com/call.mp3"),
+            "audio/mpeg",
+        ));
+        assert!(matches!(options.audio_file(), Err(Error::InvalidInput(_))));
+    }
+
+    #[test]
+    fn test_read_audio_file() {
+        let path = std::env::temp_dir().join(format!("aisdk-{}.wav", uuid::Uuid::new_v4()));
+        std::fs::write(&path, b"RIFF").unwrap();
+
+        let audio = read_audio_file(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(audio.data, DataContent::Bytes(b"RIFF".to_vec()));
+        assert_eq!(audio.media_type, "audio/wav");
+        assert_eq!(
+            audio.filename.as_deref(),
+            path.file_name().unwrap().to_str()
+        );
+    }
+}
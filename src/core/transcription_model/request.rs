@@ -0,0 +1,266 @@
+use std::ops::{Deref, DerefMut};
+use std::path::Path;
+
+use crate::core::messages::{DataContent, MediaPart};
+use crate::core::transcription_model::{
+    TimestampGranularity, TranscriptionModel, TranscriptionModelOptions, TranscriptionModelResponse,
+};
+use crate::error::Result;
+
+/// Options for transcription requests to be used by `transcribe`.
+#[derive(Debug, Clone)]
+pub struct TranscriptionModelRequest<M: TranscriptionModel> {
+    /// The transcription model to use for transcribing audio.
+    pub model: M,
+    /// Audio and configuration options for the transcription model request.
+    pub(crate) options: TranscriptionModelOptions,
+}
+
+impl<M: TranscriptionModel> TranscriptionModelRequest<M> {
+    /// Creates a new builder for constructing a `TranscriptionModelRequest`.
+    ///
+    /// This method initiates the type-state builder pattern, starting with the
+    /// [`ModelStage`] where you must specify the transcription model.
+    pub fn builder() -> TranscriptionModelRequestBuilder<M> {
+        TranscriptionModelRequestBuilder::default()
+    }
+
+    /// Transcribes the audio to text.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing the transcribed text with its segments, detected language
+    /// and duration, or an error if the request fails.
+    pub async fn transcribe(&self) -> Result<TranscriptionModelResponse> {
+        self.model.transcribe(self.options.clone()).await
+    }
+}
+
+impl<M: TranscriptionModel> Deref for TranscriptionModelRequest<M> {
+    type Target = TranscriptionModelOptions;
+
+    fn deref(&self) -> &Self::Target {
+        &self.options
+    }
+}
+
+impl<M: TranscriptionModel> DerefMut for TranscriptionModelRequest<M> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.options
+    }
+}
+
+/// Type-state markers for the `TranscriptionModelRequestBuilder`.
+///
+/// These zero-sized types ensure the builder is used in the correct order,
+/// preventing invalid request configurations at compile time.
+///
+/// The initial builder state where the transcription model must be set.
+///
+/// Transitions to [`AudioStage`] after calling [`model`](TranscriptionModelRequestBuilder::model).
+pub struct ModelStage {}
+
+/// The state where the audio must be set.
+///
+/// Transitions to [`OptionsStage`] after calling [`audio`](TranscriptionModelRequestBuilder::audio)
+/// or [`file`](TranscriptionModelRequestBuilder::file).
+pub struct AudioStage {}
+
+/// The final state where the transcription options can be configured before building.
+///
+/// Transitions to the completed `TranscriptionModelRequest` after calling [`build`](TranscriptionModelRequestBuilder::build).
+pub struct OptionsStage {}
+
+/// A type-state builder for constructing `TranscriptionModelRequest` instances.
+///
+/// This builder uses phantom types to enforce a specific construction order,
+/// ensuring that required fields (the model and the audio) are set before optional ones.
+///
+/// # Type Parameters
+///
+/// * `M` - The transcription model type.
+/// * `State` - The current builder state, determining available methods.
+pub struct TranscriptionModelRequestBuilder<M: TranscriptionModel, State = ModelStage> {
+    model: Option<M>,
+    options: TranscriptionModelOptions,
+    state: std::marker::PhantomData<State>,
+}
+
+impl<M: TranscriptionModel, State> Deref for TranscriptionModelRequestBuilder<M, State> {
+    type Target = TranscriptionModelOptions;
+
+    /// Dereferences to the underlying `TranscriptionModelOptions`.
+    ///
+    /// This allows direct access to the options fields during building.
+    fn deref(&self) -> &Self::Target {
+        &self.options
+    }
+}
+
+impl<M: TranscriptionModel, State> DerefMut for TranscriptionModelRequestBuilder<M, State> {
+    /// Mutably dereferences to the underlying `TranscriptionModelOptions`.
+    ///
+    /// This allows direct mutation of the options fields during building.
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.options
+    }
+}
+
+impl<M: TranscriptionModel> TranscriptionModelRequestBuilder<M> {
+    fn default() -> Self {
+        TranscriptionModelRequestBuilder {
+            model: None,
+            options: TranscriptionModelOptions::new(MediaPart::new(Vec::<u8>::new(), "")),
+            state: std::marker::PhantomData,
+        }
+    }
+}
+
+impl<M: TranscriptionModel, State> TranscriptionModelRequestBuilder<M, State> {
+    fn into_state<Next>(self) -> TranscriptionModelRequestBuilder<M, Next> {
+        TranscriptionModelRequestBuilder {
+            model: self.model,
+            options: self.options,
+            state: std::marker::PhantomData,
+        }
+    }
+}
+
+/// Methods available in the [`ModelStage`] state.
+impl<M: TranscriptionModel> TranscriptionModelRequestBuilder<M, ModelStage> {
+    /// Sets the transcription model for the request.
+    ///
+    /// This is the first required step in building a request.
+    ///
+    /// # Parameters
+    ///
+    /// * `model` - The transcription model instance to use.
+    ///
+    /// # Returns
+    ///
+    /// The builder in the [`AudioStage`] state.
+    pub fn model(mut self, model: M) -> TranscriptionModelRequestBuilder<M, AudioStage> {
+        self.model = Some(model);
+        self.into_state()
+    }
+}
+
+/// Methods available in the [`AudioStage`] state.
+impl<M: TranscriptionModel> TranscriptionModelRequestBuilder<M, AudioStage> {
+    /// Sets the audio to transcribe.
+    ///
+    /// # Parameters
+    ///
+    /// * `data` - The audio bytes or base64 encoded audio.
+    /// * `media_type` - The IANA media type of the audio, e.g. `audio/mpeg`.
+    ///
+    /// # Returns
+    ///
+    /// The builder in the [`OptionsStage`] state.
+    pub fn audio(
+        mut self,
+        data: impl Into<DataContent>,
+        media_type: impl Into<String>,
+    ) -> TranscriptionModelRequestBuilder<M, OptionsStage> {
+        self.options.audio = MediaPart::new(data, media_type);
+        self.into_state()
+    }
+
+    /// Reads the audio to transcribe from a file.
+    ///
+    /// The media type is guessed from the file extension.
+    ///
+    /// # Parameters
+    ///
+    /// * `path` - The path of the audio file.
+    ///
+    /// # Returns
+    ///
+    /// The builder in the [`OptionsStage`] state, or an error if the file cannot be read.
+    pub fn file(
+        mut self,
+        path: impl AsRef<Path>,
+    ) -> Result<TranscriptionModelRequestBuilder<M, OptionsStage>> {
+        self.options.audio = TranscriptionModelOptions::from_file(path)?.audio;
+        Ok(self.into_state())
+    }
+}
+
+/// Methods available in the [`OptionsStage`] state.
+impl<M: TranscriptionModel> TranscriptionModelRequestBuilder<M, OptionsStage> {
+    /// Sets the file name of the audio, used by providers to detect the audio format.
+    ///
+    /// # Parameters
+    ///
+    /// * `filename` - The file name including its extension, e.g. `call.mp3`.
+    ///
+    /// # Returns
+    ///
+    /// The builder with the file name set.
+    pub fn filename(mut self, filename: impl Into<String>) -> Self {
+        self.options.audio.filename = Some(filename.into());
+        self
+    }
+
+    /// Sets the language of the audio.
+    ///
+    /// # Parameters
+    ///
+    /// * `language` - The ISO-639-1 language code, e.g. `en`.
+    ///
+    /// # Returns
+    ///
+    /// The builder with the language set.
+    pub fn language(mut self, language: impl Into<String>) -> Self {
+        self.options.language = Some(language.into());
+        self
+    }
+
+    /// Sets a prompt guiding the style of the transcription.
+    ///
+    /// # Parameters
+    ///
+    /// * `prompt` - The prompt text.
+    ///
+    /// # Returns
+    ///
+    /// The builder with the prompt set.
+    pub fn prompt(mut self, prompt: impl Into<String>) -> Self {
+        self.options.prompt = Some(prompt.into());
+        self
+    }
+
+    /// Adds a timestamp granularity to include in the response.
+    ///
+    /// # Parameters
+    ///
+    /// * `granularity` - The [`TimestampGranularity`] to include.
+    ///
+    /// # Returns
+    ///
+    /// The builder with the granularity added.
+    pub fn timestamp_granularity(mut self, granularity: TimestampGranularity) -> Self {
+        if !self.options.timestamp_granularities.contains(&granularity) {
+            self.options.timestamp_granularities.push(granularity);
+        }
+        self
+    }
+
+    /// Builds the `TranscriptionModelRequest`.
+    ///
+    /// This method consumes the builder and returns the configured request.
+    ///
+    /// # Returns
+    ///
+    /// The constructed `TranscriptionModelRequest`.
+    pub fn build(self) -> TranscriptionModelRequest<M> {
+        let model = self
+            .model
+            .unwrap_or_else(|| unreachable!("Model must be set"));
+
+        TranscriptionModelRequest {
+            model,
+            options: self.options,
+        }
+    }
+}
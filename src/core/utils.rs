@@ -99,6 +99,13 @@ pub(crate) fn sum_options(a: Option<usize>, b: Option<usize>) -> Option<usize> {
     }
 }
 
+/// 64-bit FNV-1a hash, stable across platforms and Rust versions.
+pub(crate) fn fnv1a(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf29ce484222325, |hash, byte| {
+        (hash ^ u64::from(*byte)).wrapping_mul(0x100000001b3)
+    })
+}
+
 #[allow(dead_code)]
 /// Validates the base URL.
 pub(crate) fn validate_base_url(s: &str) -> crate::error::Result<String> {
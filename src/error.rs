@@ -55,6 +55,15 @@ pub enum Error {
     #[error("Tool error: {0}")]
     ToolCallError(String),
 
+    /// The model output does not match the requested object schema.
+    #[error("Invalid object: {details}")]
+    InvalidObject {
+        /// The parsing or validation error.
+        details: String,
+        /// The text generated by the model.
+        text: String,
+    },
+
     /// An error related to prompt template processing and rendering.
     #[error("Prompt error: {0}")]
     PromptError(String),
@@ -88,6 +97,7 @@ impl From<Error> for String {
             }
             Error::InvalidInput(error) => format!("Invalid input: {error}"),
             Error::ToolCallError(error) => format!("Tool error: {error}"),
+            Error::InvalidObject { details, .. } => format!("Invalid object: {details}"),
             Error::Other(error) => format!("Other error: {error}"),
             Error::ProviderError(error) => format!("Provider error: {error}"),
             Error::PromptError(error) => format!("Prompt error: {error}"),
@@ -1,17 +1,39 @@
 //! Extensions are a way to extend the capabilities of `aisdk`.
 //! They are used to attach provider-specific information to core SDK structures without polluting the core API.
+//!
+//! Values that must survive serialization (for example signatures a provider expects
+//! to receive back on the next turn) implement [`PersistedExtension`] and are accessed
+//! through [`Extensions::get_persisted`] and [`Extensions::get_persisted_mut`].
 
 use parking_lot::{
     MappedRwLockReadGuard, MappedRwLockWriteGuard, RwLock, RwLockReadGuard, RwLockWriteGuard,
 };
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde_json::Value;
 use std::any::{Any, TypeId};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::sync::Arc;
 
+/// An extension value that is kept when the owning structure is serialized.
+pub trait PersistedExtension:
+    Serialize + DeserializeOwned + Default + Send + Sync + 'static
+{
+    /// The stable key used for the value in the serialized form.
+    const KEY: &'static str;
+}
+
+/// Converts a stored persisted extension into its JSON form.
+type ToJsonFn = fn(&(dyn Any + Send + Sync)) -> Option<Value>;
+
 /// Extensions are a type-safe container for storing arbitrary metadata.
 #[derive(Default, Clone)]
 pub struct Extensions {
     map: Arc<RwLock<HashMap<TypeId, Box<dyn Any + Send + Sync>>>>,
+    /// Serializers for the persisted extension types present in `map`.
+    persisted: Arc<RwLock<HashMap<TypeId, (&'static str, ToJsonFn)>>>,
+    /// Deserialized values that have not been accessed as a typed value yet.
+    raw: Arc<RwLock<BTreeMap<String, Value>>>,
 }
 
 impl Extensions {
@@ -30,6 +52,14 @@ impl Extensions {
         })
     }
 
+    /// Gets a value from the extensions map, if one of the type was inserted.
+    pub fn try_get<T: Send + Sync + 'static>(&self) -> Option<MappedRwLockReadGuard<'_, T>> {
+        RwLockReadGuard::try_map(self.map.read(), |m| {
+            m.get(&TypeId::of::<T>()).and_then(|b| b.downcast_ref())
+        })
+        .ok()
+    }
+
     /// Gets a mutable value from the extensions map.
     pub fn get_mut<T: Send + Sync + Default + 'static>(&self) -> MappedRwLockWriteGuard<'_, T> {
         self.ensure::<T>();
@@ -40,12 +70,52 @@ impl Extensions {
         })
     }
 
+    /// Gets a persisted value from the extensions map.
+    ///
+    /// Values restored from a serialized form are decoded on first access.
+    pub fn get_persisted<T: PersistedExtension>(&self) -> MappedRwLockReadGuard<'_, T> {
+        self.ensure_persisted::<T>();
+        self.get::<T>()
+    }
+
+    /// Gets a mutable persisted value from the extensions map.
+    pub fn get_persisted_mut<T: PersistedExtension>(&self) -> MappedRwLockWriteGuard<'_, T> {
+        self.ensure_persisted::<T>();
+        self.get_mut::<T>()
+    }
+
+    /// Returns `true` if there are no persisted values to serialize.
+    pub fn is_empty(&self) -> bool {
+        self.persisted.read().is_empty() && self.raw.read().is_empty()
+    }
+
     /// Ensures that a value of the given type is present in the extensions map.
     fn ensure<T: Default + Send + Sync + 'static>(&self) {
         if self.map.read().get(&TypeId::of::<T>()).is_none() {
             self.insert(T::default());
         }
     }
+
+    /// Registers a persisted type and restores its value from the raw store if needed.
+    fn ensure_persisted<T: PersistedExtension>(&self) {
+        let type_id = TypeId::of::<T>();
+        if self.persisted.read().contains_key(&type_id) {
+            return;
+        }
+
+        if let Some(value) = self.raw.write().remove(T::KEY)
+            && let Ok(value) = serde_json::from_value::<T>(value)
+        {
+            self.insert(value);
+        }
+
+        let to_json: ToJsonFn = |value| {
+            value
+                .downcast_ref::<T>()
+                .and_then(|v| serde_json::to_value(v).ok())
+        };
+        self.persisted.write().insert(type_id, (T::KEY, to_json));
+    }
 }
 
 impl std::fmt::Debug for Extensions {
@@ -53,3 +123,32 @@ impl std::fmt::Debug for Extensions {
         f.debug_struct("Extensions").finish()
     }
 }
+
+impl Serialize for Extensions {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut out = self.raw.read().clone();
+        let map = self.map.read();
+        for (type_id, (key, to_json)) in self.persisted.read().iter() {
+            if let Some(value) = map.get(type_id).and_then(|v| to_json(v.as_ref())) {
+                out.insert(key.to_string(), value);
+            }
+        }
+        out.serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Extensions {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let raw = BTreeMap::<String, Value>::deserialize(deserializer)?;
+        Ok(Self {
+            raw: Arc::new(RwLock::new(raw)),
+            ..Default::default()
+        })
+    }
+}
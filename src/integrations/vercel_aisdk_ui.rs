@@ -114,6 +114,56 @@ pub enum VercelUIStream {
         #[serde(skip_serializing_if = "Option::is_none")]
         provider_metadata: Option<Value>,
     },
+    /// Start of a tool call input
+    #[serde(rename = "tool-input-start", rename_all = "camelCase")]
+    ToolInputStart {
+        /// Tool call ID
+        tool_call_id: String,
+        /// Tool name
+        tool_name: String,
+    },
+    /// Delta of a tool call input
+    #[serde(rename = "tool-input-delta", rename_all = "camelCase")]
+    ToolInputDelta {
+        /// Tool call ID
+        tool_call_id: String,
+        /// JSON input delta
+        input_text_delta: String,
+    },
+    /// Complete tool call input
+    #[serde(rename = "tool-input-available", rename_all = "camelCase")]
+    ToolInputAvailable {
+        /// Tool call ID
+        tool_call_id: String,
+        /// Tool name
+        tool_name: String,
+        /// Tool input
+        input: Value,
+    },
+    /// Tool call waiting for an approval
+    #[serde(rename = "tool-approval-request", rename_all = "camelCase")]
+    ToolApprovalRequest {
+        /// Approval ID, the tool call ID is used
+        approval_id: String,
+        /// Tool call ID
+        tool_call_id: String,
+    },
+    /// Result of a tool call
+    #[serde(rename = "tool-output-available", rename_all = "camelCase")]
+    ToolOutputAvailable {
+        /// Tool call ID
+        tool_call_id: String,
+        /// Tool output
+        output: Value,
+    },
+    /// Failed tool call
+    #[serde(rename = "tool-output-error", rename_all = "camelCase")]
+    ToolOutputError {
+        /// Tool call ID
+        tool_call_id: String,
+        /// Error text
+        error_text: String,
+    },
     /// Error chunk
     #[serde(rename = "error")]
     Error {
@@ -262,13 +312,45 @@ impl crate::core::StreamTextResponse {
                     })
                 }
 
-                LanguageModelStreamChunkType::ToolCall(_json_str) => {
-                    //TODO: handle tool call streams when they are supported
-                    Some(VercelUIStream::ToolCallStart {
-                        id: message_id.clone(),
-                        tool_call_id: "unknown".to_string(),
-                        tool_name: "unknown".to_string(),
-                        provider_metadata: None,
+                LanguageModelStreamChunkType::ToolInputStart { id, name } => {
+                    Some(VercelUIStream::ToolInputStart {
+                        tool_call_id: id,
+                        tool_name: name,
+                    })
+                }
+
+                LanguageModelStreamChunkType::ToolInputDelta { id, delta } => {
+                    Some(VercelUIStream::ToolInputDelta {
+                        tool_call_id: id,
+                        input_text_delta: delta,
+                    })
+                }
+
+                LanguageModelStreamChunkType::ToolInputAvailable(call) => {
+                    Some(VercelUIStream::ToolInputAvailable {
+                        tool_call_id: call.tool.id,
+                        tool_name: call.tool.name,
+                        input: call.input,
+                    })
+                }
+
+                LanguageModelStreamChunkType::ToolApprovalRequest(call) => {
+                    Some(VercelUIStream::ToolApprovalRequest {
+                        approval_id: call.tool.id.clone(),
+                        tool_call_id: call.tool.id,
+                    })
+                }
+
+                LanguageModelStreamChunkType::ToolOutputAvailable(result) => {
+                    Some(match result.output {
+                        Ok(output) => VercelUIStream::ToolOutputAvailable {
+                            tool_call_id: result.tool.id,
+                            output: output.to_value(),
+                        },
+                        Err(error) => VercelUIStream::ToolOutputError {
+                            tool_call_id: result.tool.id,
+                            error_text: error.to_string(),
+                        },
                     })
                 }
 
@@ -42,6 +42,9 @@ pub(crate) struct AnthropicOptions {
     pub tools: Option<Vec<AnthropicTool>>,
     #[builder(default)]
     #[serde(skip_serializing_if = "Option::is_none")]
+    pub tool_choice: Option<AnthropicToolChoice>,
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub top_k: Option<u32>,
     #[builder(default)]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -69,6 +72,10 @@ impl<M: ModelName> LanguageModelClient for Anthropic<M> {
         reqwest::Method::POST
     }
 
+    fn http_client(&self) -> reqwest::Client {
+        self.settings.http_client.clone()
+    }
+
     fn headers(&self) -> reqwest::header::HeaderMap {
         // Default headers
         let mut default_headers = reqwest::header::HeaderMap::new();
@@ -178,13 +178,48 @@ pub enum AnthropicUserMessageContentBlock {
         /// The text content
         text: String,
     },
+    #[serde(rename = "image")]
+    /// Image content
+    Image {
+        /// The source of the image
+        source: AnthropicMediaSource,
+    },
+    #[serde(rename = "document")]
+    /// Document content such as a PDF
+    Document {
+        /// The source of the document
+        source: AnthropicMediaSource,
+    },
     #[serde(rename = "tool_result")]
     /// Tool result content
     ToolResult {
         /// The ID of the tool used
         tool_use_id: String,
         /// The content of the tool result
-        content: String,
+        content: AnthropicUserMessageContent,
+        /// Whether the tool call failed
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        is_error: Option<bool>,
+    },
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type")]
+/// See more [here](https://platform.claude.com/docs/en/build-with-claude/vision)
+pub enum AnthropicMediaSource {
+    #[serde(rename = "base64")]
+    /// Base64 encoded data
+    Base64 {
+        /// The media type of the data
+        media_type: String,
+        /// The base64 encoded data
+        data: String,
+    },
+    #[serde(rename = "url")]
+    /// Data referenced by URL
+    Url {
+        /// The URL of the data
+        url: String,
     },
 }
 
@@ -210,6 +245,16 @@ pub(crate) struct AnthropicTool {
     pub input_schema: serde_json::Value,
 }
 
+/// See more [here](https://platform.claude.com/docs/en/agents-and-tools/tool-use/implement-tool-use#forcing-tool-use)
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub(crate) enum AnthropicToolChoice {
+    Auto,
+    Any,
+    Tool { name: String },
+    None,
+}
+
 #[derive(Debug, Default, Clone, Serialize, Deserialize)]
 #[serde(tag = "type")]
 pub(crate) enum AnthropicThinking {
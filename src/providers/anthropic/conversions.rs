@@ -1,10 +1,13 @@
 use crate::core::Message;
 use crate::core::language_model::{
-    LanguageModelOptions, LanguageModelResponseContentType, ReasoningEffort, Usage,
+    LanguageModelOptions, LanguageModelResponseContentType, ReasoningEffort, ToolChoice, Usage,
 };
+use crate::core::messages::{MediaPart, UserContentPart, UserMessage};
+use crate::core::tools::ToolOutput;
 use crate::providers::anthropic::client::{
-    AnthropicAssistantMessageParamContent, AnthropicMessageDeltaUsage, AnthropicMessageParam,
-    AnthropicOptions, AnthropicThinking, AnthropicTool, AnthropicUsage,
+    AnthropicAssistantMessageParamContent, AnthropicMediaSource, AnthropicMessageDeltaUsage,
+    AnthropicMessageParam, AnthropicOptions, AnthropicThinking, AnthropicTool, AnthropicToolChoice,
+    AnthropicUsage, AnthropicUserMessageContent, AnthropicUserMessageContentBlock,
 };
 use crate::providers::anthropic::extensions;
 
@@ -35,12 +38,7 @@ impl From<LanguageModelOptions> for AnthropicOptions {
                     }
                 }
                 Message::User(u) => {
-                    messages.push(AnthropicMessageParam::User {
-                        content:
-                            crate::providers::anthropic::client::AnthropicUserMessageContent::Text(
-                                u.content,
-                            ),
-                    });
+                    messages.push(AnthropicMessageParam::User { content: u.into() });
                 }
                 Message::Assistant(a) => match a.content {
                     LanguageModelResponseContentType::Text(text) => {
@@ -63,7 +61,7 @@ impl From<LanguageModelOptions> for AnthropicOptions {
                     } => {
                         // Retrieve Anthropic-specific signature from extensions
                         let signature = extensions
-                            .get::<extensions::AnthropicThinkingMetadata>()
+                            .get_persisted::<extensions::AnthropicThinkingMetadata>()
                             .signature
                             .clone()
                             .unwrap_or_else(|| content.clone());
@@ -82,7 +80,14 @@ impl From<LanguageModelOptions> for AnthropicOptions {
                         content: crate::providers::anthropic::client::AnthropicUserMessageContent::Blocks(vec![
                             crate::providers::anthropic::client::AnthropicUserMessageContentBlock::ToolResult {
                                 tool_use_id: tool.tool.id,
-                                content: tool.output.unwrap_or_default().to_string(),
+                                is_error: tool.output.is_err().then_some(true),
+                                content: match tool.output {
+                                    Ok(ToolOutput::Content(parts)) => {
+                                        AnthropicUserMessageContent::Blocks(content_blocks(parts))
+                                    }
+                                    Ok(output) => AnthropicUserMessageContent::Text(output.to_text()),
+                                    Err(error) => AnthropicUserMessageContent::Text(error.to_string()),
+                                },
                             },
                         ]),
                     });
@@ -100,6 +105,16 @@ impl From<LanguageModelOptions> for AnthropicOptions {
         // update messages
         request.messages(messages);
 
+        // convert tool choice to anthropic tool choice
+        if options.tools.is_some() {
+            request.tool_choice(options.tool_choice.map(|choice| match choice {
+                ToolChoice::Auto => AnthropicToolChoice::Auto,
+                ToolChoice::None => AnthropicToolChoice::None,
+                ToolChoice::Required => AnthropicToolChoice::Any,
+                ToolChoice::Tool(name) => AnthropicToolChoice::Tool { name },
+            }));
+        }
+
         // convert tools to anthropic tools
         if let Some(tools) = options.tools {
             request.tools(Some(
@@ -144,6 +159,49 @@ impl From<LanguageModelOptions> for AnthropicOptions {
     }
 }
 
+impl From<UserMessage> for AnthropicUserMessageContent {
+    fn from(user: UserMessage) -> Self {
+        // plain text messages keep the simple string form
+        if user.parts.is_empty() {
+            return AnthropicUserMessageContent::Text(user.content);
+        }
+
+        AnthropicUserMessageContent::Blocks(content_blocks(user.into_parts()))
+    }
+}
+
+/// Converts user content parts, e.g. of a message or a tool result, to content blocks.
+fn content_blocks(parts: Vec<UserContentPart>) -> Vec<AnthropicUserMessageContentBlock> {
+    parts
+        .into_iter()
+        .filter_map(|part| match part {
+            UserContentPart::Text(text) => Some(AnthropicUserMessageContentBlock::Text { text }),
+            UserContentPart::Image(image) => Some(AnthropicUserMessageContentBlock::Image {
+                source: image.into(),
+            }),
+            UserContentPart::File(file) => Some(AnthropicUserMessageContentBlock::Document {
+                source: file.into(),
+            }),
+            UserContentPart::Audio(_) | UserContentPart::Video(_) => {
+                log::warn!("Anthropic does not support audio or video input parts");
+                None
+            }
+        })
+        .collect()
+}
+
+impl From<MediaPart> for AnthropicMediaSource {
+    fn from(part: MediaPart) -> Self {
+        match part.data.to_base64() {
+            Some(data) => AnthropicMediaSource::Base64 {
+                media_type: part.media_type,
+                data,
+            },
+            None => AnthropicMediaSource::Url { url: part.to_url() },
+        }
+    }
+}
+
 impl From<AnthropicUsage> for Usage {
     fn from(usage: AnthropicUsage) -> Self {
         Self {
@@ -171,3 +229,75 @@ impl From<AnthropicMessageDeltaUsage> for Usage {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::messages::{DataContent, TaggedMessage};
+    use crate::core::tools::ToolResultInfo;
+
+    fn tool_result_json(result: ToolResultInfo) -> serde_json::Value {
+        let options = LanguageModelOptions {
+            messages: vec![TaggedMessage::new(1, Message::Tool(result))],
+            ..Default::default()
+        };
+        let request = serde_json::to_value(AnthropicOptions::from(options)).unwrap();
+        request["messages"][0]["content"][0].clone()
+    }
+
+    #[test]
+    fn test_tool_result_conversion() {
+        let mut result = ToolResultInfo::new("lookup");
+        result.id("toolu_1");
+        result.output("found");
+        assert_eq!(
+            tool_result_json(result.clone()),
+            serde_json::json!({
+                "type": "tool_result",
+                "tool_use_id": "toolu_1",
+                "content": "found"
+            })
+        );
+
+        result.error("not found");
+        let json = tool_result_json(result.clone());
+        assert_eq!(json["is_error"], true);
+        assert_eq!(json["content"], "Tool error: not found");
+
+        result.output(vec![
+            UserContentPart::Text("chart".to_string()),
+            UserContentPart::image(DataContent::base64("AQID"), "image/png"),
+        ]);
+        let json = tool_result_json(result);
+        assert_eq!(
+            json["content"][0],
+            serde_json::json!({"type": "text", "text": "chart"})
+        );
+        assert_eq!(json["content"][1]["type"], "image");
+        assert_eq!(json["content"][1]["source"]["data"], "AQID");
+    }
+    #[test]
+    fn test_data_url_is_sent_inline() {
+        let source = AnthropicMediaSource::from(MediaPart::new(
+            DataContent::url("data:application/pdf;base64,JVBERg=="),
+            "application/pdf",
+        ));
+        assert_eq!(
+            serde_json::to_value(source).unwrap(),
+            serde_json::json!({
+                "type": "base64",
+                "media_type": "application/pdf",
+                "data": "JVBERg=="
+            })
+        );
+
+        let source = AnthropicMediaSource::from(MediaPart::new(
+            DataContent::url("https://example.com/doc.pdf"),
+            "application/pdf",
+        ));
+        assert_eq!(
+            serde_json::to_value(source).unwrap(),
+            serde_json::json!({"type": "url", "url": "https://example.com/doc.pdf"})
+        );
+    }
+}
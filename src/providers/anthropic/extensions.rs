@@ -1,7 +1,11 @@
 //! Anthropic-specific metadata for extensions.
 
+use serde::{Deserialize, Serialize};
+
+use crate::extensions::PersistedExtension;
+
 /// Metadata specific to Anthropic provider thinking functionality.
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub(crate) struct AnthropicThinkingMetadata {
     /// The signature returned by Anthropic models for thinking blocks.
     ///
@@ -9,3 +13,7 @@ pub(crate) struct AnthropicThinkingMetadata {
     /// for extended thinking to work correctly.
     pub signature: Option<String>,
 }
+
+impl PersistedExtension for AnthropicThinkingMetadata {
+    const KEY: &'static str = "anthropic.thinking";
+}
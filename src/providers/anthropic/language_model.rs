@@ -33,11 +33,14 @@ impl<M: ModelName> LanguageModel for Anthropic<M> {
         &mut self,
         options: LanguageModelOptions,
     ) -> Result<LanguageModelResponse> {
+        let retry_policy = self.settings.retry_policy.for_request(&options);
         let mut options: AnthropicOptions = options.into();
         options.model = self.options.model.clone();
         self.options = options;
 
-        let response = self.send(self.settings.base_url.clone()).await?;
+        let response = self
+            .send(self.settings.base_url.clone(), &retry_policy)
+            .await?;
 
         let mut collected: Vec<LanguageModelResponseContentType> = Vec::new();
 
@@ -52,7 +55,7 @@ impl<M: ModelName> LanguageModel for Anthropic<M> {
                 } => {
                     let extensions = Extensions::default();
                     extensions
-                        .get_mut::<extensions::AnthropicThinkingMetadata>()
+                        .get_persisted_mut::<extensions::AnthropicThinkingMetadata>()
                         .signature = Some(signature);
                     collected.push(LanguageModelResponseContentType::Reasoning {
                         content: thinking,
@@ -86,33 +89,15 @@ impl<M: ModelName> LanguageModel for Anthropic<M> {
 
     /// Streams text using the Anthropic provider.
     async fn stream_text(&mut self, options: LanguageModelOptions) -> Result<ProviderStream> {
+        let retry_policy = self.settings.retry_policy.for_request(&options);
         let mut options: AnthropicOptions = options.into();
         options.stream = Some(true);
         options.model = self.options.model.clone();
         self.options = options;
 
-        // Retry logic for rate limiting
-        let max_retries = 5;
-        let mut retry_count = 0;
-        let mut wait_time = std::time::Duration::from_secs(1);
-
-        let response = loop {
-            match self.send_and_stream(self.settings.base_url.clone()).await {
-                Ok(stream) => break stream,
-                Err(crate::error::Error::ApiError {
-                    status_code: Some(status),
-                    ..
-                }) if status == reqwest::StatusCode::TOO_MANY_REQUESTS
-                    && retry_count < max_retries =>
-                {
-                    retry_count += 1;
-                    tokio::time::sleep(wait_time).await;
-                    wait_time *= 2; // Exponential backoff
-                    continue;
-                }
-                Err(e) => return Err(e),
-            }
-        };
+        let response = self
+            .send_and_stream(self.settings.base_url.clone(), &retry_policy)
+            .await?;
 
         #[derive(Default)]
         struct StreamState {
@@ -184,12 +169,14 @@ impl<M: ModelName> LanguageModel for Anthropic<M> {
                                     state.content_blocks.insert(
                                         index,
                                         AccumulatedBlock::ToolUse {
-                                            id,
-                                            name,
+                                            id: id.clone(),
+                                            name: name.clone(),
                                             accumulated_json: String::new(),
                                         },
                                     );
-                                    Some(Ok(unsupported("ContentBlockStart::ToolUse")))
+                                    Some(Ok(vec![LanguageModelStreamChunk::Delta(
+                                        LanguageModelStreamChunkType::ToolInputStart { id, name },
+                                    )]))
                                 }
                             },
                             AnthropicStreamEvent::ContentBlockDelta { index, delta } => {
@@ -228,15 +215,16 @@ impl<M: ModelName> LanguageModel for Anthropic<M> {
                                         }
                                         (
                                             AccumulatedBlock::ToolUse {
-                                                accumulated_json, ..
+                                                id, accumulated_json, ..
                                             },
                                             AnthropicDelta::ToolUseDelta { partial_json },
                                         ) => {
                                             accumulated_json.push_str(&partial_json);
                                             Some(Ok(vec![LanguageModelStreamChunk::Delta(
-                                                LanguageModelStreamChunkType::ToolCall(
-                                                    partial_json,
-                                                ),
+                                                LanguageModelStreamChunkType::ToolInputDelta {
+                                                    id: id.clone(),
+                                                    delta: partial_json,
+                                                },
                                             )]))
                                         }
                                         _ => Some(Ok(unsupported("ContentBlockDelta"))),
@@ -253,10 +241,11 @@ impl<M: ModelName> LanguageModel for Anthropic<M> {
                                                 LanguageModelStreamChunkType::Reasoning(thinking),
                                             )]))
                                         }
+                                        // Without its content block the call id is unknown
                                         AnthropicDelta::ToolUseDelta { partial_json } => {
                                             Some(Ok(vec![LanguageModelStreamChunk::Delta(
-                                                LanguageModelStreamChunkType::ToolCall(
-                                                    partial_json,
+                                                LanguageModelStreamChunkType::NotSupported(
+                                                    format!("ToolUseDelta: {partial_json}"),
                                                 ),
                                             )]))
                                         }
@@ -298,7 +287,7 @@ impl<M: ModelName> LanguageModel for Anthropic<M> {
                                             let extensions = Extensions::default();
                                             if let Some(sig) = signature {
                                                 extensions
-                                                .get_mut::<extensions::AnthropicThinkingMetadata>()
+                                                .get_persisted_mut::<extensions::AnthropicThinkingMetadata>()
                                                 .signature = Some(sig.clone());
                                             }
                                             collected.push(
@@ -349,12 +338,20 @@ impl<M: ModelName> LanguageModel for Anthropic<M> {
                                         }
                                     }
                                 }
+                                // The usage covers the whole turn, so only the last message
+                                // carries it.
+                                let last = collected.len().saturating_sub(1);
                                 Some(Ok(collected
                                     .into_iter()
-                                    .map(|ref c| {
+                                    .enumerate()
+                                    .map(|(index, content)| {
                                         LanguageModelStreamChunk::Done(AssistantMessage {
-                                            content: c.clone(),
-                                            usage: state.usage.clone().map(|usage| usage.into()),
+                                            content,
+                                            usage: if index == last {
+                                                state.usage.clone().map(|usage| usage.into())
+                                            } else {
+                                                None
+                                            },
                                         })
                                     })
                                     .collect()))
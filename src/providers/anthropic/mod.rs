@@ -182,6 +182,38 @@ impl<M: ModelName> AnthropicBuilder<M> {
         self
     }
 
+    /// Sets the HTTP client used for requests.
+    ///
+    /// Use this to configure timeouts, proxies, root certificates or default headers,
+    /// e.g. with [`HttpClientConfig`](crate::core::client::HttpClientConfig). By default
+    /// all providers share one pooled client.
+    ///
+    /// # Parameters
+    ///
+    /// * `http_client` - The `reqwest::Client` to use.
+    ///
+    /// # Returns
+    ///
+    /// The builder with the HTTP client set.
+    pub fn http_client(mut self, http_client: reqwest::Client) -> Self {
+        self.settings.http_client = http_client;
+        self
+    }
+
+    /// Sets the policy for retrying failed requests.
+    ///
+    /// # Parameters
+    ///
+    /// * `retry_policy` - The [`RetryPolicy`](crate::core::client::RetryPolicy) to use.
+    ///
+    /// # Returns
+    ///
+    /// The builder with the retry policy set.
+    pub fn retry_policy(mut self, retry_policy: crate::core::client::RetryPolicy) -> Self {
+        self.settings.retry_policy = retry_policy;
+        self
+    }
+
     /// Builds the Anthropic provider.
     ///
     /// Validates the configuration and creates the provider instance.
@@ -19,6 +19,14 @@ pub struct AnthropicProviderSettings {
     /// Custom API path override. When set, this path is used instead of the
     /// default "/messages".
     pub path: Option<String>,
+
+    /// The HTTP client used for requests. Defaults to the client shared by all providers.
+    #[serde(skip, default = "crate::core::client::shared_http_client")]
+    pub http_client: reqwest::Client,
+
+    /// The policy for retrying failed requests.
+    #[serde(skip)]
+    pub retry_policy: crate::core::client::RetryPolicy,
 }
 
 impl Default for AnthropicProviderSettings {
@@ -29,6 +37,8 @@ impl Default for AnthropicProviderSettings {
             base_url: "https://api.anthropic.com/v1/".to_string(),
             api_key: std::env::var("ANTHROPIC_API_KEY").unwrap_or_default(),
             path: None,
+            http_client: crate::core::client::shared_http_client(),
+            retry_policy: crate::core::client::RetryPolicy::default(),
         }
     }
 }
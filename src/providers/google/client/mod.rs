@@ -1,5 +1,5 @@
 //! Client implementation for the Google provider.
-use crate::core::client::{EmbeddingClient, LanguageModelClient};
+use crate::core::client::{JsonClient, LanguageModelClient};
 use crate::error::{Error, Result};
 use crate::providers::google::{Google, ModelName};
 use derive_builder::Builder;
@@ -55,6 +55,10 @@ impl<M: ModelName> LanguageModelClient for Google<M> {
         reqwest::Method::POST
     }
 
+    fn http_client(&self) -> reqwest::Client {
+        self.settings.http_client.clone()
+    }
+
     fn headers(&self) -> reqwest::header::HeaderMap {
         let mut headers = reqwest::header::HeaderMap::new();
         headers.insert(CONTENT_TYPE, "application/json".parse().unwrap());
@@ -121,7 +125,7 @@ impl<M: ModelName> LanguageModelClient for Google<M> {
     }
 }
 
-impl<M: ModelName> EmbeddingClient for Google<M> {
+impl<M: ModelName> JsonClient for Google<M> {
     type Response = types::BatchEmbedContentsResponse;
 
     fn path(&self) -> String {
@@ -135,6 +139,10 @@ impl<M: ModelName> EmbeddingClient for Google<M> {
         reqwest::Method::POST
     }
 
+    fn http_client(&self) -> reqwest::Client {
+        self.settings.http_client.clone()
+    }
+
     fn headers(&self) -> reqwest::header::HeaderMap {
         let mut headers = reqwest::header::HeaderMap::new();
         headers.insert(CONTENT_TYPE, "application/json".parse().unwrap());
@@ -62,6 +62,7 @@ pub(crate) struct Blob {
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub(crate) struct FileData {
     pub(crate) mime_type: String,
     pub(crate) file_uri: String,
@@ -125,17 +126,20 @@ pub(crate) struct FunctionDeclaration {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub(crate) struct ToolConfig {
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub(crate) function_calling_config: Option<FunctionCallingConfig>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub(crate) struct FunctionCallingConfig {
     pub(crate) mode: FunctionCallingMode,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub(crate) allowed_function_names: Option<Vec<String>>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
-#[serde(rename_all = "UPPERCASE")]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub(crate) enum FunctionCallingMode {
     ModeUnspecified,
     Auto,
@@ -1,14 +1,15 @@
 //! Conversions between types used by the Google provider and the types used by the core library.
 use crate::core::embedding_model::EmbeddingModelOptions;
-use crate::core::language_model::{LanguageModelOptions, LanguageModelResponseContentType, Usage};
-use crate::core::messages::{Message, TaggedMessage};
-use crate::core::tools::Tool;
+use crate::core::language_model::{
+    LanguageModelOptions, LanguageModelResponseContentType, ToolChoice, Usage,
+};
+use crate::core::messages::{Message, TaggedMessage, UserContentPart};
+use crate::core::tools::{Tool, ToolOutput};
 use crate::providers::google::client::GoogleEmbeddingOptions;
 use crate::providers::google::client::types::{
     self, Content, FunctionDeclaration, GenerateContentRequest, Part, Role,
 };
 use crate::providers::google::extensions::GoogleToolMetadata;
-use serde_json::Value;
 
 impl From<Tool> for FunctionDeclaration {
     fn from(value: Tool) -> Self {
@@ -48,6 +49,8 @@ impl From<LanguageModelOptions> for GenerateContentRequest {
             }]
         });
 
+        let tool_config = tools.as_ref().and(options.tool_choice).map(Into::into);
+
         let generation_config = Some(types::GenerationConfig {
             stop_sequences: options.stop_sequences,
             response_mime_type: options
@@ -75,7 +78,7 @@ impl From<LanguageModelOptions> for GenerateContentRequest {
         Self {
             contents,
             tools,
-            tool_config: None, // Default to auto
+            tool_config,
             safety_settings: None,
             system_instruction,
             generation_config,
@@ -84,6 +87,54 @@ impl From<LanguageModelOptions> for GenerateContentRequest {
     }
 }
 
+impl From<ToolChoice> for types::ToolConfig {
+    fn from(choice: ToolChoice) -> Self {
+        let (mode, allowed_function_names) = match choice {
+            ToolChoice::Auto => (types::FunctionCallingMode::Auto, None),
+            ToolChoice::None => (types::FunctionCallingMode::None, None),
+            ToolChoice::Required => (types::FunctionCallingMode::Any, None),
+            ToolChoice::Tool(name) => (types::FunctionCallingMode::Any, Some(vec![name])),
+        };
+
+        types::ToolConfig {
+            function_calling_config: Some(types::FunctionCallingConfig {
+                mode,
+                allowed_function_names,
+            }),
+        }
+    }
+}
+
+impl From<UserContentPart> for Part {
+    fn from(part: UserContentPart) -> Self {
+        match part {
+            UserContentPart::Text(text) => Part {
+                text: Some(text),
+                ..Default::default()
+            },
+            UserContentPart::Image(media)
+            | UserContentPart::Audio(media)
+            | UserContentPart::Video(media)
+            | UserContentPart::File(media) => match media.data.to_base64() {
+                Some(data) => Part {
+                    inline_data: Some(types::Blob {
+                        mime_type: media.media_type,
+                        data,
+                    }),
+                    ..Default::default()
+                },
+                None => Part {
+                    file_data: Some(types::FileData {
+                        mime_type: media.media_type.clone(),
+                        file_uri: media.to_url(),
+                    }),
+                    ..Default::default()
+                },
+            },
+        }
+    }
+}
+
 impl From<TaggedMessage> for Content {
     fn from(tagged: TaggedMessage) -> Self {
         tagged.message.into()
@@ -95,10 +146,7 @@ impl From<Message> for Content {
         match message {
             Message::User(u) => Content {
                 role: Role::User,
-                parts: vec![Part {
-                    text: Some(u.content),
-                    ..Default::default()
-                }],
+                parts: u.into_parts().into_iter().map(Into::into).collect(),
             },
             Message::Assistant(a) => {
                 let part = match a.content {
@@ -117,7 +165,7 @@ impl From<Message> for Content {
                         // Retrieve Gemini-specific ToolCall metadata from extensions
                         if let Some(sig) = tc
                             .extensions
-                            .get::<GoogleToolMetadata>()
+                            .get_persisted::<GoogleToolMetadata>()
                             .thought_signature
                             .as_ref()
                         {
@@ -133,10 +181,13 @@ impl From<Message> for Content {
                 }
             }
             Message::Tool(tr) => {
-                let mut response = tr.output.unwrap_or(Value::Null);
-                if !response.is_object() {
-                    response = serde_json::json!({ "result": response });
-                }
+                // Gemini expects an object and reports failures under an "error" key
+                let response = match tr.output {
+                    Ok(ToolOutput::Json(value)) if value.is_object() => value,
+                    Ok(ToolOutput::Json(value)) => serde_json::json!({ "result": value }),
+                    Ok(output) => serde_json::json!({ "result": output.to_text() }),
+                    Err(error) => serde_json::json!({ "error": error.to_string() }),
+                };
                 Content {
                     role: Role::User,
                     parts: vec![Part {
@@ -203,3 +254,28 @@ impl From<EmbeddingModelOptions> for GoogleEmbeddingOptions {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::messages::DataContent;
+
+    #[test]
+    fn test_data_url_is_sent_inline() {
+        let part = Part::from(UserContentPart::file(
+            DataContent::url("data:application/pdf;base64,JVBERg=="),
+            "application/pdf",
+        ));
+        assert!(part.file_data.is_none());
+        let blob = part.inline_data.unwrap();
+        assert_eq!(blob.mime_type, "application/pdf");
+        assert_eq!(blob.data, "JVBERg==");
+
+        let part = Part::from(UserContentPart::file(
+            DataContent::url("gs://bucket/doc.pdf"),
+            "application/pdf",
+        ));
+        assert!(part.inline_data.is_none());
+        assert_eq!(part.file_data.unwrap().file_uri, "gs://bucket/doc.pdf");
+    }
+}
@@ -1,7 +1,11 @@
 //! Google tool-specific metadata for extensions.
 
+use serde::{Deserialize, Serialize};
+
+use crate::extensions::PersistedExtension;
+
 /// Metadata specific to Google provider tool functionality.
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub(crate) struct GoogleToolMetadata {
     /// The thought signature returned by Gemini 3 models.
     ///
@@ -9,3 +13,7 @@ pub(crate) struct GoogleToolMetadata {
     /// for tool calls to work correctly.
     pub thought_signature: Option<String>,
 }
+
+impl PersistedExtension for GoogleToolMetadata {
+    const KEY: &'static str = "google.tool";
+}
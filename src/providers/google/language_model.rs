@@ -24,11 +24,13 @@ impl<M: ModelName> LanguageModel for Google<M> {
         &mut self,
         options: LanguageModelOptions,
     ) -> Result<LanguageModelResponse> {
+        let retry_policy = self.settings.retry_policy.for_request(&options);
         let request: types::GenerateContentRequest = options.into();
         self.lm_options.request = Some(request);
         self.lm_options.streaming = false;
 
-        let response: types::GenerateContentResponse = self.send(&self.settings.base_url).await?;
+        let response: types::GenerateContentResponse =
+            self.send(&self.settings.base_url, &retry_policy).await?;
 
         let mut collected = Vec::new();
         let usage = response.usage_metadata.map(|u| u.into());
@@ -44,7 +46,7 @@ impl<M: ModelName> LanguageModel for Google<M> {
                     if let Some(sig) = part.thought_signature {
                         tool_info
                             .extensions
-                            .get_mut::<extensions::GoogleToolMetadata>()
+                            .get_persisted_mut::<extensions::GoogleToolMetadata>()
                             .thought_signature = Some(sig);
                     }
                     collected.push(LanguageModelResponseContentType::ToolCall(tool_info));
@@ -59,37 +61,19 @@ impl<M: ModelName> LanguageModel for Google<M> {
     }
 
     async fn stream_text(&mut self, options: LanguageModelOptions) -> Result<ProviderStream> {
+        let retry_policy = self.settings.retry_policy.for_request(&options);
         let request: types::GenerateContentRequest = options.into();
         self.lm_options.request = Some(request);
         self.lm_options.streaming = true;
 
-        // Retry logic for rate limiting
-        let max_retries = 5;
-        let mut retry_count = 0;
-        let mut wait_time = std::time::Duration::from_secs(1);
-
-        let google_stream = loop {
-            match self.send_and_stream(&self.settings.base_url).await {
-                Ok(stream) => break stream,
-                Err(crate::error::Error::ApiError {
-                    status_code: Some(status),
-                    ..
-                }) if status == reqwest::StatusCode::TOO_MANY_REQUESTS
-                    && retry_count < max_retries =>
-                {
-                    retry_count += 1;
-                    tokio::time::sleep(wait_time).await;
-                    wait_time *= 2; // Exponential backoff
-                    continue;
-                }
-                Err(e) => return Err(e),
-            }
-        };
+        let google_stream = self
+            .send_and_stream(&self.settings.base_url, &retry_policy)
+            .await?;
 
         #[derive(Default)]
         struct StreamState {
             accumulated_text: String,
-            accumulated_tool_call: Option<ToolCallInfo>,
+            accumulated_tool_calls: Vec<ToolCallInfo>,
             usage: Option<Usage>,
         }
 
@@ -116,31 +100,54 @@ impl<M: ModelName> LanguageModel for Google<M> {
                                 if let Some(sig) = &part.thought_signature {
                                     tool_info
                                         .extensions
-                                        .get_mut::<extensions::GoogleToolMetadata>()
+                                        .get_persisted_mut::<extensions::GoogleToolMetadata>()
                                         .thought_signature = Some(sig.clone());
                                 }
-                                state.accumulated_tool_call = Some(tool_info);
-
+                                // Function calls arrive complete, so the input is a single delta.
+                                let id = tool_info.tool.id.clone();
+                                chunks.push(LanguageModelStreamChunk::Delta(
+                                    LanguageModelStreamChunkType::ToolInputStart {
+                                        id: id.clone(),
+                                        name: fc.name.clone(),
+                                    },
+                                ));
                                 chunks.push(LanguageModelStreamChunk::Delta(
-                                    LanguageModelStreamChunkType::ToolCall(
-                                        serde_json::to_string(&fc).unwrap_or_default(),
-                                    ),
+                                    LanguageModelStreamChunkType::ToolInputDelta {
+                                        id,
+                                        delta: fc.args.to_string(),
+                                    },
                                 ));
+                                state.accumulated_tool_calls.push(tool_info);
                             }
                         }
 
                         if candidate.finish_reason.is_some() {
-                            let content = if let Some(tc) = state.accumulated_tool_call.take() {
-                                LanguageModelResponseContentType::ToolCall(tc)
-                            } else {
-                                let text = std::mem::take(&mut state.accumulated_text);
-                                LanguageModelResponseContentType::Text(text)
-                            };
-
-                            chunks.push(LanguageModelStreamChunk::Done(AssistantMessage {
-                                content,
-                                usage: state.usage.clone(),
-                            }));
+                            // Emit the text of the turn, followed by every function call
+                            // of the turn, which Gemini may return in parallel.
+                            let text = std::mem::take(&mut state.accumulated_text);
+                            let tool_calls = std::mem::take(&mut state.accumulated_tool_calls);
+                            let mut contents = Vec::new();
+                            if !text.is_empty() || tool_calls.is_empty() {
+                                contents.push(LanguageModelResponseContentType::Text(text));
+                            }
+                            contents.extend(
+                                tool_calls
+                                    .into_iter()
+                                    .map(LanguageModelResponseContentType::ToolCall),
+                            );
+                            // The usage covers the whole turn, so only the last message
+                            // carries it.
+                            let last = contents.len() - 1;
+                            for (index, content) in contents.into_iter().enumerate() {
+                                chunks.push(LanguageModelStreamChunk::Done(AssistantMessage {
+                                    content,
+                                    usage: if index == last {
+                                        state.usage.clone()
+                                    } else {
+                                        None
+                                    },
+                                }));
+                            }
                         }
                     }
                     Some(Ok(chunks))
@@ -157,3 +164,74 @@ impl<M: ModelName> LanguageModel for Google<M> {
         Ok(Box::pin(stream))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::client::stub_server::StubServer;
+    use crate::providers::google::Gemini20Flash;
+    use serde_json::json;
+
+    #[tokio::test]
+    async fn test_stream_text_keeps_parallel_function_calls_and_text() {
+        let text = json!({
+            "candidates": [{ "content": { "role": "model", "parts": [{ "text": "Checking both." }] } }]
+        });
+        let calls = json!({
+            "candidates": [{
+                "content": {
+                    "role": "model",
+                    "parts": [
+                        { "functionCall": { "name": "weather", "args": { "city": "Paris" } } },
+                        { "functionCall": { "name": "weather", "args": { "city": "Rome" } } }
+                    ]
+                },
+                "finishReason": "STOP"
+            }],
+            "usageMetadata": { "promptTokenCount": 5, "candidatesTokenCount": 7, "totalTokenCount": 12 }
+        });
+        let body = format!("data: {text}\n\ndata: {calls}\n\n");
+        let server = StubServer::start(200, "text/event-stream", body).await;
+
+        let mut model = Google::<Gemini20Flash>::builder()
+            .base_url(server.url.clone())
+            .api_key("test-key")
+            .build()
+            .unwrap();
+
+        let mut stream = model
+            .stream_text(LanguageModelOptions::default())
+            .await
+            .unwrap();
+        let mut done = Vec::new();
+        let mut usages = Vec::new();
+        while let Some(chunks) = stream.next().await {
+            for chunk in chunks.unwrap() {
+                if let LanguageModelStreamChunk::Done(message) = chunk {
+                    done.push(message.content);
+                    usages.push(message.usage);
+                }
+            }
+        }
+
+        assert_eq!(done.len(), 3);
+        assert!(matches!(
+            &done[0],
+            LanguageModelResponseContentType::Text(text) if text == "Checking both."
+        ));
+        let cities: Vec<_> = done[1..]
+            .iter()
+            .map(|content| match content {
+                LanguageModelResponseContentType::ToolCall(info) => info.input["city"].clone(),
+                other => panic!("expected a tool call, got {other:?}"),
+            })
+            .collect();
+        assert_eq!(cities, vec![json!("Paris"), json!("Rome")]);
+
+        // The usage of the turn is reported once, on the last message.
+        assert_eq!(usages[..2], [None, None]);
+        let usage = usages[2].as_ref().expect("usage on the last message");
+        assert_eq!(usage.input_tokens, Some(5));
+        assert_eq!(usage.output_tokens, Some(7));
+    }
+}
@@ -19,6 +19,14 @@ pub struct GoogleProviderSettings {
     /// Custom API path override. When set, this path is used instead of the
     /// default dynamic path (e.g., "/v1beta/models/{model}:generateContent").
     pub path: Option<String>,
+
+    /// The HTTP client used for requests. Defaults to the client shared by all providers.
+    #[serde(skip, default = "crate::core::client::shared_http_client")]
+    pub http_client: reqwest::Client,
+
+    /// The policy for retrying failed requests.
+    #[serde(skip)]
+    pub retry_policy: crate::core::client::RetryPolicy,
 }
 
 impl Default for GoogleProviderSettings {
@@ -29,6 +37,8 @@ impl Default for GoogleProviderSettings {
             base_url: "https://generativelanguage.googleapis.com".to_string(),
             api_key: std::env::var("GOOGLE_API_KEY").unwrap_or_default(),
             path: None,
+            http_client: crate::core::client::shared_http_client(),
+            retry_policy: crate::core::client::RetryPolicy::default(),
         }
     }
 }
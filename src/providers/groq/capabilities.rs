@@ -64,5 +64,17 @@ model_capabilities! {
             display_name: "Qwen3 32B",
             capabilities: [ReasoningSupport, TextInputSupport, TextOutputSupport, ToolCallSupport]
         },
+        WhisperLargeV3 {
+            model_name: "whisper-large-v3",
+            constructor_name: whisper_large_v3,
+            display_name: "Whisper Large V3",
+            capabilities: [AudioInputSupport, TextOutputSupport]
+        },
+        WhisperLargeV3Turbo {
+            model_name: "whisper-large-v3-turbo",
+            constructor_name: whisper_large_v3_turbo,
+            display_name: "Whisper Large V3 Turbo",
+            capabilities: [AudioInputSupport, TextOutputSupport]
+        },
     }
 }
@@ -25,3 +25,6 @@ crate::openai_compatible_provider!(
 
 // Generate the language model implementation
 crate::openai_compatible_language_model!(Groq);
+
+// Generate the transcription model implementation
+crate::openai_compatible_transcription_model!(Groq);
@@ -1,6 +1,9 @@
 //! This module provides the `Provider` trait, which defines the interface for
 //! interacting with different AI providers.
 
+pub mod registry;
+pub use registry::ProviderRegistry;
+
 #[cfg(feature = "openai")]
 pub mod openai;
 #[cfg(feature = "openai")]
@@ -16,3 +16,6 @@ crate::openai_compatible_provider!(Nebius, NebiusBuilder, NebiusProviderSettings
 
 // Generate the language model implementation
 crate::openai_compatible_language_model!(Nebius);
+
+// Generate the image model implementation
+crate::openai_compatible_image_model!(Nebius);
@@ -16,3 +16,6 @@ crate::openai_compatible_provider!(Nvidia, NvidiaBuilder, NvidiaProviderSettings
 
 // Generate the language model implementation
 crate::openai_compatible_language_model!(Nvidia);
+
+// The reranking API has its own endpoint and format
+pub mod reranking_model;
@@ -0,0 +1,250 @@
+//! Reranking model implementation for the Nvidia provider.
+//!
+//! NVIDIA NIM rerankers do not use the `/rerank` format of the other providers: the hosted
+//! API serves each model at `https://ai.api.nvidia.com/v1/retrieval/<model>/reranking`,
+//! and self-hosted NIMs serve `/v1/ranking`. Both take the query and passages as text
+//! objects and return unsorted logits.
+
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+
+use super::Nvidia;
+use super::settings::NvidiaProviderSettings;
+use crate::{
+    Result,
+    core::{
+        capabilities::ModelName,
+        client::JsonClient,
+        reranking_model::{RerankingModel, RerankingModelOptions, RerankingModelResponse},
+    },
+};
+
+/// The default base URL of the hosted chat API.
+const HOSTED_BASE_URL: &str = "https://integrate.api.nvidia.com/v1";
+
+/// The base URL of the hosted retrieval API.
+const HOSTED_RETRIEVAL_URL: &str = "https://ai.api.nvidia.com/v1/retrieval";
+
+/// A text passed to the reranking API.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct NvidiaText {
+    text: String,
+}
+
+/// Request options for the NVIDIA reranking API.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct NvidiaRerankOptions {
+    model: String,
+    query: NvidiaText,
+    passages: Vec<NvidiaText>,
+}
+
+/// A ranked passage.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct NvidiaRanking {
+    index: usize,
+    logit: f64,
+}
+
+/// Response from the NVIDIA reranking API.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct NvidiaRerankResponse {
+    rankings: Vec<NvidiaRanking>,
+}
+
+impl<M: ModelName> Nvidia<M> {
+    /// Returns the base URL and path of the reranking endpoint.
+    fn reranking_endpoint(&self) -> (String, String) {
+        if self.settings.base_url.trim_end_matches('/') == HOSTED_BASE_URL {
+            // Hosted model paths use underscores for the dots in model names.
+            let model = self.inner.options.model.replace('.', "_");
+            (
+                HOSTED_RETRIEVAL_URL.to_string(),
+                format!("{model}/reranking"),
+            )
+        } else {
+            (self.settings.base_url.clone(), "ranking".to_string())
+        }
+    }
+}
+
+#[async_trait]
+impl<M: ModelName> RerankingModel for Nvidia<M> {
+    async fn rerank(&self, options: RerankingModelOptions) -> Result<RerankingModelResponse> {
+        let (base_url, path) = self.reranking_endpoint();
+        let rerank_client = RerankingClientWrapper {
+            settings: self.settings.clone(),
+            path,
+            options: NvidiaRerankOptions {
+                model: self.inner.options.model.clone(),
+                query: NvidiaText {
+                    text: options.query,
+                },
+                passages: options
+                    .documents
+                    .iter()
+                    .map(|document| NvidiaText {
+                        text: document.clone(),
+                    })
+                    .collect(),
+            },
+        };
+
+        let response = rerank_client
+            .send(&base_url, &self.settings.retry_policy)
+            .await?;
+
+        // The API has no `top_n`, so the results are cut after sorting.
+        let mut response = RerankingModelResponse::from_scores(
+            &options.documents,
+            response
+                .rankings
+                .into_iter()
+                .map(|ranking| (ranking.index, ranking.logit)),
+        )?;
+        if let Some(top_n) = options.top_n {
+            response.results.truncate(top_n);
+        }
+        Ok(response)
+    }
+}
+
+/// Temporary wrapper for rerank requests.
+struct RerankingClientWrapper {
+    settings: NvidiaProviderSettings,
+    path: String,
+    options: NvidiaRerankOptions,
+}
+
+impl JsonClient for RerankingClientWrapper {
+    type Response = NvidiaRerankResponse;
+
+    fn path(&self) -> String {
+        self.path.clone()
+    }
+
+    fn method(&self) -> reqwest::Method {
+        reqwest::Method::POST
+    }
+
+    fn http_client(&self) -> reqwest::Client {
+        self.settings.http_client.clone()
+    }
+
+    fn headers(&self) -> reqwest::header::HeaderMap {
+        let mut headers = reqwest::header::HeaderMap::new();
+        headers.insert(
+            reqwest::header::CONTENT_TYPE,
+            "application/json".parse().unwrap(),
+        );
+        headers.insert(
+            reqwest::header::AUTHORIZATION,
+            format!("Bearer {}", self.settings.api_key).parse().unwrap(),
+        );
+        headers
+    }
+
+    fn query_params(&self) -> Vec<(&str, &str)> {
+        Vec::new()
+    }
+
+    fn body(&self) -> reqwest::Body {
+        let body = serde_json::to_string(&self.options).unwrap();
+        reqwest::Body::from(body)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::DynamicModel;
+    use crate::core::client::stub_server::StubServer;
+    use crate::core::reranking_model::{RankedDocument, RerankingModelRequest};
+    use serde_json::json;
+
+    #[test]
+    fn test_reranking_endpoint() {
+        let hosted = Nvidia::<DynamicModel>::model_name("nvidia/llama-3.2-nv-rerankqa-1b-v2");
+        assert_eq!(
+            hosted.reranking_endpoint(),
+            (
+                HOSTED_RETRIEVAL_URL.to_string(),
+                "nvidia/llama-3_2-nv-rerankqa-1b-v2/reranking".to_string()
+            )
+        );
+
+        let self_hosted = Nvidia::<DynamicModel>::builder()
+            .base_url("http://localhost:8000/v1")
+            .api_key("test-key")
+            .model_name("nvidia/llama-3.2-nv-rerankqa-1b-v2")
+            .build()
+            .unwrap();
+        assert_eq!(
+            self_hosted.reranking_endpoint(),
+            (
+                "http://localhost:8000/v1".to_string(),
+                "ranking".to_string()
+            )
+        );
+    }
+
+    #[tokio::test]
+    async fn test_rerank() {
+        let mut server = StubServer::json(json!({
+            "rankings": [
+                { "index": 0, "logit": -4.2 },
+                { "index": 2, "logit": 3.1 },
+                { "index": 1, "logit": -8.5 }
+            ]
+        }))
+        .await;
+        let model = Nvidia::<DynamicModel>::builder()
+            .base_url(format!("{}v1", server.url))
+            .api_key("test-key")
+            .model_name("nvidia/llama-3.2-nv-rerankqa-1b-v2")
+            .build()
+            .unwrap();
+
+        let response = RerankingModelRequest::builder()
+            .model(model)
+            .query("Which language is fast?")
+            .documents(["Bananas", "The sky is blue", "Rust is fast"])
+            .top_n(2)
+            .build()
+            .rerank()
+            .await
+            .unwrap();
+
+        let request = server.request().await;
+        assert_eq!(request.path, "/v1/ranking");
+        assert_eq!(request.headers["authorization"], "Bearer test-key");
+        assert_eq!(
+            request.json(),
+            json!({
+                "model": "nvidia/llama-3.2-nv-rerankqa-1b-v2",
+                "query": { "text": "Which language is fast?" },
+                "passages": [
+                    { "text": "Bananas" },
+                    { "text": "The sky is blue" },
+                    { "text": "Rust is fast" }
+                ]
+            })
+        );
+
+        assert_eq!(
+            response.results,
+            vec![
+                RankedDocument {
+                    index: 2,
+                    score: 3.1,
+                    document: "Rust is fast".to_string(),
+                },
+                RankedDocument {
+                    index: 0,
+                    score: -4.2,
+                    document: "Bananas".to_string(),
+                },
+            ]
+        );
+    }
+}
@@ -16,6 +16,18 @@ model_capabilities! {
             display_name: "Codex Mini",
             capabilities: [ImageInputSupport, ReasoningSupport, TextInputSupport, TextOutputSupport, ToolCallSupport]
         },
+        DallE2 {
+            model_name: "dall-e-2",
+            constructor_name: dall_e_2,
+            display_name: "DALL·E 2",
+            capabilities: [ImageOutputSupport, TextInputSupport]
+        },
+        DallE3 {
+            model_name: "dall-e-3",
+            constructor_name: dall_e_3,
+            display_name: "DALL·E 3",
+            capabilities: [ImageOutputSupport, TextInputSupport]
+        },
         Gpt35Turbo {
             model_name: "gpt-3.5-turbo",
             constructor_name: gpt_3_5_turbo,
@@ -32,19 +44,19 @@ model_capabilities! {
             model_name: "gpt-4.1",
             constructor_name: gpt_4_1,
             display_name: "GPT-4.1",
-            capabilities: [ImageInputSupport, StructuredOutputSupport, TextInputSupport, TextOutputSupport, ToolCallSupport]
+            capabilities: [FileInputSupport, ImageInputSupport, StructuredOutputSupport, TextInputSupport, TextOutputSupport, ToolCallSupport]
         },
         Gpt41Mini {
             model_name: "gpt-4.1-mini",
             constructor_name: gpt_4_1_mini,
             display_name: "GPT-4.1 mini",
-            capabilities: [ImageInputSupport, StructuredOutputSupport, TextInputSupport, TextOutputSupport, ToolCallSupport]
+            capabilities: [FileInputSupport, ImageInputSupport, StructuredOutputSupport, TextInputSupport, TextOutputSupport, ToolCallSupport]
         },
         Gpt41Nano {
             model_name: "gpt-4.1-nano",
             constructor_name: gpt_4_1_nano,
             display_name: "GPT-4.1 nano",
-            capabilities: [ImageInputSupport, StructuredOutputSupport, TextInputSupport, TextOutputSupport, ToolCallSupport]
+            capabilities: [FileInputSupport, ImageInputSupport, StructuredOutputSupport, TextInputSupport, TextOutputSupport, ToolCallSupport]
         },
         Gpt4Turbo {
             model_name: "gpt-4-turbo",
@@ -56,121 +68,145 @@ model_capabilities! {
             model_name: "gpt-4o",
             constructor_name: gpt_4o,
             display_name: "GPT-4o",
-            capabilities: [ImageInputSupport, StructuredOutputSupport, TextInputSupport, TextOutputSupport, ToolCallSupport]
+            capabilities: [FileInputSupport, ImageInputSupport, StructuredOutputSupport, TextInputSupport, TextOutputSupport, ToolCallSupport]
         },
         Gpt4o20240513 {
             model_name: "gpt-4o-2024-05-13",
             constructor_name: gpt_4o_2024_05_13,
             display_name: "GPT-4o (2024-05-13)",
-            capabilities: [ImageInputSupport, StructuredOutputSupport, TextInputSupport, TextOutputSupport, ToolCallSupport]
+            capabilities: [FileInputSupport, ImageInputSupport, StructuredOutputSupport, TextInputSupport, TextOutputSupport, ToolCallSupport]
         },
         Gpt4o20240806 {
             model_name: "gpt-4o-2024-08-06",
             constructor_name: gpt_4o_2024_08_06,
             display_name: "GPT-4o (2024-08-06)",
-            capabilities: [ImageInputSupport, StructuredOutputSupport, TextInputSupport, TextOutputSupport, ToolCallSupport]
+            capabilities: [FileInputSupport, ImageInputSupport, StructuredOutputSupport, TextInputSupport, TextOutputSupport, ToolCallSupport]
         },
         Gpt4o20241120 {
             model_name: "gpt-4o-2024-11-20",
             constructor_name: gpt_4o_2024_11_20,
             display_name: "GPT-4o (2024-11-20)",
-            capabilities: [ImageInputSupport, StructuredOutputSupport, TextInputSupport, TextOutputSupport, ToolCallSupport]
+            capabilities: [FileInputSupport, ImageInputSupport, StructuredOutputSupport, TextInputSupport, TextOutputSupport, ToolCallSupport]
         },
         Gpt4oMini {
             model_name: "gpt-4o-mini",
             constructor_name: gpt_4o_mini,
             display_name: "GPT-4o mini",
-            capabilities: [ImageInputSupport, StructuredOutputSupport, TextInputSupport, TextOutputSupport, ToolCallSupport]
+            capabilities: [FileInputSupport, ImageInputSupport, StructuredOutputSupport, TextInputSupport, TextOutputSupport, ToolCallSupport]
+        },
+        Gpt4oMiniTranscribe {
+            model_name: "gpt-4o-mini-transcribe",
+            constructor_name: gpt_4o_mini_transcribe,
+            display_name: "GPT-4o mini Transcribe",
+            capabilities: [AudioInputSupport, TextOutputSupport]
+        },
+        Gpt4oMiniTts {
+            model_name: "gpt-4o-mini-tts",
+            constructor_name: gpt_4o_mini_tts,
+            display_name: "GPT-4o mini TTS",
+            capabilities: [AudioOutputSupport, TextInputSupport]
+        },
+        Gpt4oTranscribe {
+            model_name: "gpt-4o-transcribe",
+            constructor_name: gpt_4o_transcribe,
+            display_name: "GPT-4o Transcribe",
+            capabilities: [AudioInputSupport, TextOutputSupport]
         },
         Gpt5 {
             model_name: "gpt-5",
             constructor_name: gpt_5,
             display_name: "GPT-5",
-            capabilities: [ImageInputSupport, ReasoningSupport, StructuredOutputSupport, TextInputSupport, TextOutputSupport, ToolCallSupport]
+            capabilities: [FileInputSupport, ImageInputSupport, ReasoningSupport, StructuredOutputSupport, TextInputSupport, TextOutputSupport, ToolCallSupport]
         },
         Gpt51 {
             model_name: "gpt-5.1",
             constructor_name: gpt_5_1,
             display_name: "GPT-5.1",
-            capabilities: [ImageInputSupport, ReasoningSupport, TextInputSupport, TextOutputSupport, ToolCallSupport]
+            capabilities: [FileInputSupport, ImageInputSupport, ReasoningSupport, TextInputSupport, TextOutputSupport, ToolCallSupport]
         },
         Gpt51ChatLatest {
             model_name: "gpt-5.1-chat-latest",
             constructor_name: gpt_5_1_chat_latest,
             display_name: "GPT-5.1 Chat",
-            capabilities: [ImageInputSupport, ReasoningSupport, StructuredOutputSupport, TextInputSupport, TextOutputSupport, ToolCallSupport]
+            capabilities: [FileInputSupport, ImageInputSupport, ReasoningSupport, StructuredOutputSupport, TextInputSupport, TextOutputSupport, ToolCallSupport]
         },
         Gpt51Codex {
             model_name: "gpt-5.1-codex",
             constructor_name: gpt_5_1_codex,
             display_name: "GPT-5.1 Codex",
-            capabilities: [ImageInputSupport, ReasoningSupport, StructuredOutputSupport, TextInputSupport, TextOutputSupport, ToolCallSupport]
+            capabilities: [FileInputSupport, ImageInputSupport, ReasoningSupport, StructuredOutputSupport, TextInputSupport, TextOutputSupport, ToolCallSupport]
         },
         Gpt51CodexMax {
             model_name: "gpt-5.1-codex-max",
             constructor_name: gpt_5_1_codex_max,
             display_name: "GPT-5.1 Codex Max",
-            capabilities: [ImageInputSupport, ReasoningSupport, StructuredOutputSupport, TextInputSupport, TextOutputSupport, ToolCallSupport]
+            capabilities: [FileInputSupport, ImageInputSupport, ReasoningSupport, StructuredOutputSupport, TextInputSupport, TextOutputSupport, ToolCallSupport]
         },
         Gpt51CodexMini {
             model_name: "gpt-5.1-codex-mini",
             constructor_name: gpt_5_1_codex_mini,
             display_name: "GPT-5.1 Codex mini",
-            capabilities: [ImageInputSupport, ImageOutputSupport, ReasoningSupport, StructuredOutputSupport, TextInputSupport, TextOutputSupport, ToolCallSupport]
+            capabilities: [FileInputSupport, ImageInputSupport, ImageOutputSupport, ReasoningSupport, StructuredOutputSupport, TextInputSupport, TextOutputSupport, ToolCallSupport]
         },
         Gpt52 {
             model_name: "gpt-5.2",
             constructor_name: gpt_5_2,
             display_name: "GPT-5.2",
-            capabilities: [ImageInputSupport, ReasoningSupport, TextInputSupport, TextOutputSupport, ToolCallSupport]
+            capabilities: [FileInputSupport, ImageInputSupport, ReasoningSupport, TextInputSupport, TextOutputSupport, ToolCallSupport]
         },
         Gpt52ChatLatest {
             model_name: "gpt-5.2-chat-latest",
             constructor_name: gpt_5_2_chat_latest,
             display_name: "GPT-5.2 Chat",
-            capabilities: [ImageInputSupport, ReasoningSupport, StructuredOutputSupport, TextInputSupport, TextOutputSupport, ToolCallSupport]
+            capabilities: [FileInputSupport, ImageInputSupport, ReasoningSupport, StructuredOutputSupport, TextInputSupport, TextOutputSupport, ToolCallSupport]
         },
         Gpt52Pro {
             model_name: "gpt-5.2-pro",
             constructor_name: gpt_5_2_pro,
             display_name: "GPT-5.2 Pro",
-            capabilities: [ImageInputSupport, ReasoningSupport, StructuredOutputSupport, TextInputSupport, TextOutputSupport, ToolCallSupport]
+            capabilities: [FileInputSupport, ImageInputSupport, ReasoningSupport, StructuredOutputSupport, TextInputSupport, TextOutputSupport, ToolCallSupport]
         },
         Gpt5ChatLatest {
             model_name: "gpt-5-chat-latest",
             constructor_name: gpt_5_chat_latest,
             display_name: "GPT-5 Chat (latest)",
-            capabilities: [ImageInputSupport, ReasoningSupport, StructuredOutputSupport, TextInputSupport, TextOutputSupport]
+            capabilities: [FileInputSupport, ImageInputSupport, ReasoningSupport, StructuredOutputSupport, TextInputSupport, TextOutputSupport]
         },
         Gpt5Codex {
             model_name: "gpt-5-codex",
             constructor_name: gpt_5_codex,
             display_name: "GPT-5-Codex",
-            capabilities: [ImageInputSupport, ReasoningSupport, StructuredOutputSupport, TextInputSupport, TextOutputSupport, ToolCallSupport]
+            capabilities: [FileInputSupport, ImageInputSupport, ReasoningSupport, StructuredOutputSupport, TextInputSupport, TextOutputSupport, ToolCallSupport]
         },
         Gpt5Mini {
             model_name: "gpt-5-mini",
             constructor_name: gpt_5_mini,
             display_name: "GPT-5 Mini",
-            capabilities: [ImageInputSupport, ReasoningSupport, StructuredOutputSupport, TextInputSupport, TextOutputSupport, ToolCallSupport]
+            capabilities: [FileInputSupport, ImageInputSupport, ReasoningSupport, StructuredOutputSupport, TextInputSupport, TextOutputSupport, ToolCallSupport]
         },
         Gpt5Nano {
             model_name: "gpt-5-nano",
             constructor_name: gpt_5_nano,
             display_name: "GPT-5 Nano",
-            capabilities: [ImageInputSupport, ReasoningSupport, StructuredOutputSupport, TextInputSupport, TextOutputSupport, ToolCallSupport]
+            capabilities: [FileInputSupport, ImageInputSupport, ReasoningSupport, StructuredOutputSupport, TextInputSupport, TextOutputSupport, ToolCallSupport]
         },
         Gpt5Pro {
             model_name: "gpt-5-pro",
             constructor_name: gpt_5_pro,
             display_name: "GPT-5 Pro",
-            capabilities: [ImageInputSupport, ReasoningSupport, StructuredOutputSupport, TextInputSupport, TextOutputSupport, ToolCallSupport]
+            capabilities: [FileInputSupport, ImageInputSupport, ReasoningSupport, StructuredOutputSupport, TextInputSupport, TextOutputSupport, ToolCallSupport]
+        },
+        GptImage1 {
+            model_name: "gpt-image-1",
+            constructor_name: gpt_image_1,
+            display_name: "GPT Image 1",
+            capabilities: [ImageInputSupport, ImageOutputSupport, TextInputSupport]
         },
         O1 {
             model_name: "o1",
             constructor_name: o1,
             display_name: "o1",
-            capabilities: [ImageInputSupport, ReasoningSupport, StructuredOutputSupport, TextInputSupport, TextOutputSupport, ToolCallSupport]
+            capabilities: [FileInputSupport, ImageInputSupport, ReasoningSupport, StructuredOutputSupport, TextInputSupport, TextOutputSupport, ToolCallSupport]
         },
         O1Mini {
             model_name: "o1-mini",
@@ -188,13 +224,13 @@ model_capabilities! {
             model_name: "o1-pro",
             constructor_name: o1_pro,
             display_name: "o1-pro",
-            capabilities: [ImageInputSupport, ReasoningSupport, StructuredOutputSupport, TextInputSupport, TextOutputSupport, ToolCallSupport]
+            capabilities: [FileInputSupport, ImageInputSupport, ReasoningSupport, StructuredOutputSupport, TextInputSupport, TextOutputSupport, ToolCallSupport]
         },
         O3 {
             model_name: "o3",
             constructor_name: o3,
             display_name: "o3",
-            capabilities: [ImageInputSupport, ReasoningSupport, StructuredOutputSupport, TextInputSupport, TextOutputSupport, ToolCallSupport]
+            capabilities: [FileInputSupport, ImageInputSupport, ReasoningSupport, StructuredOutputSupport, TextInputSupport, TextOutputSupport, ToolCallSupport]
         },
         O3DeepResearch {
             model_name: "o3-deep-research",
@@ -212,13 +248,13 @@ model_capabilities! {
             model_name: "o3-pro",
             constructor_name: o3_pro,
             display_name: "o3-pro",
-            capabilities: [ImageInputSupport, ReasoningSupport, StructuredOutputSupport, TextInputSupport, TextOutputSupport, ToolCallSupport]
+            capabilities: [FileInputSupport, ImageInputSupport, ReasoningSupport, StructuredOutputSupport, TextInputSupport, TextOutputSupport, ToolCallSupport]
         },
         O4Mini {
             model_name: "o4-mini",
             constructor_name: o4_mini,
             display_name: "o4-mini",
-            capabilities: [ImageInputSupport, ReasoningSupport, StructuredOutputSupport, TextInputSupport, TextOutputSupport, ToolCallSupport]
+            capabilities: [FileInputSupport, ImageInputSupport, ReasoningSupport, StructuredOutputSupport, TextInputSupport, TextOutputSupport, ToolCallSupport]
         },
         O4MiniDeepResearch {
             model_name: "o4-mini-deep-research",
@@ -226,6 +262,12 @@ model_capabilities! {
             display_name: "o4-mini-deep-research",
             capabilities: [ImageInputSupport, ReasoningSupport, TextInputSupport, TextOutputSupport, ToolCallSupport]
         },
+        OmniModerationLatest {
+            model_name: "omni-moderation-latest",
+            constructor_name: omni_moderation_latest,
+            display_name: "omni-moderation",
+            capabilities: [ImageInputSupport, TextInputSupport]
+        },
         TextEmbedding3Large {
             model_name: "text-embedding-3-large",
             constructor_name: text_embedding_3_large,
@@ -244,5 +286,29 @@ model_capabilities! {
             display_name: "text-embedding-ada-002",
             capabilities: [TextInputSupport, TextOutputSupport]
         },
+        TextModerationLatest {
+            model_name: "text-moderation-latest",
+            constructor_name: text_moderation_latest,
+            display_name: "text-moderation",
+            capabilities: [TextInputSupport]
+        },
+        Tts1 {
+            model_name: "tts-1",
+            constructor_name: tts_1,
+            display_name: "TTS-1",
+            capabilities: [AudioOutputSupport, TextInputSupport]
+        },
+        Tts1Hd {
+            model_name: "tts-1-hd",
+            constructor_name: tts_1_hd,
+            display_name: "TTS-1 HD",
+            capabilities: [AudioOutputSupport, TextInputSupport]
+        },
+        Whisper1 {
+            model_name: "whisper-1",
+            constructor_name: whisper_1,
+            display_name: "Whisper",
+            capabilities: [AudioInputSupport, TextOutputSupport]
+        },
     }
 }
@@ -6,7 +6,7 @@ pub(crate) mod types;
 
 pub(crate) use types::*;
 
-use crate::core::client::{EmbeddingClient, LanguageModelClient};
+use crate::core::client::{JsonClient, LanguageModelClient};
 use crate::error::Error;
 use crate::providers::openai::{ModelName, OpenAI};
 use reqwest::header::CONTENT_TYPE;
@@ -27,6 +27,10 @@ impl<M: ModelName> LanguageModelClient for OpenAI<M> {
         reqwest::Method::POST
     }
 
+    fn http_client(&self) -> reqwest::Client {
+        self.settings.http_client.clone()
+    }
+
     fn headers(&self) -> reqwest::header::HeaderMap {
         // Default headers
         let mut default_headers = reqwest::header::HeaderMap::new();
@@ -93,7 +97,7 @@ impl<M: ModelName> LanguageModelClient for OpenAI<M> {
     }
 }
 
-impl<M: ModelName> EmbeddingClient for OpenAI<M> {
+impl<M: ModelName> JsonClient for OpenAI<M> {
     type Response = types::EmbeddingResponse;
 
     fn path(&self) -> String {
@@ -104,6 +108,10 @@ impl<M: ModelName> EmbeddingClient for OpenAI<M> {
         reqwest::Method::POST
     }
 
+    fn http_client(&self) -> reqwest::Client {
+        self.settings.http_client.clone()
+    }
+
     fn headers(&self) -> reqwest::header::HeaderMap {
         // Default headers
         let mut default_headers = reqwest::header::HeaderMap::new();
@@ -1,6 +1,7 @@
 use crate::error::Error;
 use derive_builder::Builder;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 
 /// Configuration options for OpenAI API requests.
 #[derive(Debug, Default, Clone, Serialize, Deserialize, Builder)]
@@ -30,6 +31,25 @@ pub(crate) struct OpenAILanguageModelOptions {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[builder(default)]
     pub(crate) tools: Option<Vec<ToolParams>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default)]
+    pub(crate) tool_choice: Option<ToolChoice>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default)]
+    pub(crate) parallel_tool_calls: Option<bool>,
+}
+
+/// See <https://platform.openai.com/docs/api-reference/responses/create#responses_create-tool_choice>
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(untagged)]
+pub(crate) enum ToolChoice {
+    /// One of `none`, `auto` or `required`.
+    Mode(String),
+    Function {
+        #[serde(rename = "type")]
+        type_: String, // always "function"
+        name: String,
+    },
 }
 
 /// Response structure from the OpenAI API.
@@ -107,6 +127,21 @@ pub(crate) enum OpenAiStreamEvent {
         summary_index: u32,
         delta: String,
     },
+    /// Emitted when a new output item is added.
+    #[serde(rename = "response.output_item.added")]
+    ResponseOutputItemAdded {
+        sequence_number: u64,
+        output_index: u32,
+        item: MessageItem,
+    },
+    /// Emitted when there is a partial function-call arguments delta.
+    #[serde(rename = "response.function_call_arguments.delta")]
+    ResponseFunctionCallArgumentsDelta {
+        sequence_number: u64,
+        item_id: String,
+        output_index: u32,
+        delta: String,
+    },
     /// Emitted when an error occurs.
     #[serde(rename = "error")]
     ResponseError {
@@ -320,7 +355,7 @@ pub(crate) enum MessageItem {
 #[serde(untagged)]
 pub(crate) enum FunctionCallOutput {
     Text(String),
-    Other(ContentType),
+    Content(Vec<ContentType>),
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -345,18 +380,25 @@ pub(crate) enum ContentType {
     },
     InputImage {
         detail: ImageDetail,
+        #[serde(skip_serializing_if = "Option::is_none")]
         file_id: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
         image_url: Option<String>,
     },
     InputFile {
+        #[serde(skip_serializing_if = "Option::is_none")]
         file_data: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
         filename: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
         file_url: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
         file_id: Option<String>,
     },
 }
 
 #[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "lowercase")]
 pub(crate) enum ImageDetail {
     #[default]
     Auto,
@@ -449,3 +491,120 @@ pub(crate) struct OpenAIEmbeddingOptions {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub encoding_format: Option<String>,
 }
+
+/// See [OpenAI Image API](https://platform.openai.com/docs/api-reference/images/create)
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub(crate) struct OpenAIImageOptions {
+    pub model: String,
+    pub prompt: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub n: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub size: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub quality: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub style: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub(crate) struct ImageResponse {
+    pub created: Option<u64>,
+    pub data: Vec<Image>,
+    pub usage: Option<ImageUsage>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub(crate) struct Image {
+    pub url: Option<String>,
+    pub b64_json: Option<String>,
+    pub revised_prompt: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub(crate) struct ImageUsage {
+    pub input_tokens: Option<usize>,
+    pub output_tokens: Option<usize>,
+    pub total_tokens: Option<usize>,
+}
+
+/// See [OpenAI Speech API](https://platform.openai.com/docs/api-reference/audio/createSpeech)
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub(crate) struct OpenAISpeechOptions {
+    pub model: String,
+    pub input: String,
+    pub voice: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub response_format: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub speed: Option<f32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub instructions: Option<String>,
+}
+
+/// See [OpenAI Transcription API](https://platform.openai.com/docs/api-reference/audio/json-object)
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub(crate) struct TranscriptionResponse {
+    pub text: String,
+    pub language: Option<String>,
+    pub duration: Option<f64>,
+    pub segments: Option<Vec<TranscriptionSegment>>,
+    pub words: Option<Vec<TranscriptionWord>>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub(crate) struct TranscriptionSegment {
+    pub text: String,
+    pub start: f64,
+    pub end: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub(crate) struct TranscriptionWord {
+    pub word: String,
+    pub start: f64,
+    pub end: f64,
+}
+
+/// See [OpenAI Moderation API](https://platform.openai.com/docs/api-reference/moderations/create)
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub(crate) struct OpenAIModerationOptions {
+    pub model: String,
+    pub input: ModerationInput,
+}
+
+/// Plain texts are sent as strings, which all moderation models accept. Images need
+/// the multi-modal format of the omni moderation models.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(untagged)]
+pub(crate) enum ModerationInput {
+    Texts(Vec<String>),
+    MultiModal(Vec<ModerationContent>),
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub(crate) enum ModerationContent {
+    Text { text: String },
+    ImageUrl { image_url: ModerationImageUrl },
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub(crate) struct ModerationImageUrl {
+    pub url: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub(crate) struct ModerationResponse {
+    pub id: Option<String>,
+    pub model: Option<String>,
+    pub results: Vec<ModerationResult>,
+}
+
+/// Categories a model does not support are `null`.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub(crate) struct ModerationResult {
+    pub flagged: bool,
+    pub categories: HashMap<String, Option<bool>>,
+    pub category_scores: HashMap<String, Option<f64>>,
+}
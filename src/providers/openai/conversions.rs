@@ -1,11 +1,18 @@
 //! Helper functions and conversions for the OpenAI provider.
 
 use crate::core::embedding_model::EmbeddingModelOptions;
+use crate::core::image_model::{GeneratedImage, ImageModelOptions, ImageModelResponse};
 use crate::core::language_model::{
-    LanguageModelOptions, LanguageModelResponseContentType, ReasoningEffort, Usage,
+    LanguageModelOptions, LanguageModelResponseContentType, ReasoningEffort, ToolChoice, Usage,
 };
-use crate::core::messages::Message;
-use crate::core::tools::Tool;
+use crate::core::messages::{DataContent, Message, UserContentPart};
+use crate::core::moderation_model::{
+    ModerationCategory, ModerationInput, ModerationModelOptions, ModerationModelResponse,
+    ModerationResult,
+};
+use crate::core::speech_model::SpeechModelOptions;
+use crate::core::tools::{Tool, ToolOutput};
+use crate::core::transcription_model::{TranscriptionModelResponse, TranscriptionSegment};
 use crate::providers::openai::client::{self, types};
 use schemars::Schema;
 use serde_json::Value;
@@ -74,6 +81,8 @@ impl From<LanguageModelOptions> for client::OpenAILanguageModelOptions {
             max_output_tokens: options.max_output_tokens.map(|t| t as usize),
             stream: Some(false),
             top_p: options.top_p.map(|t| t as f32 / 100.0),
+            tool_choice: tools.as_ref().and(options.tool_choice).map(Into::into),
+            parallel_tool_calls: tools.as_ref().and(options.parallel_tool_calls),
             tools,
         }
     }
@@ -88,13 +97,13 @@ impl From<Message> for Option<types::InputItem> {
                     type_: "function_call_output".to_string(),
                     status: None,
                     call_id: tool_info.tool.id.clone(),
-                    output: types::FunctionCallOutput::Text(
-                        tool_info
-                            .output
-                            .clone()
-                            .unwrap_or_else(|e| Value::String(e.to_string()))
-                            .to_string(),
-                    ),
+                    output: match &tool_info.output {
+                        Ok(ToolOutput::Content(parts)) => types::FunctionCallOutput::Content(
+                            parts.iter().cloned().filter_map(Option::from).collect(),
+                        ),
+                        Ok(output) => types::FunctionCallOutput::Text(output.to_text()),
+                        Err(error) => types::FunctionCallOutput::Text(error.to_string()),
+                    },
                 },
             )),
             Message::Assistant(ref assistant_msg) => match assistant_msg.content {
@@ -137,7 +146,7 @@ impl From<Message> for Option<types::InputItem> {
                 _ => None,
             },
             Message::User(u) => Some(types::InputItem::Item(types::MessageItem::InputMessage {
-                content: vec![types::ContentType::InputText { text: u.content }],
+                content: u.into_parts().into_iter().filter_map(Into::into).collect(),
                 role: types::Role::User,
                 type_: "message".to_string(),
             })),
@@ -157,6 +166,52 @@ impl From<Message> for Option<types::InputItem> {
     }
 }
 
+impl From<ToolChoice> for types::ToolChoice {
+    fn from(value: ToolChoice) -> Self {
+        match value {
+            ToolChoice::Auto => types::ToolChoice::Mode("auto".to_string()),
+            ToolChoice::None => types::ToolChoice::Mode("none".to_string()),
+            ToolChoice::Required => types::ToolChoice::Mode("required".to_string()),
+            ToolChoice::Tool(name) => types::ToolChoice::Function {
+                type_: "function".to_string(),
+                name,
+            },
+        }
+    }
+}
+
+impl From<UserContentPart> for Option<types::ContentType> {
+    fn from(part: UserContentPart) -> Self {
+        match part {
+            UserContentPart::Text(text) => Some(types::ContentType::InputText { text }),
+            UserContentPart::Image(image) => Some(types::ContentType::InputImage {
+                detail: types::ImageDetail::Auto,
+                file_id: None,
+                image_url: Some(image.to_url()),
+            }),
+            UserContentPart::File(file) => {
+                let (file_data, file_url) = match file.data {
+                    DataContent::Url(ref url) => (None, Some(url.clone())),
+                    _ => (Some(file.to_url()), None),
+                };
+                Some(types::ContentType::InputFile {
+                    // openai requires a filename for inline file data
+                    filename: file_data
+                        .as_ref()
+                        .map(|_| file.filename.clone().unwrap_or_else(|| "file".to_string())),
+                    file_data,
+                    file_url,
+                    file_id: None,
+                })
+            }
+            UserContentPart::Audio(_) | UserContentPart::Video(_) => {
+                log::warn!("OpenAI Responses API does not support audio or video input parts");
+                None
+            }
+        }
+    }
+}
+
 impl From<types::ResponseUsage> for Usage {
     fn from(value: types::ResponseUsage) -> Self {
         Self {
@@ -190,6 +245,161 @@ impl From<EmbeddingModelOptions> for types::OpenAIEmbeddingOptions {
     }
 }
 
+impl From<ImageModelOptions> for types::OpenAIImageOptions {
+    fn from(value: ImageModelOptions) -> Self {
+        if value.seed.is_some() {
+            log::warn!("OpenAI image generation does not support a seed, ignoring it");
+        }
+
+        types::OpenAIImageOptions {
+            model: "".to_string(), // will be set in image_model.rs
+            prompt: value.prompt,
+            n: value.n,
+            size: value.size,
+            quality: value.quality,
+            style: value.style,
+        }
+    }
+}
+
+impl From<types::ImageResponse> for ImageModelResponse {
+    fn from(value: types::ImageResponse) -> Self {
+        let images = value
+            .data
+            .into_iter()
+            .filter_map(|image| {
+                let data = match (image.b64_json, image.url) {
+                    (Some(b64), _) => DataContent::Base64(b64),
+                    (None, Some(url)) => DataContent::Url(url),
+                    (None, None) => return None,
+                };
+                Some(GeneratedImage::new(data).with_revised_prompt(image.revised_prompt))
+            })
+            .collect();
+
+        ImageModelResponse {
+            images,
+            usage: value.usage.map(|usage| Usage {
+                input_tokens: usage.input_tokens,
+                output_tokens: usage.output_tokens,
+                reasoning_tokens: None,
+                cached_tokens: None,
+            }),
+        }
+    }
+}
+
+impl From<SpeechModelOptions> for types::OpenAISpeechOptions {
+    fn from(value: SpeechModelOptions) -> Self {
+        types::OpenAISpeechOptions {
+            model: "".to_string(), // will be set in speech_model.rs
+            input: value.text,
+            voice: value.voice.unwrap_or_else(|| "alloy".to_string()),
+            response_format: value.format.map(|format| format.as_str().to_string()),
+            speed: value.speed,
+            instructions: value.instructions,
+        }
+    }
+}
+
+impl From<types::TranscriptionResponse> for TranscriptionModelResponse {
+    fn from(value: types::TranscriptionResponse) -> Self {
+        TranscriptionModelResponse {
+            text: value.text,
+            segments: value
+                .segments
+                .unwrap_or_default()
+                .into_iter()
+                .map(|segment| TranscriptionSegment {
+                    text: segment.text,
+                    start: segment.start,
+                    end: segment.end,
+                })
+                .collect(),
+            words: value
+                .words
+                .unwrap_or_default()
+                .into_iter()
+                .map(|word| TranscriptionSegment {
+                    text: word.word,
+                    start: word.start,
+                    end: word.end,
+                })
+                .collect(),
+            language: value.language,
+            duration: value.duration,
+        }
+    }
+}
+
+impl From<ModerationModelOptions> for types::OpenAIModerationOptions {
+    fn from(value: ModerationModelOptions) -> Self {
+        let input = if value.has_images() {
+            types::ModerationInput::MultiModal(
+                value
+                    .inputs
+                    .into_iter()
+                    .map(|input| match input {
+                        ModerationInput::Text(text) => types::ModerationContent::Text { text },
+                        ModerationInput::Image(image) => types::ModerationContent::ImageUrl {
+                            image_url: types::ModerationImageUrl {
+                                url: image.to_url(),
+                            },
+                        },
+                    })
+                    .collect(),
+            )
+        } else {
+            types::ModerationInput::Texts(
+                value
+                    .inputs
+                    .into_iter()
+                    .filter_map(|input| match input {
+                        ModerationInput::Text(text) => Some(text),
+                        ModerationInput::Image(_) => None,
+                    })
+                    .collect(),
+            )
+        };
+
+        types::OpenAIModerationOptions {
+            model: "".to_string(), // will be set in moderation_model.rs
+            input,
+        }
+    }
+}
+
+impl From<types::ModerationResponse> for ModerationModelResponse {
+    fn from(value: types::ModerationResponse) -> Self {
+        let results = value
+            .results
+            .into_iter()
+            .map(|result| {
+                let mut categories: Vec<ModerationCategory> = result
+                    .category_scores
+                    .into_iter()
+                    .filter_map(|(name, score)| {
+                        let flagged = result.categories.get(&name).copied().flatten();
+                        Some(ModerationCategory {
+                            score: score?,
+                            flagged: flagged.unwrap_or(false),
+                            name,
+                        })
+                    })
+                    .collect();
+                categories.sort_by(|a, b| a.name.cmp(&b.name));
+
+                ModerationResult {
+                    flagged: result.flagged,
+                    categories,
+                }
+            })
+            .collect();
+
+        ModerationModelResponse { results }
+    }
+}
+
 fn from_schema_to_response_format(schema: Schema) -> types::TextResponseFormat {
     let json = serde_json::to_value(schema).expect("Failed to serialize schema");
     types::TextResponseFormat::JsonSchema {
@@ -302,4 +512,123 @@ mod tests {
         assert_eq!(usage.cached_tokens, Some(0));
         assert_eq!(usage.reasoning_tokens, Some(0));
     }
+
+    #[test]
+    fn test_user_message_with_parts_to_input_item() {
+        use crate::core::messages::{DataContent, Message, UserMessage};
+
+        let msg = Message::User(
+            UserMessage::new("Describe these")
+                .with_image(DataContent::url("https://example.com/cat.png"), "image/png")
+                .with_file(DataContent::bytes(b"%PDF".to_vec()), "application/pdf"),
+        );
+
+        let item: Option<InputItem> = msg.into();
+        let Some(InputItem::Item(MessageItem::InputMessage { content, .. })) = item else {
+            panic!("expected input message");
+        };
+
+        assert_eq!(content.len(), 3);
+        assert_eq!(
+            content[0],
+            ContentType::InputText {
+                text: "Describe these".to_string()
+            }
+        );
+        assert_eq!(
+            content[1],
+            ContentType::InputImage {
+                detail: ImageDetail::Auto,
+                file_id: None,
+                image_url: Some("https://example.com/cat.png".to_string()),
+            }
+        );
+        assert_eq!(
+            content[2],
+            ContentType::InputFile {
+                file_data: Some("data:application/pdf;base64,JVBERg==".to_string()),
+                filename: Some("file".to_string()),
+                file_url: None,
+                file_id: None,
+            }
+        );
+
+        let json = serde_json::to_value(&content[1]).unwrap();
+        assert_eq!(json["type"], "input_image");
+        assert_eq!(json["detail"], "auto");
+        assert!(json.get("file_id").is_none());
+    }
+
+    #[test]
+    fn test_tool_result_to_function_call_output() {
+        use crate::core::messages::{DataContent, Message, UserContentPart};
+        use crate::core::tools::ToolResultInfo;
+
+        let output_of = |result: &ToolResultInfo| {
+            let item: Option<InputItem> = Message::Tool(result.clone()).into();
+            match item {
+                Some(InputItem::Item(MessageItem::FunctionCallOutput { output, .. })) => output,
+                other => panic!("expected function call output, got {other:?}"),
+            }
+        };
+
+        let mut result = ToolResultInfo::new("screenshot");
+        result.id("call_1");
+        result.output(vec![
+            UserContentPart::Text("the page".to_string()),
+            UserContentPart::image(
+                DataContent::url("https://example.com/page.png"),
+                "image/png",
+            ),
+        ]);
+        assert_eq!(
+            output_of(&result),
+            FunctionCallOutput::Content(vec![
+                ContentType::InputText {
+                    text: "the page".to_string()
+                },
+                ContentType::InputImage {
+                    detail: ImageDetail::Auto,
+                    file_id: None,
+                    image_url: Some("https://example.com/page.png".to_string()),
+                },
+            ])
+        );
+
+        result.output("plain");
+        assert_eq!(
+            output_of(&result),
+            FunctionCallOutput::Text("plain".to_string())
+        );
+
+        result.error("timeout");
+        assert_eq!(
+            output_of(&result),
+            FunctionCallOutput::Text("Tool error: timeout".to_string())
+        );
+    }
+
+    #[test]
+    fn test_tool_choice_conversion() {
+        use crate::core::language_model::ToolChoice as LMToolChoice;
+
+        let choice: ToolChoice = LMToolChoice::Required.into();
+        assert_eq!(serde_json::to_value(choice).unwrap(), "required");
+
+        let choice: ToolChoice = LMToolChoice::tool("extract").into();
+        assert_eq!(
+            serde_json::to_value(choice).unwrap(),
+            serde_json::json!({ "type": "function", "name": "extract" })
+        );
+    }
+
+    #[test]
+    fn test_tool_choice_omitted_without_tools() {
+        let options = LanguageModelOptions {
+            tool_choice: Some(crate::core::language_model::ToolChoice::Required),
+            ..Default::default()
+        };
+        let lm_options: OpenAILanguageModelOptions = options.into();
+        assert!(lm_options.tool_choice.is_none());
+    }
 }
@@ -3,7 +3,7 @@
 use crate::{
     core::{
         capabilities::ModelName,
-        client::EmbeddingClient,
+        client::JsonClient,
         embedding_model::{EmbeddingModel, EmbeddingModelOptions, EmbeddingModelResponse},
     },
     error::Result,
@@ -37,7 +37,9 @@ impl<M: ModelName> EmbeddingModel for OpenAI<M> {
         model.embedding_options = options;
 
         // Send the request
-        let response = model.send(&model.settings.base_url).await?;
+        let response = model
+            .send(&model.settings.base_url, &model.settings.retry_policy)
+            .await?;
 
         // Extract embeddings from response
         Ok(response.data.into_iter().map(|e| e.embedding).collect())
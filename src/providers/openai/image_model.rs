@@ -0,0 +1,148 @@
+//! Image model implementation for the OpenAI provider.
+
+use crate::{
+    core::{
+        capabilities::ModelName,
+        client::JsonClient,
+        image_model::{ImageModel, ImageModelOptions, ImageModelResponse},
+    },
+    error::Result,
+    providers::openai::{
+        OpenAI,
+        client::{ImageResponse, OpenAIImageOptions},
+        settings::OpenAIProviderSettings,
+    },
+};
+use async_trait::async_trait;
+use reqwest::header::CONTENT_TYPE;
+
+#[async_trait]
+impl<M: ModelName> ImageModel for OpenAI<M> {
+    async fn generate_image(&self, options: ImageModelOptions) -> Result<ImageModelResponse> {
+        // Convert options to OpenAI image options
+        let mut options: OpenAIImageOptions = options.into();
+
+        // Set the model name from the current model
+        options.model = self.lm_options.model.clone();
+
+        let client = OpenAIImageClient {
+            settings: self.settings.clone(),
+            options,
+        };
+
+        let response = client
+            .send(&self.settings.base_url, &self.settings.retry_policy)
+            .await?;
+
+        Ok(response.into())
+    }
+}
+
+/// A `/v1/images/generations` request.
+struct OpenAIImageClient {
+    settings: OpenAIProviderSettings,
+    options: OpenAIImageOptions,
+}
+
+impl JsonClient for OpenAIImageClient {
+    type Response = ImageResponse;
+
+    fn path(&self) -> String {
+        "/v1/images/generations".to_string()
+    }
+
+    fn method(&self) -> reqwest::Method {
+        reqwest::Method::POST
+    }
+
+    fn http_client(&self) -> reqwest::Client {
+        self.settings.http_client.clone()
+    }
+
+    fn headers(&self) -> reqwest::header::HeaderMap {
+        let mut headers = reqwest::header::HeaderMap::new();
+        headers.insert(CONTENT_TYPE, "application/json".parse().unwrap());
+        headers.insert(
+            "Authorization",
+            format!("Bearer {}", self.settings.api_key).parse().unwrap(),
+        );
+        headers
+    }
+
+    fn query_params(&self) -> Vec<(&str, &str)> {
+        Vec::new()
+    }
+
+    fn body(&self) -> reqwest::Body {
+        let body = serde_json::to_string(&self.options).unwrap();
+        reqwest::Body::from(body)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::client::stub_server::StubServer;
+    use crate::core::image_model::ImageModelRequest;
+    use crate::core::messages::DataContent;
+    use crate::providers::openai::GptImage1;
+    use serde_json::json;
+
+    #[tokio::test]
+    async fn test_generate_image() {
+        let mut server = StubServer::json(json!({
+            "created": 1713833628,
+            "data": [
+                { "b64_json": "iVBORw0KGgo=", "revised_prompt": "A red fox, digital art" },
+                { "url": "https://example.com/fox.png" }
+            ],
+            "usage": { "input_tokens": 10, "output_tokens": 4160, "total_tokens": 4170 }
+        }))
+        .await;
+
+        let model = OpenAI::<GptImage1>::builder()
+            .base_url(server.url.clone())
+            .api_key("test-key")
+            .build()
+            .unwrap();
+
+        let response = ImageModelRequest::builder()
+            .model(model)
+            .prompt("A red fox")
+            .size("1024x1024")
+            .n(2)
+            .quality("high")
+            .build()
+            .generate_image()
+            .await
+            .unwrap();
+
+        let request = server.request().await;
+        assert_eq!(request.method, "POST");
+        assert_eq!(request.path, "/v1/images/generations");
+        assert_eq!(request.headers["authorization"], "Bearer test-key");
+        assert_eq!(
+            request.json(),
+            json!({
+                "model": "gpt-image-1",
+                "prompt": "A red fox",
+                "n": 2,
+                "size": "1024x1024",
+                "quality": "high"
+            })
+        );
+
+        assert_eq!(response.images.len(), 2);
+        assert_eq!(response.images[0].data, DataContent::base64("iVBORw0KGgo="));
+        assert_eq!(response.images[0].media_type.as_deref(), Some("image/png"));
+        assert_eq!(
+            response.images[0].revised_prompt.as_deref(),
+            Some("A red fox, digital art")
+        );
+        assert_eq!(
+            response.images[1].url(),
+            Some("https://example.com/fox.png")
+        );
+        assert_eq!(response.usage.unwrap().output_tokens, Some(4160));
+    }
+}
@@ -15,6 +15,7 @@ use crate::{
 };
 use async_trait::async_trait;
 use futures::StreamExt;
+use std::collections::HashMap;
 
 #[async_trait]
 impl<M: ModelName> LanguageModel for OpenAI<M> {
@@ -28,13 +29,15 @@ impl<M: ModelName> LanguageModel for OpenAI<M> {
         &mut self,
         options: LanguageModelOptions,
     ) -> Result<LanguageModelResponse> {
+        let retry_policy = self.settings.retry_policy.for_request(&options);
         let mut options: OpenAILanguageModelOptions = options.into();
 
         options.model = self.lm_options.model.clone();
 
         self.lm_options = options;
 
-        let response: client::OpenAIResponse = self.send(&self.settings.base_url).await?;
+        let response: client::OpenAIResponse =
+            self.send(&self.settings.base_url, &retry_policy).await?;
 
         let mut collected: Vec<LanguageModelResponseContentType> = Vec::new();
 
@@ -70,6 +73,7 @@ impl<M: ModelName> LanguageModel for OpenAI<M> {
 
     /// Streams text using the OpenAI provider.
     async fn stream_text(&mut self, options: LanguageModelOptions) -> Result<ProviderStream> {
+        let retry_policy = self.settings.retry_policy.for_request(&options);
         let mut options: OpenAILanguageModelOptions = options.into();
 
         options.model = self.lm_options.model.to_string();
@@ -77,30 +81,14 @@ impl<M: ModelName> LanguageModel for OpenAI<M> {
 
         self.lm_options = options;
 
-        // Retry logic for rate limiting
-        let max_retries = 5;
-        let mut retry_count = 0;
-        let mut wait_time = std::time::Duration::from_secs(1);
+        let openai_stream = self
+            .send_and_stream(&self.settings.base_url, &retry_policy)
+            .await?;
 
-        let openai_stream = loop {
-            match self.send_and_stream(&self.settings.base_url).await {
-                Ok(stream) => break stream,
-                Err(crate::error::Error::ApiError {
-                    status_code: Some(status),
-                    ..
-                }) if status == reqwest::StatusCode::TOO_MANY_REQUESTS
-                    && retry_count < max_retries =>
-                {
-                    retry_count += 1;
-                    tokio::time::sleep(wait_time).await;
-                    wait_time *= 2; // Exponential backoff
-                    continue;
-                }
-                Err(e) => return Err(e),
-            }
-        };
+        // Function call ids by output item id, for attributing argument deltas
+        let mut call_ids: HashMap<String, String> = HashMap::new();
 
-        let stream = openai_stream.map(|evt_res| match evt_res {
+        let stream = openai_stream.map(move |evt_res| match evt_res {
             Ok(client::OpenAiStreamEvent::ResponseOutputTextDelta { delta, .. }) => {
                 Ok(vec![LanguageModelStreamChunk::Delta(
                     LanguageModelStreamChunkType::Text(delta),
@@ -111,6 +99,30 @@ impl<M: ModelName> LanguageModel for OpenAI<M> {
                     LanguageModelStreamChunkType::Reasoning(delta),
                 )])
             }
+            Ok(client::OpenAiStreamEvent::ResponseOutputItemAdded {
+                item:
+                    types::MessageItem::FunctionCall {
+                        call_id, name, id, ..
+                    },
+                ..
+            }) => {
+                if let Some(item_id) = id {
+                    call_ids.insert(item_id, call_id.clone());
+                }
+                Ok(vec![LanguageModelStreamChunk::Delta(
+                    LanguageModelStreamChunkType::ToolInputStart { id: call_id, name },
+                )])
+            }
+            Ok(client::OpenAiStreamEvent::ResponseFunctionCallArgumentsDelta {
+                item_id,
+                delta,
+                ..
+            }) => Ok(vec![LanguageModelStreamChunk::Delta(
+                LanguageModelStreamChunkType::ToolInputDelta {
+                    id: call_ids.get(&item_id).cloned().unwrap_or(item_id),
+                    delta,
+                },
+            )]),
             Ok(client::OpenAiStreamEvent::ResponseCompleted { response, .. }) => {
                 let mut result: Vec<LanguageModelStreamChunk> = Vec::new();
 
@@ -126,7 +138,7 @@ impl<M: ModelName> LanguageModel for OpenAI<M> {
                             {
                                 result.push(LanguageModelStreamChunk::Done(AssistantMessage {
                                     content: LanguageModelResponseContentType::new(text.clone()),
-                                    usage: Some(usage.clone()),
+                                    usage: None,
                                 }));
                             }
                         }
@@ -139,7 +151,7 @@ impl<M: ModelName> LanguageModel for OpenAI<M> {
                                         content: text.to_owned(),
                                         extensions: crate::extensions::Extensions::default(),
                                     },
-                                    usage: Some(usage.clone()),
+                                    usage: None,
                                 }));
                             }
                         }
@@ -157,7 +169,7 @@ impl<M: ModelName> LanguageModel for OpenAI<M> {
 
                             result.push(LanguageModelStreamChunk::Done(AssistantMessage {
                                 content: LanguageModelResponseContentType::ToolCall(tool_info),
-                                usage: Some(usage.clone()),
+                                usage: None,
                             }));
                         }
 
@@ -165,6 +177,11 @@ impl<M: ModelName> LanguageModel for OpenAI<M> {
                     }
                 }
 
+                // The usage covers the whole response, so only the last message carries it.
+                if let Some(LanguageModelStreamChunk::Done(message)) = result.last_mut() {
+                    message.usage = Some(usage);
+                }
+
                 Ok(result)
             }
             Ok(client::OpenAiStreamEvent::ResponseIncomplete { response, .. }) => {
@@ -0,0 +1,206 @@
+//! Moderation model implementation for the OpenAI provider.
+
+use crate::{
+    core::{
+        capabilities::ModelName,
+        client::JsonClient,
+        moderation_model::{ModerationModel, ModerationModelOptions, ModerationModelResponse},
+    },
+    error::Result,
+    providers::openai::{
+        OpenAI,
+        client::{ModerationResponse, OpenAIModerationOptions},
+        settings::OpenAIProviderSettings,
+    },
+};
+use async_trait::async_trait;
+use reqwest::header::CONTENT_TYPE;
+
+#[async_trait]
+impl<M: ModelName> ModerationModel for OpenAI<M> {
+    async fn moderate(&self, options: ModerationModelOptions) -> Result<ModerationModelResponse> {
+        // Convert options to OpenAI moderation options
+        let mut options: OpenAIModerationOptions = options.into();
+
+        // Set the model name from the current model
+        options.model = self.lm_options.model.clone();
+
+        let client = OpenAIModerationClient {
+            settings: self.settings.clone(),
+            options,
+        };
+
+        let response = client
+            .send(&self.settings.base_url, &self.settings.retry_policy)
+            .await?;
+
+        Ok(response.into())
+    }
+}
+
+/// A `/v1/moderations` request.
+struct OpenAIModerationClient {
+    settings: OpenAIProviderSettings,
+    options: OpenAIModerationOptions,
+}
+
+impl JsonClient for OpenAIModerationClient {
+    type Response = ModerationResponse;
+
+    fn path(&self) -> String {
+        "/v1/moderations".to_string()
+    }
+
+    fn method(&self) -> reqwest::Method {
+        reqwest::Method::POST
+    }
+
+    fn http_client(&self) -> reqwest::Client {
+        self.settings.http_client.clone()
+    }
+
+    fn headers(&self) -> reqwest::header::HeaderMap {
+        let mut headers = reqwest::header::HeaderMap::new();
+        headers.insert(CONTENT_TYPE, "application/json".parse().unwrap());
+        headers.insert(
+            "Authorization",
+            format!("Bearer {}", self.settings.api_key).parse().unwrap(),
+        );
+        headers
+    }
+
+    fn query_params(&self) -> Vec<(&str, &str)> {
+        Vec::new()
+    }
+
+    fn body(&self) -> reqwest::Body {
+        let body = serde_json::to_string(&self.options).unwrap();
+        reqwest::Body::from(body)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::client::stub_server::StubServer;
+    use crate::core::moderation_model::{ModerationCategory, ModerationModelRequest};
+    use crate::providers::openai::{OmniModerationLatest, TextModerationLatest};
+    use serde_json::json;
+
+    #[tokio::test]
+    async fn test_moderate_texts() {
+        let mut server = StubServer::json(json!({
+            "id": "modr-123",
+            "model": "text-moderation-007",
+            "results": [
+                {
+                    "flagged": true,
+                    "categories": { "harassment": true, "violence": true, "illicit": null },
+                    "category_scores": { "harassment": 0.71, "violence": 0.93, "illicit": null }
+                },
+                {
+                    "flagged": false,
+                    "categories": { "harassment": false, "violence": false, "illicit": null },
+                    "category_scores": { "harassment": 0.01, "violence": 0.02, "illicit": null }
+                }
+            ]
+        }))
+        .await;
+
+        let model = OpenAI::<TextModerationLatest>::builder()
+            .base_url(server.url.clone())
+            .api_key("test-key")
+            .build()
+            .unwrap();
+
+        let response = ModerationModelRequest::builder()
+            .model(model)
+            .texts(["I will hurt you", "Good morning"])
+            .build()
+            .moderate()
+            .await
+            .unwrap();
+
+        let request = server.request().await;
+        assert_eq!(request.method, "POST");
+        assert_eq!(request.path, "/v1/moderations");
+        assert_eq!(request.headers["authorization"], "Bearer test-key");
+        assert_eq!(
+            request.json(),
+            json!({
+                "model": "text-moderation-latest",
+                "input": ["I will hurt you", "Good morning"]
+            })
+        );
+
+        assert!(response.flagged());
+        assert_eq!(
+            response.flagged_categories(),
+            vec!["harassment", "violence"]
+        );
+        assert_eq!(
+            response.results[1].categories,
+            vec![
+                ModerationCategory {
+                    name: "harassment".to_string(),
+                    flagged: false,
+                    score: 0.01,
+                },
+                ModerationCategory {
+                    name: "violence".to_string(),
+                    flagged: false,
+                    score: 0.02,
+                },
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_moderate_image() {
+        let mut server = StubServer::json(json!({
+            "id": "modr-456",
+            "model": "omni-moderation-latest",
+            "results": [{
+                "flagged": false,
+                "categories": { "violence/graphic": false },
+                "category_scores": { "violence/graphic": 0.004 }
+            }]
+        }))
+        .await;
+
+        let model = OpenAI::<OmniModerationLatest>::builder()
+            .base_url(server.url.clone())
+            .api_key("test-key")
+            .build()
+            .unwrap();
+
+        let response = ModerationModelRequest::builder()
+            .model(model)
+            .text("Is this fine?")
+            .image(vec![0x89, b'P', b'N', b'G'], "image/png")
+            .build()
+            .moderate()
+            .await
+            .unwrap();
+
+        let request = server.request().await;
+        assert_eq!(
+            request.json(),
+            json!({
+                "model": "omni-moderation-latest",
+                "input": [
+                    { "type": "text", "text": "Is this fine?" },
+                    { "type": "image_url", "image_url": { "url": "data:image/png;base64,iVBORw==" } }
+                ]
+            })
+        );
+        assert!(!response.flagged());
+        assert_eq!(
+            response.results[0]
+                .category("violence/graphic")
+                .unwrap()
+                .score,
+            0.004
+        );
+    }
+}
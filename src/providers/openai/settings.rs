@@ -20,6 +20,12 @@ pub struct OpenAIProviderSettings {
     /// This is useful for connecting to endpoints that use a different path,
     /// such as OpenAI Codex (`/responses`).
     pub path: Option<String>,
+
+    /// The HTTP client used for requests. Defaults to the client shared by all providers.
+    pub http_client: reqwest::Client,
+
+    /// The policy for retrying failed requests.
+    pub retry_policy: crate::core::client::RetryPolicy,
 }
 
 impl Default for OpenAIProviderSettings {
@@ -30,6 +36,8 @@ impl Default for OpenAIProviderSettings {
             base_url: "https://api.openai.com".to_string(),
             api_key: std::env::var("OPENAI_API_KEY").unwrap_or_default(),
             path: None,
+            http_client: crate::core::client::shared_http_client(),
+            retry_policy: crate::core::client::RetryPolicy::default(),
         }
     }
 }
@@ -0,0 +1,218 @@
+//! Speech model implementation for the OpenAI provider.
+
+use crate::{
+    core::{
+        capabilities::{AudioOutputSupport, ModelName},
+        client::SpeechClient,
+        speech_model::{SpeechModel, SpeechModelOptions, SpeechModelResponse, SpeechModelStream},
+    },
+    error::{Error, Result},
+    providers::openai::{OpenAI, client::OpenAISpeechOptions, settings::OpenAIProviderSettings},
+};
+use async_trait::async_trait;
+use futures::StreamExt;
+use reqwest::header::CONTENT_TYPE;
+
+impl<M: ModelName> OpenAI<M> {
+    /// Sends a `/v1/audio/speech` request and returns the unread response and the
+    /// media type of the audio.
+    async fn send_speech(
+        &self,
+        options: SpeechModelOptions,
+    ) -> Result<(reqwest::Response, &'static str)> {
+        let media_type = options.format.unwrap_or_default().media_type();
+
+        // Convert options to OpenAI speech options
+        let mut options: OpenAISpeechOptions = options.into();
+
+        // Set the model name from the current model
+        options.model = self.lm_options.model.clone();
+
+        let client = OpenAISpeechClient {
+            settings: self.settings.clone(),
+            options,
+        };
+
+        let response = client
+            .send(&self.settings.base_url, &self.settings.retry_policy)
+            .await?;
+
+        Ok((response, media_type))
+    }
+}
+
+#[async_trait]
+impl<M: ModelName> SpeechModel for OpenAI<M>
+where
+    OpenAI<M>: AudioOutputSupport,
+{
+    async fn generate_speech(&self, options: SpeechModelOptions) -> Result<SpeechModelResponse> {
+        let (response, media_type) = self.send_speech(options).await?;
+
+        let audio = response.bytes().await.map_err(|e| Error::ApiError {
+            status_code: e.status(),
+            details: format!("Failed to read response: {e}"),
+        })?;
+
+        Ok(SpeechModelResponse {
+            audio: audio.to_vec(),
+            media_type: media_type.to_string(),
+        })
+    }
+
+    async fn stream_speech(&self, options: SpeechModelOptions) -> Result<SpeechModelStream> {
+        let (response, media_type) = self.send_speech(options).await?;
+
+        let stream = response.bytes_stream().map(|chunk| {
+            chunk
+                .map(|bytes| bytes.to_vec())
+                .map_err(|e| Error::ApiError {
+                    status_code: e.status(),
+                    details: format!("Failed to read response: {e}"),
+                })
+        });
+
+        Ok(SpeechModelStream {
+            stream: Box::pin(stream),
+            media_type: media_type.to_string(),
+        })
+    }
+}
+
+/// A `/v1/audio/speech` request.
+struct OpenAISpeechClient {
+    settings: OpenAIProviderSettings,
+    options: OpenAISpeechOptions,
+}
+
+impl SpeechClient for OpenAISpeechClient {
+    fn path(&self) -> String {
+        "/v1/audio/speech".to_string()
+    }
+
+    fn method(&self) -> reqwest::Method {
+        reqwest::Method::POST
+    }
+
+    fn http_client(&self) -> reqwest::Client {
+        self.settings.http_client.clone()
+    }
+
+    fn headers(&self) -> reqwest::header::HeaderMap {
+        let mut headers = reqwest::header::HeaderMap::new();
+        headers.insert(CONTENT_TYPE, "application/json".parse().unwrap());
+        headers.insert(
+            "Authorization",
+            format!("Bearer {}", self.settings.api_key).parse().unwrap(),
+        );
+        headers
+    }
+
+    fn query_params(&self) -> Vec<(&str, &str)> {
+        Vec::new()
+    }
+
+    fn body(&self) -> reqwest::Body {
+        let body = serde_json::to_string(&self.options).unwrap();
+        reqwest::Body::from(body)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::client::stub_server::StubServer;
+    use crate::core::speech_model::{AudioFormat, SpeechModelRequest};
+    use crate::providers::openai::Gpt4oMiniTts;
+    use serde_json::json;
+
+    const AUDIO: &[u8] = b"RIFF\x24\x00\x00\x00WAVEfmt ";
+
+    fn model(server: &StubServer) -> OpenAI<Gpt4oMiniTts> {
+        OpenAI::<Gpt4oMiniTts>::builder()
+            .base_url(server.url.clone())
+            .api_key("test-key")
+            .build()
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_generate_speech() {
+        let mut server = StubServer::start(200, "audio/wav", AUDIO).await;
+
+        let response = SpeechModelRequest::builder()
+            .model(model(&server))
+            .text("Your order has shipped.")
+            .voice("coral")
+            .format(AudioFormat::Wav)
+            .speed(1.25)
+            .instructions("Speak in a cheerful tone.")
+            .build()
+            .generate_speech()
+            .await
+            .unwrap();
+
+        let request = server.request().await;
+        assert_eq!(request.path, "/v1/audio/speech");
+        assert_eq!(
+            request.json(),
+            json!({
+                "model": "gpt-4o-mini-tts",
+                "input": "Your order has shipped.",
+                "voice": "coral",
+                "response_format": "wav",
+                "speed": 1.25,
+                "instructions": "Speak in a cheerful tone."
+            })
+        );
+
+        assert_eq!(response.audio, AUDIO);
+        assert_eq!(response.media_type, "audio/wav");
+    }
+
+    #[tokio::test]
+    async fn test_stream_speech() {
+        let mut server = StubServer::start(200, "audio/mpeg", AUDIO).await;
+
+        let mut response = SpeechModelRequest::builder()
+            .model(model(&server))
+            .text("Hello")
+            .build()
+            .stream_speech()
+            .await
+            .unwrap();
+
+        let mut audio = Vec::new();
+        while let Some(chunk) = response.next().await {
+            audio.extend(chunk.unwrap());
+        }
+
+        let request = server.request().await;
+        assert_eq!(request.json()["voice"], "alloy");
+        assert_eq!(audio, AUDIO);
+        assert_eq!(response.media_type, "audio/mpeg");
+    }
+
+    #[tokio::test]
+    async fn test_generate_speech_error() {
+        let server = StubServer::start(
+            400,
+            "application/json",
+            r#"{"error":{"message":"Invalid voice"}}"#,
+        )
+        .await;
+
+        let result = SpeechModelRequest::builder()
+            .model(model(&server))
+            .text("Hello")
+            .voice("unknown")
+            .build()
+            .generate_speech()
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(Error::ApiError { status_code: Some(status), .. }) if status == 400
+        ));
+    }
+}
@@ -0,0 +1,176 @@
+//! Transcription model implementation for the OpenAI provider.
+
+use crate::{
+    core::{
+        capabilities::{AudioInputSupport, ModelName},
+        client::{JsonClient, multipart::MultipartForm},
+        transcription_model::{
+            TranscriptionModel, TranscriptionModelOptions, TranscriptionModelResponse,
+        },
+    },
+    error::Result,
+    providers::openai::{OpenAI, client::TranscriptionResponse, settings::OpenAIProviderSettings},
+};
+use async_trait::async_trait;
+use reqwest::header::CONTENT_TYPE;
+
+#[async_trait]
+impl<M: ModelName> TranscriptionModel for OpenAI<M>
+where
+    OpenAI<M>: AudioInputSupport,
+{
+    async fn transcribe(
+        &self,
+        options: TranscriptionModelOptions,
+    ) -> Result<TranscriptionModelResponse> {
+        let model = self.lm_options.model.clone();
+        let (filename, data) = options.audio_file()?;
+
+        // The GPT-4o transcription models only support the `json` format, which has
+        // no segments, language or duration.
+        let response_format = if model.starts_with("gpt-") {
+            "json"
+        } else {
+            "verbose_json"
+        };
+
+        let mut form = MultipartForm::new()
+            .file("file", filename, options.audio.media_type, data)
+            .text("model", model)
+            .text("response_format", response_format)
+            .text_opt("language", options.language)
+            .text_opt("prompt", options.prompt);
+        for granularity in &options.timestamp_granularities {
+            form = form.text("timestamp_granularities[]", granularity.as_str());
+        }
+        let (content_type, body) = form.finish();
+
+        let client = OpenAITranscriptionClient {
+            settings: self.settings.clone(),
+            content_type,
+            body,
+        };
+
+        let response = client
+            .send(&self.settings.base_url, &self.settings.retry_policy)
+            .await?;
+
+        Ok(response.into())
+    }
+}
+
+/// A `/v1/audio/transcriptions` request with a multipart body.
+struct OpenAITranscriptionClient {
+    settings: OpenAIProviderSettings,
+    content_type: String,
+    body: Vec<u8>,
+}
+
+impl JsonClient for OpenAITranscriptionClient {
+    type Response = TranscriptionResponse;
+
+    fn path(&self) -> String {
+        "/v1/audio/transcriptions".to_string()
+    }
+
+    fn method(&self) -> reqwest::Method {
+        reqwest::Method::POST
+    }
+
+    fn http_client(&self) -> reqwest::Client {
+        self.settings.http_client.clone()
+    }
+
+    fn headers(&self) -> reqwest::header::HeaderMap {
+        let mut headers = reqwest::header::HeaderMap::new();
+        headers.insert(CONTENT_TYPE, self.content_type.parse().unwrap());
+        headers.insert(
+            "Authorization",
+            format!("Bearer {}", self.settings.api_key).parse().unwrap(),
+        );
+        headers
+    }
+
+    fn query_params(&self) -> Vec<(&str, &str)> {
+        Vec::new()
+    }
+
+    fn body(&self) -> reqwest::Body {
+        reqwest::Body::from(self.body.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::client::stub_server::StubServer;
+    use crate::core::transcription_model::{
+        TimestampGranularity, TranscriptionModelRequest, TranscriptionSegment,
+    };
+    use crate::providers::openai::Whisper1;
+    use serde_json::json;
+
+    #[tokio::test]
+    async fn test_transcribe() {
+        let mut server = StubServer::json(json!({
+            "task": "transcribe",
+            "language": "english",
+            "duration": 2.5,
+            "text": "Thanks for calling.",
+            "segments": [
+                { "id": 0, "seek": 0, "start": 0.0, "end": 2.5, "text": " Thanks for calling.", "avg_logprob": -0.2 }
+            ],
+            "words": [
+                { "word": "Thanks", "start": 0.0, "end": 0.6 }
+            ]
+        }))
+        .await;
+
+        let model = OpenAI::<Whisper1>::builder()
+            .base_url(server.url.clone())
+            .api_key("test-key")
+            .build()
+            .unwrap();
+
+        let response = TranscriptionModelRequest::builder()
+            .model(model)
+            .audio(b"RIFF".to_vec(), "audio/wav")
+            .language("en")
+            .prompt("Customer support call")
+            .timestamp_granularity(TimestampGranularity::Word)
+            .timestamp_granularity(TimestampGranularity::Segment)
+            .build()
+            .transcribe()
+            .await
+            .unwrap();
+
+        let request = server.request().await;
+        assert_eq!(request.path, "/v1/audio/transcriptions");
+        assert!(request.headers["content-type"].starts_with("multipart/form-data; boundary="));
+        let body = String::from_utf8(request.body).unwrap();
+        for field in [
+            "name=\"file\"; filename=\"audio.wav\"\r\nContent-Type: audio/wav\r\n\r\nRIFF\r\n",
+            "name=\"model\"\r\n\r\nwhisper-1\r\n",
+            "name=\"response_format\"\r\n\r\nverbose_json\r\n",
+            "name=\"language\"\r\n\r\nen\r\n",
+            "name=\"prompt\"\r\n\r\nCustomer support call\r\n",
+            "name=\"timestamp_granularities[]\"\r\n\r\nword\r\n",
+            "name=\"timestamp_granularities[]\"\r\n\r\nsegment\r\n",
+        ] {
+            assert!(body.contains(field), "missing {field:?} in {body}");
+        }
+
+        assert_eq!(response.text, "Thanks for calling.");
+        assert_eq!(response.language.as_deref(), Some("english"));
+        assert_eq!(response.duration, Some(2.5));
+        assert_eq!(
+            response.segments,
+            vec![TranscriptionSegment {
+                text: " Thanks for calling.".to_string(),
+                start: 0.0,
+                end: 2.5,
+            }]
+        );
+        assert_eq!(response.words[0].text, "Thanks");
+    }
+}
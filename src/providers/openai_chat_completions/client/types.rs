@@ -75,7 +75,7 @@ pub(crate) struct ChatMessage {
     pub role: Role,
 
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub content: Option<String>,
+    pub content: Option<ChatMessageContent>,
 
     #[serde(skip_serializing_if = "Option::is_none")]
     pub name: Option<String>,
@@ -87,6 +87,58 @@ pub(crate) struct ChatMessage {
     pub tool_call_id: Option<String>,
 }
 
+/// Message content, either plain text or a list of content parts.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(untagged)]
+pub(crate) enum ChatMessageContent {
+    Text(String),
+    Parts(Vec<ChatContentPart>),
+}
+
+impl From<String> for ChatMessageContent {
+    fn from(value: String) -> Self {
+        Self::Text(value)
+    }
+}
+
+impl From<&str> for ChatMessageContent {
+    fn from(value: &str) -> Self {
+        Self::Text(value.to_string())
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub(crate) enum ChatContentPart {
+    Text { text: String },
+    ImageUrl { image_url: ImageUrl },
+    InputAudio { input_audio: InputAudio },
+    File { file: FileContent },
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub(crate) struct ImageUrl {
+    pub url: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub(crate) struct InputAudio {
+    pub data: String, // base64
+    pub format: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub(crate) struct FileContent {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub file_data: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub file_id: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub filename: Option<String>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 #[serde(rename_all = "lowercase")]
 pub(crate) enum Role {
@@ -384,3 +436,124 @@ pub(crate) struct EmbeddingResponse {
     /// Usage statistics.
     pub usage: Option<EmbeddingUsage>,
 }
+
+// ============================================================================
+// IMAGE TYPES
+// ============================================================================
+
+/// Request options for the OpenAI-compatible Images API.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub(crate) struct ImageOptions {
+    /// The model to use for image generation.
+    pub model: String,
+    /// The text description of the images.
+    pub prompt: String,
+    /// The number of images to generate.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub n: Option<usize>,
+    /// The size of the images, e.g. "1024x1024".
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub size: Option<String>,
+    /// The seed for reproducible images (for providers that support it).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub seed: Option<u64>,
+    /// The quality of the images.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub quality: Option<String>,
+    /// The style of the images.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub style: Option<String>,
+}
+
+/// A single generated image.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub(crate) struct Image {
+    /// The URL of the image.
+    pub url: Option<String>,
+    /// The base64 encoded image.
+    pub b64_json: Option<String>,
+    /// The prompt used after the provider revised it.
+    pub revised_prompt: Option<String>,
+}
+
+/// Response from the OpenAI-compatible Images API.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub(crate) struct ImageResponse {
+    /// The list of generated images.
+    pub data: Vec<Image>,
+}
+
+// ============================================================================
+// TRANSCRIPTION TYPES
+// ============================================================================
+
+/// Response from the OpenAI-compatible Transcriptions API in `verbose_json` format.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub(crate) struct TranscriptionResponse {
+    /// The transcribed text.
+    pub text: String,
+    /// The detected language.
+    pub language: Option<String>,
+    /// The duration of the audio in seconds.
+    pub duration: Option<f64>,
+    /// The segments of the transcription.
+    pub segments: Option<Vec<TranscriptionSegment>>,
+    /// The words of the transcription, if word timestamps were requested.
+    pub words: Option<Vec<TranscriptionWord>>,
+}
+
+/// A segment of a transcription.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub(crate) struct TranscriptionSegment {
+    /// The text of the segment.
+    pub text: String,
+    /// The start of the segment in seconds.
+    pub start: f64,
+    /// The end of the segment in seconds.
+    pub end: f64,
+}
+
+/// A word of a transcription.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub(crate) struct TranscriptionWord {
+    /// The word.
+    pub word: String,
+    /// The start of the word in seconds.
+    pub start: f64,
+    /// The end of the word in seconds.
+    pub end: f64,
+}
+
+// ============================================================================
+// RERANK TYPES
+// ============================================================================
+
+/// Request options for `/rerank` APIs in the format shared by Together AI, Jina and Cohere.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub(crate) struct RerankOptions {
+    /// The reranking model.
+    pub model: String,
+    /// The query to rank the documents against.
+    pub query: String,
+    /// The documents to rank.
+    pub documents: Vec<String>,
+    /// The number of most relevant documents to return.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub top_n: Option<usize>,
+}
+
+/// A ranked document.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub(crate) struct RerankResult {
+    /// The index of the document in the request.
+    pub index: usize,
+    /// The relevance score of the document.
+    pub relevance_score: f64,
+}
+
+/// Response from a `/rerank` API.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub(crate) struct RerankResponse {
+    /// The ranked documents.
+    pub results: Vec<RerankResult>,
+}
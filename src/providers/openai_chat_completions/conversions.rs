@@ -1,9 +1,9 @@
 //! Helper functions and conversions for the OpenAI Chat Completions provider.
 
 use crate::core::language_model::{
-    LanguageModelOptions, LanguageModelResponseContentType, ReasoningEffort, Usage,
+    LanguageModelOptions, LanguageModelResponseContentType, ReasoningEffort, ToolChoice, Usage,
 };
-use crate::core::messages::Message;
+use crate::core::messages::{Message, UserContentPart, UserMessage};
 use crate::core::tools::Tool as SdkTool;
 use crate::providers::openai_chat_completions::client::{self, types};
 
@@ -18,7 +18,7 @@ impl From<LanguageModelOptions> for client::ChatCompletionsOptions {
         if let Some(system_prompt) = options.system {
             messages.push(types::ChatMessage {
                 role: types::Role::System,
-                content: Some(system_prompt),
+                content: Some(system_prompt.into()),
                 name: None,
                 tool_calls: None,
                 tool_call_id: None,
@@ -79,13 +79,13 @@ impl From<LanguageModelOptions> for client::ChatCompletionsOptions {
             .to_string()
         });
 
-        let tool_choice = if tools.is_some() {
-            Some(types::ToolChoice::String("auto".to_string()))
-        } else {
-            None
-        };
+        let tool_choice = tools
+            .as_ref()
+            .map(|_| options.tool_choice.unwrap_or_default().into());
 
-        let parallel_tool_calls = if tools.is_some() { Some(true) } else { None };
+        let parallel_tool_calls = tools
+            .as_ref()
+            .map(|_| options.parallel_tool_calls.unwrap_or(true));
 
         client::ChatCompletionsOptions {
             model: "".to_string(),
@@ -128,14 +128,14 @@ impl From<Message> for types::ChatMessage {
         match msg {
             Message::System(s) => types::ChatMessage {
                 role: types::Role::System,
-                content: Some(s.content),
+                content: Some(s.content.into()),
                 name: None,
                 tool_calls: None,
                 tool_call_id: None,
             },
             Message::User(u) => types::ChatMessage {
                 role: types::Role::User,
-                content: Some(u.content),
+                content: Some(u.into()),
                 name: None,
                 tool_calls: None,
                 tool_call_id: None,
@@ -143,14 +143,14 @@ impl From<Message> for types::ChatMessage {
             Message::Assistant(a) => match a.content {
                 LanguageModelResponseContentType::Text(text) => types::ChatMessage {
                     role: types::Role::Assistant,
-                    content: Some(text),
+                    content: Some(text.into()),
                     name: None,
                     tool_calls: None,
                     tool_call_id: None,
                 },
                 LanguageModelResponseContentType::ToolCall(tool_info) => types::ChatMessage {
                     role: types::Role::Assistant,
-                    content: Some("".to_string()),
+                    content: Some("".into()),
                     name: None,
                     tool_calls: Some(vec![types::ToolCall {
                         id: tool_info.tool.id.clone(),
@@ -167,7 +167,7 @@ impl From<Message> for types::ChatMessage {
                     // Include as text with prefix
                     types::ChatMessage {
                         role: types::Role::Assistant,
-                        content: Some(format!("[Reasoning]: {content}")),
+                        content: Some(format!("[Reasoning]: {content}").into()),
                         name: None,
                         tool_calls: None,
                         tool_call_id: None,
@@ -184,10 +184,11 @@ impl From<Message> for types::ChatMessage {
             Message::Tool(tool_result) => types::ChatMessage {
                 role: types::Role::Tool,
                 content: Some(
-                    tool_result
-                        .output
-                        .unwrap_or_else(|e| serde_json::Value::String(e.to_string()))
-                        .to_string(),
+                    match tool_result.output {
+                        Ok(output) => output.to_text(),
+                        Err(error) => error.to_string(),
+                    }
+                    .into(),
                 ),
                 name: Some(tool_result.tool.name),
                 tool_calls: None,
@@ -195,7 +196,7 @@ impl From<Message> for types::ChatMessage {
             },
             Message::Developer(d) => types::ChatMessage {
                 role: types::Role::Developer,
-                content: Some(d),
+                content: Some(d.into()),
                 name: None,
                 tool_calls: None,
                 tool_call_id: None,
@@ -204,6 +205,93 @@ impl From<Message> for types::ChatMessage {
     }
 }
 
+// ============================================================================
+// SDK ToolChoice -> ChatCompletions ToolChoice
+// ============================================================================
+
+impl From<ToolChoice> for types::ToolChoice {
+    fn from(choice: ToolChoice) -> Self {
+        match choice {
+            ToolChoice::Auto => types::ToolChoice::String("auto".to_string()),
+            ToolChoice::None => types::ToolChoice::String("none".to_string()),
+            ToolChoice::Required => types::ToolChoice::String("required".to_string()),
+            ToolChoice::Tool(name) => types::ToolChoice::Specific(types::ToolChoiceSpecific {
+                type_: "function".to_string(),
+                function: types::FunctionChoice { name },
+            }),
+        }
+    }
+}
+
+// ============================================================================
+// SDK UserMessage -> ChatMessageContent
+// ============================================================================
+
+impl From<UserMessage> for types::ChatMessageContent {
+    fn from(user: UserMessage) -> Self {
+        // plain text messages keep the simple string form
+        if user.parts.is_empty() {
+            return types::ChatMessageContent::Text(user.content);
+        }
+
+        types::ChatMessageContent::Parts(
+            user.into_parts()
+                .into_iter()
+                .filter_map(|part| match part {
+                    UserContentPart::Text(text) => Some(types::ChatContentPart::Text { text }),
+                    UserContentPart::Image(image) => Some(types::ChatContentPart::ImageUrl {
+                        image_url: types::ImageUrl {
+                            url: image.to_url(),
+                        },
+                    }),
+                    UserContentPart::Audio(audio) => match audio.data.to_base64() {
+                        Some(data) => Some(types::ChatContentPart::InputAudio {
+                            input_audio: types::InputAudio {
+                                data,
+                                format: audio_format(&audio.media_type),
+                            },
+                        }),
+                        None => {
+                            log::warn!("Chat Completions API does not support audio URLs");
+                            None
+                        }
+                    },
+                    // `data:` URLs are sent inline, other URLs are not supported.
+                    UserContentPart::File(file) => match file.data.to_base64() {
+                        Some(data) => Some(types::ChatContentPart::File {
+                            file: types::FileContent {
+                                file_data: Some(format!("data:{};base64,{data}", file.media_type)),
+                                file_id: None,
+                                filename: Some(file.filename.unwrap_or_else(|| "file".to_string())),
+                            },
+                        }),
+                        None => {
+                            log::warn!("Chat Completions API does not support file URLs");
+                            None
+                        }
+                    },
+                    UserContentPart::Video(_) => {
+                        log::warn!("Chat Completions API does not support video input parts");
+                        None
+                    }
+                })
+                .collect(),
+        )
+    }
+}
+
+/// Maps an audio media type to the format name expected by `input_audio`.
+fn audio_format(media_type: &str) -> String {
+    match media_type {
+        "audio/mpeg" | "audio/mp3" => "mp3".to_string(),
+        other => other
+            .strip_prefix("audio/")
+            .unwrap_or(other)
+            .trim_start_matches("x-")
+            .to_string(),
+    }
+}
+
 // ============================================================================
 // SDK Tool -> ChatCompletions Tool
 // ============================================================================
@@ -264,6 +352,7 @@ impl From<types::Usage> for Usage {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::core::messages::DataContent;
 
     #[test]
     fn test_message_conversion_system() {
@@ -271,7 +360,7 @@ mod tests {
         let chat_msg: types::ChatMessage = msg.into();
 
         assert_eq!(chat_msg.role, types::Role::System);
-        assert_eq!(chat_msg.content, Some("You are helpful".to_string()));
+        assert_eq!(chat_msg.content, Some("You are helpful".into()));
         assert!(chat_msg.tool_calls.is_none());
     }
 
@@ -281,7 +370,85 @@ mod tests {
         let chat_msg: types::ChatMessage = msg.into();
 
         assert_eq!(chat_msg.role, types::Role::User);
-        assert_eq!(chat_msg.content, Some("Hello".to_string()));
+        assert_eq!(chat_msg.content, Some("Hello".into()));
+    }
+
+    #[test]
+    fn test_message_conversion_user_with_parts() {
+        let msg = Message::User(
+            UserMessage::new("What is this?")
+                .with_image(DataContent::bytes(vec![1, 2, 3]), "image/png")
+                .with_audio(DataContent::base64("UklGRg=="), "audio/wav"),
+        );
+        let chat_msg: types::ChatMessage = msg.into();
+
+        assert_eq!(
+            chat_msg.content,
+            Some(types::ChatMessageContent::Parts(vec![
+                types::ChatContentPart::Text {
+                    text: "What is this?".to_string()
+                },
+                types::ChatContentPart::ImageUrl {
+                    image_url: types::ImageUrl {
+                        url: "data:image/png;base64,AQID".to_string()
+                    }
+                },
+                types::ChatContentPart::InputAudio {
+                    input_audio: types::InputAudio {
+                        data: "UklGRg==".to_string(),
+                        format: "wav".to_string()
+                    }
+                },
+            ]))
+        );
+
+        let json = serde_json::to_value(&chat_msg).unwrap();
+        assert_eq!(json["content"][1]["type"], "image_url");
+        assert_eq!(json["content"][2]["type"], "input_audio");
+    }
+
+    #[test]
+    fn test_message_conversion_user_with_files() {
+        let msg = Message::User(
+            UserMessage::new("Summarize")
+                .with_file(
+                    DataContent::url("data:application/pdf;base64,JVBERg=="),
+                    "application/pdf",
+                )
+                .with_file(
+                    DataContent::url("https://example.com/doc.pdf"),
+                    "application/pdf",
+                ),
+        );
+        let chat_msg: types::ChatMessage = msg.into();
+
+        assert_eq!(
+            chat_msg.content,
+            Some(types::ChatMessageContent::Parts(vec![
+                types::ChatContentPart::Text {
+                    text: "Summarize".to_string()
+                },
+                types::ChatContentPart::File {
+                    file: types::FileContent {
+                        file_data: Some("data:application/pdf;base64,JVBERg==".to_string()),
+                        file_id: None,
+                        filename: Some("file".to_string()),
+                    }
+                },
+            ]))
+        );
+    }
+
+    #[test]
+    fn test_tool_choice_specific() {
+        let choice: types::ToolChoice = ToolChoice::tool("extract").into();
+        assert_eq!(
+            serde_json::to_value(choice).unwrap(),
+            serde_json::json!({ "type": "function", "function": { "name": "extract" } })
+        );
+
+        let choice: types::ToolChoice = ToolChoice::None.into();
+        assert_eq!(serde_json::to_value(choice).unwrap(), "none");
     }
 
     #[test]
@@ -3,7 +3,7 @@
 use crate::{
     core::{
         capabilities::ModelName,
-        client::EmbeddingClient,
+        client::JsonClient,
         embedding_model::{EmbeddingModel, EmbeddingModelOptions, EmbeddingModelResponse},
     },
     error::Result,
@@ -13,8 +13,8 @@ use async_trait::async_trait;
 
 use super::client::types::{EmbeddingOptions, EmbeddingResponse};
 
-/// Implement EmbeddingClient trait for OpenAIChatCompletions
-impl<M: ModelName> EmbeddingClient for OpenAIChatCompletions<M> {
+/// Implement JsonClient trait for OpenAIChatCompletions embeddings
+impl<M: ModelName> JsonClient for OpenAIChatCompletions<M> {
     type Response = EmbeddingResponse;
 
     fn path(&self) -> String {
@@ -25,6 +25,10 @@ impl<M: ModelName> EmbeddingClient for OpenAIChatCompletions<M> {
         reqwest::Method::POST
     }
 
+    fn http_client(&self) -> reqwest::Client {
+        self.settings.http_client.clone()
+    }
+
     fn headers(&self) -> reqwest::header::HeaderMap {
         let mut headers = reqwest::header::HeaderMap::new();
         headers.insert(
@@ -70,7 +74,9 @@ impl<M: ModelName> OpenAIChatCompletions<M> {
             options: embedding_options,
         };
 
-        let response = embedding_client.send(&self.settings.base_url).await?;
+        let response = embedding_client
+            .send(&self.settings.base_url, &self.settings.retry_policy)
+            .await?;
 
         // Extract embeddings from response
         Ok(response.data.into_iter().map(|e| e.embedding).collect())
@@ -83,7 +89,7 @@ struct EmbeddingClientWrapper {
     options: EmbeddingOptions,
 }
 
-impl EmbeddingClient for EmbeddingClientWrapper {
+impl JsonClient for EmbeddingClientWrapper {
     type Response = EmbeddingResponse;
 
     fn path(&self) -> String {
@@ -94,6 +100,10 @@ impl EmbeddingClient for EmbeddingClientWrapper {
         reqwest::Method::POST
     }
 
+    fn http_client(&self) -> reqwest::Client {
+        self.settings.http_client.clone()
+    }
+
     fn headers(&self) -> reqwest::header::HeaderMap {
         let mut headers = reqwest::header::HeaderMap::new();
         headers.insert(
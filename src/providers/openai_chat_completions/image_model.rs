@@ -0,0 +1,106 @@
+//! Image model implementation for OpenAI Chat Completions API compatible providers.
+
+use crate::{
+    core::{
+        capabilities::ModelName,
+        client::JsonClient,
+        image_model::{GeneratedImage, ImageModel, ImageModelOptions, ImageModelResponse},
+        messages::DataContent,
+    },
+    error::Result,
+    providers::openai_chat_completions::OpenAIChatCompletions,
+};
+use async_trait::async_trait;
+
+use super::client::types::{ImageOptions, ImageResponse};
+
+impl<M: ModelName> OpenAIChatCompletions<M> {
+    /// Generates images using the OpenAI-compatible Images API.
+    pub async fn generate_image(&self, options: ImageModelOptions) -> Result<ImageModelResponse> {
+        let image_client = ImageClientWrapper {
+            settings: self.settings.clone(),
+            options: ImageOptions {
+                model: self.options.model.clone(),
+                prompt: options.prompt,
+                n: options.n,
+                size: options.size,
+                seed: options.seed,
+                quality: options.quality,
+                style: options.style,
+            },
+        };
+
+        let response = image_client
+            .send(&self.settings.base_url, &self.settings.retry_policy)
+            .await?;
+
+        let images = response
+            .data
+            .into_iter()
+            .filter_map(|image| {
+                let data = match (image.b64_json, image.url) {
+                    (Some(b64), _) => DataContent::Base64(b64),
+                    (None, Some(url)) => DataContent::Url(url),
+                    (None, None) => return None,
+                };
+                Some(GeneratedImage::new(data).with_revised_prompt(image.revised_prompt))
+            })
+            .collect();
+
+        Ok(ImageModelResponse {
+            images,
+            usage: None,
+        })
+    }
+}
+
+/// Temporary wrapper for image generation requests.
+struct ImageClientWrapper {
+    settings: super::settings::OpenAIChatCompletionsSettings,
+    options: ImageOptions,
+}
+
+impl JsonClient for ImageClientWrapper {
+    type Response = ImageResponse;
+
+    fn path(&self) -> String {
+        "images/generations".to_string()
+    }
+
+    fn method(&self) -> reqwest::Method {
+        reqwest::Method::POST
+    }
+
+    fn http_client(&self) -> reqwest::Client {
+        self.settings.http_client.clone()
+    }
+
+    fn headers(&self) -> reqwest::header::HeaderMap {
+        let mut headers = reqwest::header::HeaderMap::new();
+        headers.insert(
+            reqwest::header::CONTENT_TYPE,
+            "application/json".parse().unwrap(),
+        );
+        headers.insert(
+            reqwest::header::AUTHORIZATION,
+            format!("Bearer {}", self.settings.api_key).parse().unwrap(),
+        );
+        headers
+    }
+
+    fn query_params(&self) -> Vec<(&str, &str)> {
+        Vec::new()
+    }
+
+    fn body(&self) -> reqwest::Body {
+        let body = serde_json::to_string(&self.options).unwrap();
+        reqwest::Body::from(body)
+    }
+}
+
+#[async_trait]
+impl<M: ModelName> ImageModel for OpenAIChatCompletions<M> {
+    async fn generate_image(&self, options: ImageModelOptions) -> Result<ImageModelResponse> {
+        self.generate_image(options).await
+    }
+}
@@ -24,18 +24,20 @@ impl<M: ModelName> LanguageModel for OpenAIChatCompletions<M> {
         &mut self,
         options: LanguageModelOptions,
     ) -> Result<LanguageModelResponse> {
+        let retry_policy = self.settings.retry_policy.for_request(&options);
         let mut options: client::ChatCompletionsOptions = options.into();
         options.model = self.options.model.clone();
         self.options = options;
 
-        let response: types::ChatCompletionsResponse = self.send(&self.settings.base_url).await?;
+        let response: types::ChatCompletionsResponse =
+            self.send(&self.settings.base_url, &retry_policy).await?;
 
         // Convert choices to LanguageModelResponse
         let mut contents = Vec::new();
 
         for choice in response.choices {
             // Handle text content
-            if let Some(text) = choice.message.content
+            if let Some(types::ChatMessageContent::Text(text)) = choice.message.content
                 && !text.is_empty()
             {
                 contents.push(LanguageModelResponseContentType::Text(text));
@@ -62,6 +64,7 @@ impl<M: ModelName> LanguageModel for OpenAIChatCompletions<M> {
     }
 
     async fn stream_text(&mut self, options: LanguageModelOptions) -> Result<ProviderStream> {
+        let retry_policy = self.settings.retry_policy.for_request(&options);
         let mut options: client::ChatCompletionsOptions = options.into();
         options.model = self.options.model.clone();
         options.stream = Some(true);
@@ -71,11 +74,14 @@ impl<M: ModelName> LanguageModel for OpenAIChatCompletions<M> {
         // open ai compatible providers
         self.options = options;
 
-        let stream = self.send_and_stream(&self.settings.base_url).await?;
+        let stream = self
+            .send_and_stream(&self.settings.base_url, &retry_policy)
+            .await?;
 
         // State for accumulating tool calls across chunks
-        use std::collections::HashMap;
-        let mut accumulated_tool_calls: HashMap<u32, (String, String, String)> = HashMap::new();
+        // Keyed by the call index so the calls are emitted in call order
+        use std::collections::BTreeMap;
+        let mut accumulated_tool_calls: BTreeMap<u32, (String, String, String)> = BTreeMap::new();
 
         // Map stream events to SDK stream chunks
         let stream = stream.map(move |evt_res| match evt_res {
@@ -117,13 +123,25 @@ impl<M: ModelName> LanguageModel for OpenAIChatCompletions<M> {
 
                             // Accumulate name and arguments
                             if let Some(function) = tool_call.function {
+                                // The first delta of a call carries its name
                                 if let Some(name) = function.name {
                                     entry.1 = name;
+                                    results.push(LanguageModelStreamChunk::Delta(
+                                        LanguageModelStreamChunkType::ToolInputStart {
+                                            id: entry.0.clone(),
+                                            name: entry.1.clone(),
+                                        },
+                                    ));
                                 }
-                                if let Some(args) = function.arguments {
+                                if let Some(args) = function.arguments
+                                    && !args.is_empty()
+                                {
                                     entry.2.push_str(&args);
                                     results.push(LanguageModelStreamChunk::Delta(
-                                        LanguageModelStreamChunkType::ToolCall(args),
+                                        LanguageModelStreamChunkType::ToolInputDelta {
+                                            id: entry.0.clone(),
+                                            delta: args,
+                                        },
                                     ));
                                 }
                             }
@@ -141,8 +159,12 @@ impl<M: ModelName> LanguageModel for OpenAIChatCompletions<M> {
                                 }));
                             }
                             "tool_calls" | "function_call" => {
-                                // Send accumulated tool calls
-                                for (id, name, args) in accumulated_tool_calls.values() {
+                                // Send accumulated tool calls, with the usage of the turn
+                                // on the last one only
+                                let last = accumulated_tool_calls.len().saturating_sub(1);
+                                for (index, (id, name, args)) in
+                                    accumulated_tool_calls.values().enumerate()
+                                {
                                     let mut tool_info = ToolCallInfo::new(name.clone());
                                     tool_info.id(id.clone());
                                     tool_info.input(serde_json::from_str(args).unwrap_or_else(
@@ -153,7 +175,7 @@ impl<M: ModelName> LanguageModel for OpenAIChatCompletions<M> {
                                             content: LanguageModelResponseContentType::ToolCall(
                                                 tool_info,
                                             ),
-                                            usage: usage.clone(),
+                                            usage: if index == last { usage.clone() } else { None },
                                         },
                                     ));
                                 }
@@ -195,3 +217,73 @@ impl<M: ModelName> LanguageModel for OpenAIChatCompletions<M> {
         Ok(Box::pin(stream))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::DynamicModel;
+    use crate::core::client::stub_server::StubServer;
+    use serde_json::json;
+
+    #[tokio::test]
+    async fn test_stream_text_emits_tool_calls_in_call_order() {
+        let tool_calls: Vec<_> = (0..8)
+            .map(|index| {
+                json!({
+                    "index": index,
+                    "id": format!("call_{index}"),
+                    "type": "function",
+                    "function": { "name": "echo", "arguments": format!("{{\"n\":{index}}}") }
+                })
+            })
+            .collect();
+        let deltas = json!({
+            "id": "chatcmpl-1",
+            "object": "chat.completion.chunk",
+            "created": 1,
+            "model": "gpt-4o",
+            "choices": [{ "index": 0, "delta": { "tool_calls": tool_calls }, "finish_reason": null }]
+        });
+        let finish = json!({
+            "id": "chatcmpl-1",
+            "object": "chat.completion.chunk",
+            "created": 1,
+            "model": "gpt-4o",
+            "choices": [{ "index": 0, "delta": {}, "finish_reason": "tool_calls" }],
+            "usage": { "prompt_tokens": 5, "completion_tokens": 7, "total_tokens": 12 }
+        });
+        let body = format!("data: {deltas}\n\ndata: {finish}\n\ndata: [DONE]\n\n");
+        let server = StubServer::start(200, "text/event-stream", body).await;
+
+        let mut model = OpenAIChatCompletions::<DynamicModel>::model_name("gpt-4o");
+        model.settings.base_url = server.url.clone();
+        model.settings.api_key = "test-key".to_string();
+
+        let mut stream = model
+            .stream_text(LanguageModelOptions::default())
+            .await
+            .unwrap();
+        let mut ids = Vec::new();
+        let mut usages = Vec::new();
+        while let Some(chunks) = stream.next().await {
+            for chunk in chunks.unwrap() {
+                if let LanguageModelStreamChunk::Done(AssistantMessage {
+                    content: LanguageModelResponseContentType::ToolCall(info),
+                    usage,
+                }) = chunk
+                {
+                    ids.push(info.tool.id);
+                    usages.push(usage);
+                }
+            }
+        }
+
+        let expected: Vec<_> = (0..8).map(|index| format!("call_{index}")).collect();
+        assert_eq!(ids, expected);
+        // The usage of the turn is reported once, on the last tool call.
+        assert!(usages[..7].iter().all(Option::is_none));
+        let usage = usages[7].as_ref().expect("usage on the last tool call");
+        assert_eq!(usage.input_tokens, Some(5));
+        assert_eq!(usage.output_tokens, Some(7));
+    }
+}
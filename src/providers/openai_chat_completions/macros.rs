@@ -40,6 +40,12 @@ macro_rules! openai_compatible_settings {
 
                 /// Custom API path override.
                 pub path: Option<String>,
+
+                /// The HTTP client used for requests. Defaults to the client shared by all providers.
+                pub http_client: reqwest::Client,
+
+                /// The policy for retrying failed requests.
+                pub retry_policy: $crate::core::client::RetryPolicy,
             }
 
             impl Default for $settings_struct {
@@ -49,6 +55,8 @@ macro_rules! openai_compatible_settings {
                         base_url: $default_base_url.to_string(),
                         api_key: std::env::var($api_key_env).unwrap_or_default(),
                         path: None,
+                        http_client: $crate::core::client::shared_http_client(),
+                        retry_policy: $crate::core::client::RetryPolicy::default(),
                     }
                 }
             }
@@ -151,6 +159,124 @@ macro_rules! openai_compatible_embedding_model {
     };
 }
 
+/// Generates the image model implementation for an OpenAI-compatible provider.
+///
+/// # Arguments
+///
+/// * `$provider_struct` - The name of the provider struct (e.g., `TogetherAI`)
+#[macro_export]
+macro_rules! openai_compatible_image_model {
+    ($provider_struct:ident) => {
+        pub mod image_model {
+            //! Image model implementation for this provider.
+
+            use async_trait::async_trait;
+
+            use super::$provider_struct;
+            use $crate::{
+                Result,
+                core::{
+                    capabilities::ModelName,
+                    image_model::{ImageModel, ImageModelOptions, ImageModelResponse},
+                },
+            };
+
+            #[async_trait]
+            impl<M: ModelName> ImageModel for $provider_struct<M> {
+                async fn generate_image(
+                    &self,
+                    options: ImageModelOptions,
+                ) -> Result<ImageModelResponse> {
+                    // Delegate to OpenAIChatCompletions' image implementation
+                    self.inner.generate_image(options).await
+                }
+            }
+        }
+    };
+}
+
+/// Generates the reranking model implementation for an OpenAI-compatible provider
+/// with a `/rerank` endpoint.
+///
+/// # Arguments
+///
+/// * `$provider_struct` - The name of the provider struct (e.g., `TogetherAI`)
+#[macro_export]
+macro_rules! openai_compatible_reranking_model {
+    ($provider_struct:ident) => {
+        pub mod reranking_model {
+            //! Reranking model implementation for this provider.
+
+            use async_trait::async_trait;
+
+            use super::$provider_struct;
+            use $crate::{
+                Result,
+                core::{
+                    capabilities::ModelName,
+                    reranking_model::{
+                        RerankingModel, RerankingModelOptions, RerankingModelResponse,
+                    },
+                },
+            };
+
+            #[async_trait]
+            impl<M: ModelName> RerankingModel for $provider_struct<M> {
+                async fn rerank(
+                    &self,
+                    options: RerankingModelOptions,
+                ) -> Result<RerankingModelResponse> {
+                    // Delegate to OpenAIChatCompletions' reranking implementation
+                    self.inner.rerank(options).await
+                }
+            }
+        }
+    };
+}
+
+/// Generates the transcription model implementation for an OpenAI-compatible provider.
+///
+/// The implementation is available for models with `AudioInputSupport`.
+///
+/// # Arguments
+///
+/// * `$provider_struct` - The name of the provider struct (e.g., `Groq`)
+#[macro_export]
+macro_rules! openai_compatible_transcription_model {
+    ($provider_struct:ident) => {
+        pub mod transcription_model {
+            //! Transcription model implementation for this provider.
+
+            use async_trait::async_trait;
+
+            use super::$provider_struct;
+            use $crate::{
+                Result,
+                core::{
+                    capabilities::{AudioInputSupport, ModelName},
+                    transcription_model::{
+                        TranscriptionModel, TranscriptionModelOptions, TranscriptionModelResponse,
+                    },
+                },
+            };
+
+            #[async_trait]
+            impl<M: ModelName> TranscriptionModel for $provider_struct<M>
+            where
+                $provider_struct<M>: AudioInputSupport,
+            {
+                async fn transcribe(
+                    &self,
+                    options: TranscriptionModelOptions,
+                ) -> Result<TranscriptionModelResponse> {
+                    // Delegate to OpenAIChatCompletions' transcription implementation
+                    self.inner.transcribe(options).await
+                }
+            }
+        }
+    };
+}
+
 /// Generates the main provider struct, builder, and implementations for an OpenAI-compatible provider.
 ///
 /// # Arguments
@@ -234,6 +360,8 @@ macro_rules! openai_compatible_provider {
                 inner.settings.base_url = settings.base_url.clone();
                 inner.settings.api_key = settings.api_key.clone();
                 inner.settings.path = settings.path.clone();
+                inner.settings.http_client = settings.http_client.clone();
+                inner.settings.retry_policy = settings.retry_policy.clone();
 
                 Self { settings, inner }
             }
@@ -293,6 +421,34 @@ macro_rules! openai_compatible_provider {
                 self
             }
 
+            #[doc = concat!(
+                "Sets the HTTP client used by the ", stringify!($provider_struct), " provider.\n\n",
+                "Use this to configure timeouts, proxies, root certificates or default headers, ",
+                "e.g. with `HttpClientConfig`. By default all providers share one pooled client.\n\n",
+                "# Parameters\n\n",
+                "* `http_client` - The `reqwest::Client` to use.\n\n",
+                "# Returns\n\n",
+                "The builder with the HTTP client set."
+            )]
+            pub fn http_client(mut self, http_client: reqwest::Client) -> Self {
+                self.settings.http_client = http_client.clone();
+                self.inner.settings.http_client = http_client;
+                self
+            }
+
+            #[doc = concat!(
+                "Sets the policy for retrying failed requests of the ", stringify!($provider_struct), " provider.\n\n",
+                "# Parameters\n\n",
+                "* `retry_policy` - The `RetryPolicy` to use.\n\n",
+                "# Returns\n\n",
+                "The builder with the retry policy set."
+            )]
+            pub fn retry_policy(mut self, retry_policy: $crate::core::client::RetryPolicy) -> Self {
+                self.settings.retry_policy = retry_policy.clone();
+                self.inner.settings.retry_policy = retry_policy;
+                self
+            }
+
             #[doc = concat!(
                 "Builds the ", stringify!($provider_struct), " provider.\n\n",
                 "Validates the configuration and creates the provider instance.\n\n",
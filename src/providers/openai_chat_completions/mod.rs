@@ -8,9 +8,12 @@
 pub(crate) mod client;
 pub(crate) mod conversions;
 pub(crate) mod embedding_model;
+pub(crate) mod image_model;
 pub(crate) mod language_model;
+pub(crate) mod transcription_model;
 #[macro_use]
 pub mod macros;
+pub(crate) mod reranking_model;
 pub mod settings;
 
 use crate::core::DynamicModel;
@@ -0,0 +1,93 @@
+//! Reranking model implementation for providers with a `/rerank` endpoint next to their
+//! OpenAI Chat Completions compatible API.
+
+use crate::{
+    core::{
+        capabilities::ModelName,
+        client::JsonClient,
+        reranking_model::{RerankingModel, RerankingModelOptions, RerankingModelResponse},
+    },
+    error::Result,
+    providers::openai_chat_completions::OpenAIChatCompletions,
+};
+use async_trait::async_trait;
+
+use super::client::types::{RerankOptions, RerankResponse};
+
+impl<M: ModelName> OpenAIChatCompletions<M> {
+    /// Ranks documents using a `/rerank` API in the format shared by Together AI, Jina and Cohere.
+    pub async fn rerank(&self, options: RerankingModelOptions) -> Result<RerankingModelResponse> {
+        let rerank_client = RerankingClientWrapper {
+            settings: self.settings.clone(),
+            options: RerankOptions {
+                model: self.options.model.clone(),
+                query: options.query,
+                documents: options.documents.clone(),
+                top_n: options.top_n,
+            },
+        };
+
+        let response = rerank_client
+            .send(&self.settings.base_url, &self.settings.retry_policy)
+            .await?;
+
+        RerankingModelResponse::from_scores(
+            &options.documents,
+            response
+                .results
+                .into_iter()
+                .map(|result| (result.index, result.relevance_score)),
+        )
+    }
+}
+
+/// Temporary wrapper for rerank requests.
+struct RerankingClientWrapper {
+    settings: super::settings::OpenAIChatCompletionsSettings,
+    options: RerankOptions,
+}
+
+impl JsonClient for RerankingClientWrapper {
+    type Response = RerankResponse;
+
+    fn path(&self) -> String {
+        "rerank".to_string()
+    }
+
+    fn method(&self) -> reqwest::Method {
+        reqwest::Method::POST
+    }
+
+    fn http_client(&self) -> reqwest::Client {
+        self.settings.http_client.clone()
+    }
+
+    fn headers(&self) -> reqwest::header::HeaderMap {
+        let mut headers = reqwest::header::HeaderMap::new();
+        headers.insert(
+            reqwest::header::CONTENT_TYPE,
+            "application/json".parse().unwrap(),
+        );
+        headers.insert(
+            reqwest::header::AUTHORIZATION,
+            format!("Bearer {}", self.settings.api_key).parse().unwrap(),
+        );
+        headers
+    }
+
+    fn query_params(&self) -> Vec<(&str, &str)> {
+        Vec::new()
+    }
+
+    fn body(&self) -> reqwest::Body {
+        let body = serde_json::to_string(&self.options).unwrap();
+        reqwest::Body::from(body)
+    }
+}
+
+#[async_trait]
+impl<M: ModelName> RerankingModel for OpenAIChatCompletions<M> {
+    async fn rerank(&self, options: RerankingModelOptions) -> Result<RerankingModelResponse> {
+        self.rerank(options).await
+    }
+}
@@ -21,6 +21,12 @@ pub struct OpenAIChatCompletionsSettings {
     /// Custom API path override. When set, this path is used instead of the
     /// default "chat/completions".
     pub path: Option<String>,
+
+    /// The HTTP client used for requests. Defaults to the client shared by all providers.
+    pub http_client: reqwest::Client,
+
+    /// The policy for retrying failed requests.
+    pub retry_policy: crate::core::client::RetryPolicy,
 }
 
 impl Default for OpenAIChatCompletionsSettings {
@@ -30,6 +36,8 @@ impl Default for OpenAIChatCompletionsSettings {
             base_url: "https://api.openai.com/v1".to_string(),
             api_key: std::env::var("OPENAI_API_KEY").unwrap_or_default(),
             path: None,
+            http_client: crate::core::client::shared_http_client(),
+            retry_policy: crate::core::client::RetryPolicy::default(),
         }
     }
 }
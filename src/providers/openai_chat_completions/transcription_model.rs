@@ -0,0 +1,178 @@
+//! Transcription model implementation for OpenAI Chat Completions API compatible providers.
+
+use crate::{
+    core::{
+        capabilities::ModelName,
+        client::{JsonClient, multipart::MultipartForm},
+        transcription_model::{
+            TranscriptionModel, TranscriptionModelOptions, TranscriptionModelResponse,
+            TranscriptionSegment,
+        },
+    },
+    error::Result,
+    providers::openai_chat_completions::OpenAIChatCompletions,
+};
+use async_trait::async_trait;
+
+use super::client::types::TranscriptionResponse;
+
+impl<M: ModelName> OpenAIChatCompletions<M> {
+    /// Transcribes audio using the OpenAI-compatible Transcriptions API.
+    pub async fn transcribe(
+        &self,
+        options: TranscriptionModelOptions,
+    ) -> Result<TranscriptionModelResponse> {
+        let (filename, data) = options.audio_file()?;
+
+        let mut form = MultipartForm::new()
+            .file("file", filename, options.audio.media_type, data)
+            .text("model", self.options.model.clone())
+            .text("response_format", "verbose_json")
+            .text_opt("language", options.language)
+            .text_opt("prompt", options.prompt);
+        for granularity in &options.timestamp_granularities {
+            form = form.text("timestamp_granularities[]", granularity.as_str());
+        }
+        let (content_type, body) = form.finish();
+
+        let transcription_client = TranscriptionClientWrapper {
+            settings: self.settings.clone(),
+            content_type,
+            body,
+        };
+
+        let response = transcription_client
+            .send(&self.settings.base_url, &self.settings.retry_policy)
+            .await?;
+
+        Ok(TranscriptionModelResponse {
+            text: response.text,
+            segments: response
+                .segments
+                .unwrap_or_default()
+                .into_iter()
+                .map(|segment| TranscriptionSegment {
+                    text: segment.text,
+                    start: segment.start,
+                    end: segment.end,
+                })
+                .collect(),
+            words: response
+                .words
+                .unwrap_or_default()
+                .into_iter()
+                .map(|word| TranscriptionSegment {
+                    text: word.word,
+                    start: word.start,
+                    end: word.end,
+                })
+                .collect(),
+            language: response.language,
+            duration: response.duration,
+        })
+    }
+}
+
+/// Temporary wrapper for transcription requests with a multipart body.
+struct TranscriptionClientWrapper {
+    settings: super::settings::OpenAIChatCompletionsSettings,
+    content_type: String,
+    body: Vec<u8>,
+}
+
+impl JsonClient for TranscriptionClientWrapper {
+    type Response = TranscriptionResponse;
+
+    fn path(&self) -> String {
+        "audio/transcriptions".to_string()
+    }
+
+    fn method(&self) -> reqwest::Method {
+        reqwest::Method::POST
+    }
+
+    fn http_client(&self) -> reqwest::Client {
+        self.settings.http_client.clone()
+    }
+
+    fn headers(&self) -> reqwest::header::HeaderMap {
+        let mut headers = reqwest::header::HeaderMap::new();
+        headers.insert(
+            reqwest::header::CONTENT_TYPE,
+            self.content_type.parse().unwrap(),
+        );
+        headers.insert(
+            reqwest::header::AUTHORIZATION,
+            format!("Bearer {}", self.settings.api_key).parse().unwrap(),
+        );
+        headers
+    }
+
+    fn query_params(&self) -> Vec<(&str, &str)> {
+        Vec::new()
+    }
+
+    fn body(&self) -> reqwest::Body {
+        reqwest::Body::from(self.body.clone())
+    }
+}
+
+#[async_trait]
+impl<M: ModelName> TranscriptionModel for OpenAIChatCompletions<M> {
+    async fn transcribe(
+        &self,
+        options: TranscriptionModelOptions,
+    ) -> Result<TranscriptionModelResponse> {
+        self.transcribe(options).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::DynamicModel;
+    use crate::core::client::stub_server::StubServer;
+    use crate::core::transcription_model::TranscriptionModelRequest;
+    use serde_json::json;
+
+    #[tokio::test]
+    async fn test_transcribe_file() {
+        let mut server = StubServer::json(json!({
+            "task": "transcribe",
+            "language": "English",
+            "duration": 1.2,
+            "text": " Hello.",
+            "segments": [{ "id": 0, "start": 0.0, "end": 1.2, "text": " Hello." }],
+            "x_groq": { "id": "req_1" }
+        }))
+        .await;
+
+        let mut model = OpenAIChatCompletions::<DynamicModel>::model_name("whisper-large-v3");
+        model.settings.base_url = format!("{}openai/v1/", server.url);
+        model.settings.api_key = "test-key".to_string();
+
+        let path = std::env::temp_dir().join(format!("aisdk-{}.mp3", uuid::Uuid::new_v4()));
+        std::fs::write(&path, b"ID3").unwrap();
+        let request = TranscriptionModelRequest::builder()
+            .model(model)
+            .file(&path)
+            .unwrap()
+            .build();
+        std::fs::remove_file(&path).unwrap();
+
+        let response = request.transcribe().await.unwrap();
+
+        let request = server.request().await;
+        assert_eq!(request.path, "/openai/v1/audio/transcriptions");
+        let body = String::from_utf8(request.body).unwrap();
+        assert!(body.contains("Content-Type: audio/mpeg\r\n\r\nID3\r\n"));
+        assert!(body.contains("name=\"model\"\r\n\r\nwhisper-large-v3\r\n"));
+        assert!(body.contains("name=\"response_format\"\r\n\r\nverbose_json\r\n"));
+
+        assert_eq!(response.text, " Hello.");
+        assert_eq!(response.language.as_deref(), Some("English"));
+        assert_eq!(response.duration, Some(1.2));
+        assert_eq!(response.segments.len(), 1);
+        assert!(response.words.is_empty());
+    }
+}
@@ -22,6 +22,8 @@ impl<M: ModelName> EmbeddingModel for OpenAICompatible<M> {
                 api_key: self.inner.settings.api_key.clone(),
                 provider_name: self.inner.settings.provider_name.clone(),
                 path: self.inner.settings.path.clone(),
+                http_client: self.inner.settings.http_client.clone(),
+                retry_policy: self.inner.settings.retry_policy.clone(),
             },
             lm_options: Default::default(),
             embedding_options: crate::providers::openai::client::OpenAIEmbeddingOptions {
@@ -0,0 +1,73 @@
+//! Image model implementation for the OpenAI-compatible provider.
+
+use async_trait::async_trait;
+
+use crate::{
+    Result,
+    core::{
+        capabilities::ModelName,
+        image_model::{ImageModel, ImageModelOptions, ImageModelResponse},
+    },
+    providers::openai_compatible::OpenAICompatible,
+};
+
+#[async_trait]
+impl<M: ModelName> ImageModel for OpenAICompatible<M> {
+    async fn generate_image(&self, options: ImageModelOptions) -> Result<ImageModelResponse> {
+        // Delegate to OpenAIChatCompletions' image implementation
+        self.inner.generate_image(options).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::DynamicModel;
+    use crate::core::client::stub_server::StubServer;
+    use crate::core::image_model::ImageModelRequest;
+    use serde_json::json;
+
+    #[tokio::test]
+    async fn test_generate_image() {
+        let mut server = StubServer::json(json!({
+            "id": "img-1",
+            "object": "list",
+            "data": [{ "index": 0, "url": "https://example.com/thumbnail.png" }]
+        }))
+        .await;
+
+        let model = OpenAICompatible::<DynamicModel>::builder()
+            .base_url(format!("{}v1", server.url))
+            .api_key("test-key")
+            .model_name("black-forest-labs/FLUX.1-schnell")
+            .build()
+            .unwrap();
+
+        let response = ImageModelRequest::builder()
+            .model(model)
+            .prompt("A product thumbnail")
+            .seed(42)
+            .build()
+            .generate_image()
+            .await
+            .unwrap();
+
+        let request = server.request().await;
+        assert_eq!(request.path, "/v1/images/generations");
+        assert_eq!(
+            request.json(),
+            json!({
+                "model": "black-forest-labs/FLUX.1-schnell",
+                "prompt": "A product thumbnail",
+                "seed": 42
+            })
+        );
+
+        assert_eq!(response.images.len(), 1);
+        assert_eq!(
+            response.images[0].url(),
+            Some("https://example.com/thumbnail.png")
+        );
+        assert_eq!(response.images[0].revised_prompt, None);
+    }
+}
@@ -20,8 +20,11 @@
 
 pub mod capabilities;
 pub mod embedding_model;
+pub mod image_model;
 pub mod language_model;
+pub mod reranking_model;
 pub mod settings;
+pub mod transcription_model;
 
 use crate::Error;
 use crate::core::DynamicModel;
@@ -113,6 +116,8 @@ impl<M: ModelName> Default for OpenAICompatibleBuilder<M> {
         inner.settings.base_url = settings.base_url.clone();
         inner.settings.api_key = settings.api_key.clone();
         inner.settings.path = settings.path.clone();
+        inner.settings.http_client = settings.http_client.clone();
+        inner.settings.retry_policy = settings.retry_policy.clone();
 
         Self { settings, inner }
     }
@@ -175,6 +180,40 @@ impl<M: ModelName> OpenAICompatibleBuilder<M> {
         self
     }
 
+    /// Sets the HTTP client used for requests.
+    ///
+    /// Use this to configure timeouts, proxies, root certificates or default headers,
+    /// e.g. with [`HttpClientConfig`](crate::core::client::HttpClientConfig). By default
+    /// all providers share one pooled client.
+    ///
+    /// # Parameters
+    ///
+    /// * `http_client` - The `reqwest::Client` to use.
+    ///
+    /// # Returns
+    ///
+    /// The builder with the HTTP client set.
+    pub fn http_client(mut self, http_client: reqwest::Client) -> Self {
+        self.settings.http_client = http_client.clone();
+        self.inner.settings.http_client = http_client;
+        self
+    }
+
+    /// Sets the policy for retrying failed requests.
+    ///
+    /// # Parameters
+    ///
+    /// * `retry_policy` - The [`RetryPolicy`](crate::core::client::RetryPolicy) to use.
+    ///
+    /// # Returns
+    ///
+    /// The builder with the retry policy set.
+    pub fn retry_policy(mut self, retry_policy: crate::core::client::RetryPolicy) -> Self {
+        self.settings.retry_policy = retry_policy.clone();
+        self.inner.settings.retry_policy = retry_policy;
+        self
+    }
+
     /// Builds the OpenAICompatible provider.
     ///
     /// Validates the configuration and creates the provider instance.
@@ -0,0 +1,118 @@
+//! Reranking model implementation for the OpenAI-compatible provider.
+//!
+//! Sends requests to the `/rerank` endpoint below the base URL in the format shared by
+//! Together AI, Jina and Cohere.
+
+use async_trait::async_trait;
+
+use crate::{
+    Result,
+    core::{
+        capabilities::ModelName,
+        reranking_model::{RerankingModel, RerankingModelOptions, RerankingModelResponse},
+    },
+    providers::openai_compatible::OpenAICompatible,
+};
+
+#[async_trait]
+impl<M: ModelName> RerankingModel for OpenAICompatible<M> {
+    async fn rerank(&self, options: RerankingModelOptions) -> Result<RerankingModelResponse> {
+        // Delegate to OpenAIChatCompletions' reranking implementation
+        self.inner.rerank(options).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Error;
+    use crate::core::DynamicModel;
+    use crate::core::client::stub_server::StubServer;
+    use crate::core::reranking_model::{RankedDocument, RerankingModelRequest};
+    use serde_json::json;
+
+    fn model(server: &StubServer) -> OpenAICompatible<DynamicModel> {
+        OpenAICompatible::<DynamicModel>::builder()
+            .base_url(format!("{}v1", server.url))
+            .api_key("test-key")
+            .model_name("jina-reranker-v2-base-multilingual")
+            .build()
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_rerank() {
+        let mut server = StubServer::json(json!({
+            "model": "jina-reranker-v2-base-multilingual",
+            "usage": { "total_tokens": 38 },
+            "results": [
+                { "index": 2, "relevance_score": 0.92, "document": { "text": "Rust is fast" } },
+                { "index": 0, "relevance_score": 0.15, "document": { "text": "Bananas" } }
+            ]
+        }))
+        .await;
+
+        let response = RerankingModelRequest::builder()
+            .model(model(&server))
+            .query("Which language is fast?")
+            .documents(["Bananas", "The sky is blue", "Rust is fast"])
+            .top_n(2)
+            .build()
+            .rerank()
+            .await
+            .unwrap();
+
+        let request = server.request().await;
+        assert_eq!(request.path, "/v1/rerank");
+        assert_eq!(request.headers["authorization"], "Bearer test-key");
+        assert_eq!(
+            request.json(),
+            json!({
+                "model": "jina-reranker-v2-base-multilingual",
+                "query": "Which language is fast?",
+                "documents": ["Bananas", "The sky is blue", "Rust is fast"],
+                "top_n": 2
+            })
+        );
+
+        assert_eq!(
+            response.results,
+            vec![
+                RankedDocument {
+                    index: 2,
+                    score: 0.92,
+                    document: "Rust is fast".to_string(),
+                },
+                RankedDocument {
+                    index: 0,
+                    score: 0.15,
+                    document: "Bananas".to_string(),
+                },
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_rerank_error() {
+        let server = StubServer::start(
+            422,
+            "application/json",
+            r#"{"detail":"top_n must be positive"}"#,
+        )
+        .await;
+
+        let result = RerankingModelRequest::builder()
+            .model(model(&server))
+            .query("query")
+            .documents(["a"])
+            .top_n(0)
+            .build()
+            .rerank()
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(Error::ApiError { status_code: Some(status), .. }) if status == 422
+        ));
+    }
+}
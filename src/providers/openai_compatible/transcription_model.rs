@@ -0,0 +1,28 @@
+//! Transcription model implementation for the OpenAI-compatible provider.
+
+use async_trait::async_trait;
+
+use crate::{
+    Result,
+    core::{
+        capabilities::{AudioInputSupport, ModelName},
+        transcription_model::{
+            TranscriptionModel, TranscriptionModelOptions, TranscriptionModelResponse,
+        },
+    },
+    providers::openai_compatible::OpenAICompatible,
+};
+
+#[async_trait]
+impl<M: ModelName> TranscriptionModel for OpenAICompatible<M>
+where
+    OpenAICompatible<M>: AudioInputSupport,
+{
+    async fn transcribe(
+        &self,
+        options: TranscriptionModelOptions,
+    ) -> Result<TranscriptionModelResponse> {
+        // Delegate to OpenAIChatCompletions' transcription implementation
+        self.inner.transcribe(options).await
+    }
+}
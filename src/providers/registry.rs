@@ -0,0 +1,300 @@
+//! A registry that resolves `"provider:model"` identifiers into language models.
+//!
+//! The registry maps provider names to factories producing an [`AnyLanguageModel`],
+//! which allows choosing the provider at runtime, e.g. from configuration.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//!# #[cfg(feature = "anthropic")]
+//!# {
+//! use aisdk::core::{AnyLanguageModel, LanguageModelRequest};
+//! use aisdk::providers::{Anthropic, ProviderRegistry};
+//!
+//! async fn run(model_id: &str, api_key: String) -> aisdk::Result<()> {
+//!     let mut registry = ProviderRegistry::with_default_providers();
+//!
+//!     // Override a provider with custom settings.
+//!     registry.register("anthropic", move |model| {
+//!         Ok(AnyLanguageModel::new(
+//!             Anthropic::builder()
+//!                 .model_name(model)
+//!                 .api_key(api_key.clone())
+//!                 .build()?,
+//!         ))
+//!     });
+//!
+//!     let result = LanguageModelRequest::builder()
+//!         .model(registry.language_model(model_id)?)
+//!         .prompt("Hello!")
+//!         .build()
+//!         .generate_text()
+//!         .await?;
+//!
+//!     println!("{:?}", result.text());
+//!     Ok(())
+//! }
+//!# }
+//! ```
+
+use crate::core::AnyLanguageModel;
+use crate::error::{Error, Result};
+use std::collections::HashMap;
+use std::str::FromStr;
+use std::sync::Arc;
+
+/// A factory creating a language model from a model name.
+pub type LanguageModelFactory = Arc<dyn Fn(&str) -> Result<AnyLanguageModel> + Send + Sync>;
+
+/// Registers `model_name` factories for the providers enabled through features.
+macro_rules! register_default_providers {
+    ($registry:ident, { $($feature:literal => $provider:ident),* $(,)? }) => {
+        $(
+            #[cfg(feature = $feature)]
+            $registry.register($feature, |model| {
+                Ok(AnyLanguageModel::new(
+                    crate::providers::$provider::<crate::core::DynamicModel>::model_name(model),
+                ))
+            });
+        )*
+    };
+}
+
+/// Resolves `"provider:model"` identifiers into [`AnyLanguageModel`] instances.
+#[derive(Clone, Default)]
+pub struct ProviderRegistry {
+    factories: HashMap<String, LanguageModelFactory>,
+}
+
+impl ProviderRegistry {
+    /// Creates an empty registry.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a registry containing every provider enabled through features.
+    ///
+    /// Providers are registered under their feature name (e.g. `openai`, `anthropic`,
+    /// `amazon-bedrock`) and use their default settings, reading the API key from the
+    /// environment. `openaicompatible` is not registered since it needs a base URL.
+    pub fn with_default_providers() -> Self {
+        #[allow(unused_mut)]
+        let mut registry = Self::new();
+
+        register_default_providers!(registry, {
+            "openai" => OpenAI,
+            "anthropic" => Anthropic,
+            "google" => Google,
+            "groq" => Groq,
+            "vercel" => Vercel,
+            "openrouter" => Openrouter,
+            "mistral" => Mistral,
+            "amazon-bedrock" => AmazonBedrock,
+            "togetherai" => TogetherAI,
+            "xai" => XAI,
+            "302ai" => Ai302,
+            "abacus" => Abacus,
+            "aihubmix" => Aihubmix,
+            "alibaba" => Alibaba,
+            "alibaba-cn" => AlibabaCn,
+            "bailing" => Bailing,
+            "baseten" => Baseten,
+            "berget" => Berget,
+            "chutes" => Chutes,
+            "cloudflare-ai-gateway" => CloudflareAiGateway,
+            "cloudflare-workers-ai" => CloudflareWorkersAi,
+            "cortecs" => Cortecs,
+            "deepseek" => Deepseek,
+            "fastrouter" => Fastrouter,
+            "fireworks-ai" => FireworksAi,
+            "firmware" => Firmware,
+            "friendli" => Friendli,
+            "github-copilot" => GithubCopilot,
+            "github-models" => GithubModels,
+            "helicone" => Helicone,
+            "huggingface" => Huggingface,
+            "iflowcn" => Iflowcn,
+            "inception" => Inception,
+            "inference" => Inference,
+            "io-net" => IoNet,
+            "jiekou" => Jiekou,
+            "kuae-cloud-coding-plan" => KuaeCloudCodingPlan,
+            "llama" => Llama,
+            "lmstudio" => Lmstudio,
+            "lucidquery" => Lucidquery,
+            "moark" => Moark,
+            "modelscope" => Modelscope,
+            "moonshotai" => Moonshotai,
+            "moonshotai-cn" => MoonshotaiCn,
+            "morph" => Morph,
+            "nano-gpt" => NanoGpt,
+            "nebius" => Nebius,
+            "nova" => Nova,
+            "novita-ai" => NovitaAi,
+            "nvidia" => Nvidia,
+            "ollama-cloud" => OllamaCloud,
+            "opencode" => Opencode,
+            "ovhcloud" => Ovhcloud,
+            "poe" => Poe,
+            "requesty" => Requesty,
+            "scaleway" => Scaleway,
+            "siliconflow" => Siliconflow,
+            "siliconflow-cn" => SiliconflowCn,
+            "stackit" => Stackit,
+            "stepfun" => Stepfun,
+            "submodel" => Submodel,
+            "synthetic" => Synthetic,
+            "upstage" => Upstage,
+            "vultr" => Vultr,
+            "wandb" => Wandb,
+            "xiaomi" => Xiaomi,
+            "zai" => Zai,
+            "zai-coding-plan" => ZaiCodingPlan,
+            "zenmux" => Zenmux,
+            "zhipuai" => Zhipuai,
+            "zhipuai-coding-plan" => ZhipuaiCodingPlan,
+        });
+
+        registry
+    }
+
+    /// Registers a provider, replacing any provider with the same name.
+    ///
+    /// # Parameters
+    ///
+    /// * `provider` - The provider name used as prefix in model identifiers.
+    /// * `factory` - Creates a model from the model name following the prefix.
+    ///
+    /// # Returns
+    ///
+    /// The registry, for chaining further registrations.
+    pub fn register<F>(&mut self, provider: impl Into<String>, factory: F) -> &mut Self
+    where
+        F: Fn(&str) -> Result<AnyLanguageModel> + Send + Sync + 'static,
+    {
+        self.factories.insert(provider.into(), Arc::new(factory));
+        self
+    }
+
+    /// Returns the names of the registered providers, sorted alphabetically.
+    pub fn providers(&self) -> Vec<&str> {
+        let mut providers: Vec<&str> = self.factories.keys().map(String::as_str).collect();
+        providers.sort_unstable();
+        providers
+    }
+
+    /// Creates a language model from a `"provider:model"` identifier.
+    ///
+    /// Only the first `:` separates the provider, so model names may contain colons.
+    ///
+    /// # Parameters
+    ///
+    /// * `id` - The model identifier, e.g. `"anthropic:claude-sonnet-4-5"`.
+    ///
+    /// # Returns
+    ///
+    /// The model, or [`Error::InvalidInput`] if the identifier is malformed or the
+    /// provider is not registered.
+    pub fn language_model(&self, id: &str) -> Result<AnyLanguageModel> {
+        let (provider, model) = id
+            .split_once(':')
+            .filter(|(provider, model)| !provider.is_empty() && !model.is_empty())
+            .ok_or_else(|| {
+                Error::InvalidInput(format!(
+                    "Invalid model identifier '{id}', expected 'provider:model'"
+                ))
+            })?;
+
+        let factory = self
+            .factories
+            .get(provider)
+            .ok_or_else(|| Error::InvalidInput(format!("Unknown provider '{provider}'")))?;
+
+        factory(model)
+    }
+}
+
+impl std::fmt::Debug for ProviderRegistry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ProviderRegistry")
+            .field("providers", &self.providers())
+            .finish()
+    }
+}
+
+impl FromStr for AnyLanguageModel {
+    type Err = Error;
+
+    /// Resolves a `"provider:model"` identifier using
+    /// [`ProviderRegistry::with_default_providers`].
+    fn from_str(s: &str) -> Result<Self> {
+        ProviderRegistry::with_default_providers().language_model(s)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::LanguageModel;
+    use crate::core::language_model::{
+        LanguageModelOptions, LanguageModelResponse, ProviderStream,
+    };
+    use async_trait::async_trait;
+
+    #[derive(Debug, Clone)]
+    struct NamedModel(String);
+
+    #[async_trait]
+    impl LanguageModel for NamedModel {
+        fn name(&self) -> String {
+            self.0.clone()
+        }
+
+        async fn generate_text(
+            &mut self,
+            _options: LanguageModelOptions,
+        ) -> Result<LanguageModelResponse> {
+            Ok(LanguageModelResponse::new(self.0.clone()))
+        }
+
+        async fn stream_text(&mut self, _options: LanguageModelOptions) -> Result<ProviderStream> {
+            Err(Error::Other("not supported".to_string()))
+        }
+    }
+
+    fn registry() -> ProviderRegistry {
+        let mut registry = ProviderRegistry::new();
+        registry.register("test", |model| {
+            Ok(AnyLanguageModel::new(NamedModel(model.to_string())))
+        });
+        registry
+    }
+
+    #[test]
+    fn test_language_model_resolves_provider_and_model() {
+        let model = registry().language_model("test:org/model:latest").unwrap();
+        assert_eq!(model.name(), "org/model:latest");
+    }
+
+    #[test]
+    fn test_language_model_rejects_invalid_identifiers() {
+        let registry = registry();
+        for id in ["test", ":model", "test:", "unknown:model"] {
+            assert!(matches!(
+                registry.language_model(id),
+                Err(Error::InvalidInput(_))
+            ));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_any_language_model_delegates_to_inner_model() {
+        let mut model = registry().language_model("test:echo").unwrap().clone();
+        let response = model
+            .generate_text(LanguageModelOptions::default())
+            .await
+            .unwrap();
+        assert_eq!(format!("{:?}", response.contents), r#"[Text("echo")]"#);
+        assert_eq!(registry().providers(), vec!["test"]);
+    }
+}
@@ -28,3 +28,9 @@ crate::openai_compatible_language_model!(TogetherAI);
 
 // Generate the embedding model implementation
 crate::openai_compatible_embedding_model!(TogetherAI);
+
+// Generate the image model implementation
+crate::openai_compatible_image_model!(TogetherAI);
+
+// Generate the reranking model implementation
+crate::openai_compatible_reranking_model!(TogetherAI);
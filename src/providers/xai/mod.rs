@@ -20,3 +20,6 @@ crate::openai_compatible_provider!(XAI, XAIBuilder, XAISettings, "grok-2-latest"
 
 // Generate the language model implementation
 crate::openai_compatible_language_model!(XAI);
+
+// Generate the image model implementation
+crate::openai_compatible_image_model!(XAI);